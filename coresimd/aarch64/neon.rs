@@ -107,6 +107,23 @@ extern "C" {
     #[link_name = "llvm.aarch64.neon.fminv.f64.v2f64"]
     fn vminvq_f64_(a: f64x2) -> f64;
 
+    #[link_name = "llvm.aarch64.neon.saddlv.i32.v8i8"]
+    fn vaddlv_s8_(a: i8x8) -> i32;
+    #[link_name = "llvm.aarch64.neon.saddlv.i32.v16i8"]
+    fn vaddlvq_s8_(a: i8x16) -> i32;
+    #[link_name = "llvm.aarch64.neon.saddlv.i32.v4i16"]
+    fn vaddlv_s16_(a: i16x4) -> i32;
+    #[link_name = "llvm.aarch64.neon.saddlv.i32.v8i16"]
+    fn vaddlvq_s16_(a: i16x8) -> i32;
+    #[link_name = "llvm.aarch64.neon.uaddlv.i32.v8i8"]
+    fn vaddlv_u8_(a: u8x8) -> u32;
+    #[link_name = "llvm.aarch64.neon.uaddlv.i32.v16i8"]
+    fn vaddlvq_u8_(a: u8x16) -> u32;
+    #[link_name = "llvm.aarch64.neon.uaddlv.i32.v4i16"]
+    fn vaddlv_u16_(a: u16x4) -> u32;
+    #[link_name = "llvm.aarch64.neon.uaddlv.i32.v8i16"]
+    fn vaddlvq_u16_(a: u16x8) -> u32;
+
 }
 
 /// Horizontal vector max.
@@ -349,6 +366,70 @@ pub unsafe fn vminvq_f64(a: f64x2) -> f64 {
     vminvq_f64_(a)
 }
 
+/// Horizontal vector add long: sums the lanes into a wider result.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(saddlv))]
+pub unsafe fn vaddlv_s8(a: i8x8) -> i16 {
+    vaddlv_s8_(a) as i16
+}
+
+/// Horizontal vector add long: sums the lanes into a wider result.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(saddlv))]
+pub unsafe fn vaddlvq_s8(a: i8x16) -> i16 {
+    vaddlvq_s8_(a) as i16
+}
+
+/// Horizontal vector add long: sums the lanes into a wider result.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(saddlv))]
+pub unsafe fn vaddlv_s16(a: i16x4) -> i32 {
+    vaddlv_s16_(a)
+}
+
+/// Horizontal vector add long: sums the lanes into a wider result.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(saddlv))]
+pub unsafe fn vaddlvq_s16(a: i16x8) -> i32 {
+    vaddlvq_s16_(a)
+}
+
+/// Horizontal vector add long: sums the lanes into a wider result.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(uaddlv))]
+pub unsafe fn vaddlv_u8(a: u8x8) -> u16 {
+    vaddlv_u8_(a) as u16
+}
+
+/// Horizontal vector add long: sums the lanes into a wider result.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(uaddlv))]
+pub unsafe fn vaddlvq_u8(a: u8x16) -> u16 {
+    vaddlvq_u8_(a) as u16
+}
+
+/// Horizontal vector add long: sums the lanes into a wider result.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(uaddlv))]
+pub unsafe fn vaddlv_u16(a: u16x4) -> u32 {
+    vaddlv_u16_(a)
+}
+
+/// Horizontal vector add long: sums the lanes into a wider result.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(uaddlv))]
+pub unsafe fn vaddlvq_u16(a: u16x8) -> u32 {
+    vaddlvq_u16_(a)
+}
+
 #[cfg(test)]
 mod tests {
     use simd::*;
@@ -594,4 +675,52 @@ mod tests {
         let r = neon::vminvq_f64(f64x2::new(1., 4.));
         assert_eq!(r, 1_f64);
     }
+
+    #[simd_test = "neon"]
+    unsafe fn vaddlv_s8() {
+        let r = neon::vaddlv_s8(i8x8::splat(-128));
+        assert_eq!(r, -1024_i16);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vaddlvq_s8() {
+        let r = neon::vaddlvq_s8(i8x16::splat(127));
+        assert_eq!(r, 2032_i16);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vaddlv_s16() {
+        let r = neon::vaddlv_s16(i16x4::new(-32768, -32768, 1, -1));
+        assert_eq!(r, -65536_i32);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vaddlvq_s16() {
+        let r = neon::vaddlvq_s16(i16x8::splat(32767));
+        assert_eq!(r, 262136_i32);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vaddlv_u8() {
+        let r = neon::vaddlv_u8(u8x8::splat(255));
+        assert_eq!(r, 2040_u16);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vaddlvq_u8() {
+        let r = neon::vaddlvq_u8(u8x16::splat(255));
+        assert_eq!(r, 4080_u16);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vaddlv_u16() {
+        let r = neon::vaddlv_u16(u16x4::new(65535, 65535, 1, 2));
+        assert_eq!(r, 131073_u32);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vaddlvq_u16() {
+        let r = neon::vaddlvq_u16(u16x8::splat(65535));
+        assert_eq!(r, 524280_u32);
+    }
 }
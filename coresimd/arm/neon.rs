@@ -199,18 +199,64 @@ pub unsafe fn vaddl_u32(a: u32x2, b: u32x2) -> u64x2 {
 extern "C" {
     // The Reference says this instruction is
     // supported in v7/A32/A64:
-    #[link_name = "llvm.aarch64.neon.frsqrte.v2f32"]
+    #[cfg_attr(target_arch = "arm",
+               link_name = "llvm.arm.neon.vrsqrte.v2f32")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.frsqrte.v2f32")]
     fn frsqrte_v2f32(a: f32x2) -> f32x2;
+    #[cfg_attr(target_arch = "arm",
+               link_name = "llvm.arm.neon.vrsqrte.v4f32")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.frsqrte.v4f32")]
+    fn frsqrte_v4f32(a: f32x4) -> f32x4;
+    #[cfg_attr(target_arch = "arm",
+               link_name = "llvm.arm.neon.vrecpe.v2f32")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.frecpe.v2f32")]
+    fn frecpe_v2f32(a: f32x2) -> f32x2;
+    #[cfg_attr(target_arch = "arm",
+               link_name = "llvm.arm.neon.vrecpe.v4f32")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.frecpe.v4f32")]
+    fn frecpe_v4f32(a: f32x4) -> f32x4;
 }
 
 /// Reciprocal square-root estimate.
 #[inline]
 #[target_feature(enable = "neon")]
-#[cfg_attr(test, assert_instr(frsqrte))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrsqrte))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(frsqrte))]
 pub unsafe fn vrsqrte_f32(a: f32x2) -> f32x2 {
     frsqrte_v2f32(a)
 }
 
+/// Reciprocal square-root estimate.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrsqrte))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(frsqrte))]
+pub unsafe fn vrsqrteq_f32(a: f32x4) -> f32x4 {
+    frsqrte_v4f32(a)
+}
+
+/// Reciprocal estimate.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrecpe))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(frecpe))]
+pub unsafe fn vrecpe_f32(a: f32x2) -> f32x2 {
+    frecpe_v2f32(a)
+}
+
+/// Reciprocal estimate.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrecpe))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(frecpe))]
+pub unsafe fn vrecpeq_f32(a: f32x4) -> f32x4 {
+    frecpe_v4f32(a)
+}
+
 /// Vector narrow integer.
 #[inline]
 #[target_feature(enable = "neon")]
@@ -313,6 +359,387 @@ pub unsafe fn vmovl_u32(a: u32x2) -> u64x2 {
     a.into()
 }
 
+#[allow(improper_ctypes)]
+extern "C" {
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmins.v8i8")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.sminp.v8i8")]
+    fn vpmins_v8i8(a: i8x8, b: i8x8) -> i8x8;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmins.v4i16")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.sminp.v4i16")]
+    fn vpmins_v4i16(a: i16x4, b: i16x4) -> i16x4;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmins.v2i32")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.sminp.v2i32")]
+    fn vpmins_v2i32(a: i32x2, b: i32x2) -> i32x2;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpminu.v8i8")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.uminp.v8i8")]
+    fn vpminu_v8i8(a: u8x8, b: u8x8) -> u8x8;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpminu.v4i16")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.uminp.v4i16")]
+    fn vpminu_v4i16(a: u16x4, b: u16x4) -> u16x4;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpminu.v2i32")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.uminp.v2i32")]
+    fn vpminu_v2i32(a: u32x2, b: u32x2) -> u32x2;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmins.v2f32")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.fminp.v2f32")]
+    fn vpminf_v2f32(a: f32x2, b: f32x2) -> f32x2;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmaxs.v8i8")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.smaxp.v8i8")]
+    fn vpmaxs_v8i8(a: i8x8, b: i8x8) -> i8x8;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmaxs.v4i16")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.smaxp.v4i16")]
+    fn vpmaxs_v4i16(a: i16x4, b: i16x4) -> i16x4;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmaxs.v2i32")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.smaxp.v2i32")]
+    fn vpmaxs_v2i32(a: i32x2, b: i32x2) -> i32x2;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmaxu.v8i8")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.umaxp.v8i8")]
+    fn vpmaxu_v8i8(a: u8x8, b: u8x8) -> u8x8;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmaxu.v4i16")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.umaxp.v4i16")]
+    fn vpmaxu_v4i16(a: u16x4, b: u16x4) -> u16x4;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmaxu.v2i32")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.umaxp.v2i32")]
+    fn vpmaxu_v2i32(a: u32x2, b: u32x2) -> u32x2;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmaxs.v2f32")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.fmaxp.v2f32")]
+    fn vpmaxf_v2f32(a: f32x2, b: f32x2) -> f32x2;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpadd.v8i8")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.addp.v8i8")]
+    fn vpadd_v8i8(a: i8x8, b: i8x8) -> i8x8;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpadd.v4i16")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.addp.v4i16")]
+    fn vpadd_v4i16(a: i16x4, b: i16x4) -> i16x4;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpadd.v2i32")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.addp.v2i32")]
+    fn vpadd_v2i32(a: i32x2, b: i32x2) -> i32x2;
+    #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpadd.v2f32")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.faddp.v2f32")]
+    fn vpadd_v2f32(a: f32x2, b: f32x2) -> f32x2;
+}
+
+/// Folding minimum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmin))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(sminp))]
+pub unsafe fn vpmin_s8(a: i8x8, b: i8x8) -> i8x8 {
+    vpmins_v8i8(a, b)
+}
+
+/// Folding minimum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmin))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(sminp))]
+pub unsafe fn vpmin_s16(a: i16x4, b: i16x4) -> i16x4 {
+    vpmins_v4i16(a, b)
+}
+
+/// Folding minimum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmin))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(sminp))]
+pub unsafe fn vpmin_s32(a: i32x2, b: i32x2) -> i32x2 {
+    vpmins_v2i32(a, b)
+}
+
+/// Folding minimum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmin))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(uminp))]
+pub unsafe fn vpmin_u8(a: u8x8, b: u8x8) -> u8x8 {
+    vpminu_v8i8(a, b)
+}
+
+/// Folding minimum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmin))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(uminp))]
+pub unsafe fn vpmin_u16(a: u16x4, b: u16x4) -> u16x4 {
+    vpminu_v4i16(a, b)
+}
+
+/// Folding minimum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmin))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(uminp))]
+pub unsafe fn vpmin_u32(a: u32x2, b: u32x2) -> u32x2 {
+    vpminu_v2i32(a, b)
+}
+
+/// Folding minimum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmin))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(fminp))]
+pub unsafe fn vpmin_f32(a: f32x2, b: f32x2) -> f32x2 {
+    vpminf_v2f32(a, b)
+}
+
+/// Folding maximum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmax))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(smaxp))]
+pub unsafe fn vpmax_s8(a: i8x8, b: i8x8) -> i8x8 {
+    vpmaxs_v8i8(a, b)
+}
+
+/// Folding maximum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmax))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(smaxp))]
+pub unsafe fn vpmax_s16(a: i16x4, b: i16x4) -> i16x4 {
+    vpmaxs_v4i16(a, b)
+}
+
+/// Folding maximum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmax))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(smaxp))]
+pub unsafe fn vpmax_s32(a: i32x2, b: i32x2) -> i32x2 {
+    vpmaxs_v2i32(a, b)
+}
+
+/// Folding maximum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmax))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(umaxp))]
+pub unsafe fn vpmax_u8(a: u8x8, b: u8x8) -> u8x8 {
+    vpmaxu_v8i8(a, b)
+}
+
+/// Folding maximum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmax))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(umaxp))]
+pub unsafe fn vpmax_u16(a: u16x4, b: u16x4) -> u16x4 {
+    vpmaxu_v4i16(a, b)
+}
+
+/// Folding maximum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmax))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(umaxp))]
+pub unsafe fn vpmax_u32(a: u32x2, b: u32x2) -> u32x2 {
+    vpmaxu_v2i32(a, b)
+}
+
+/// Folding maximum of adjacent pairs.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmax))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(fmaxp))]
+pub unsafe fn vpmax_f32(a: f32x2, b: f32x2) -> f32x2 {
+    vpmaxf_v2f32(a, b)
+}
+
+/// Add pairwise.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(addp))]
+pub unsafe fn vpadd_s8(a: i8x8, b: i8x8) -> i8x8 {
+    vpadd_v8i8(a, b)
+}
+
+/// Add pairwise.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(addp))]
+pub unsafe fn vpadd_s16(a: i16x4, b: i16x4) -> i16x4 {
+    vpadd_v4i16(a, b)
+}
+
+/// Add pairwise.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(addp))]
+pub unsafe fn vpadd_s32(a: i32x2, b: i32x2) -> i32x2 {
+    vpadd_v2i32(a, b)
+}
+
+/// Add pairwise.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(addp))]
+pub unsafe fn vpadd_u8(a: u8x8, b: u8x8) -> u8x8 {
+    vpadd_v8i8(a.into_bits(), b.into_bits()).into_bits()
+}
+
+/// Add pairwise.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(addp))]
+pub unsafe fn vpadd_u16(a: u16x4, b: u16x4) -> u16x4 {
+    vpadd_v4i16(a.into_bits(), b.into_bits()).into_bits()
+}
+
+/// Add pairwise.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(addp))]
+pub unsafe fn vpadd_u32(a: u32x2, b: u32x2) -> u32x2 {
+    vpadd_v2i32(a.into_bits(), b.into_bits()).into_bits()
+}
+
+/// Add pairwise.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(faddp))]
+pub unsafe fn vpadd_f32(a: f32x2, b: f32x2) -> f32x2 {
+    vpadd_v2f32(a, b)
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[cfg_attr(target_arch = "arm",
+               link_name = "llvm.arm.neon.vrhadds.v8i8")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.srhadd.v8i8")]
+    fn vrhadd_s8_(a: i8x8, b: i8x8) -> i8x8;
+    #[cfg_attr(target_arch = "arm",
+               link_name = "llvm.arm.neon.vrhadds.v16i8")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.srhadd.v16i8")]
+    fn vrhaddq_s8_(a: i8x16, b: i8x16) -> i8x16;
+    #[cfg_attr(target_arch = "arm",
+               link_name = "llvm.arm.neon.vrhadds.v4i16")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.srhadd.v4i16")]
+    fn vrhadd_s16_(a: i16x4, b: i16x4) -> i16x4;
+    #[cfg_attr(target_arch = "arm",
+               link_name = "llvm.arm.neon.vrhadds.v8i16")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.srhadd.v8i16")]
+    fn vrhaddq_s16_(a: i16x8, b: i16x8) -> i16x8;
+    #[cfg_attr(target_arch = "arm",
+               link_name = "llvm.arm.neon.vrhaddu.v8i8")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.urhadd.v8i8")]
+    fn vrhadd_u8_(a: u8x8, b: u8x8) -> u8x8;
+    #[cfg_attr(target_arch = "arm",
+               link_name = "llvm.arm.neon.vrhaddu.v16i8")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.urhadd.v16i8")]
+    fn vrhaddq_u8_(a: u8x16, b: u8x16) -> u8x16;
+    #[cfg_attr(target_arch = "arm",
+               link_name = "llvm.arm.neon.vrhaddu.v4i16")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.urhadd.v4i16")]
+    fn vrhadd_u16_(a: u16x4, b: u16x4) -> u16x4;
+    #[cfg_attr(target_arch = "arm",
+               link_name = "llvm.arm.neon.vrhaddu.v8i16")]
+    #[cfg_attr(target_arch = "aarch64",
+               link_name = "llvm.aarch64.neon.urhadd.v8i16")]
+    fn vrhaddq_u16_(a: u16x8, b: u16x8) -> u16x8;
+}
+
+/// Vector rounding halving add: `(a + b + 1) >> 1` without overflow.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrhadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(srhadd))]
+pub unsafe fn vrhadd_s8(a: i8x8, b: i8x8) -> i8x8 {
+    vrhadd_s8_(a, b)
+}
+
+/// Vector rounding halving add: `(a + b + 1) >> 1` without overflow.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrhadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(srhadd))]
+pub unsafe fn vrhaddq_s8(a: i8x16, b: i8x16) -> i8x16 {
+    vrhaddq_s8_(a, b)
+}
+
+/// Vector rounding halving add: `(a + b + 1) >> 1` without overflow.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrhadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(srhadd))]
+pub unsafe fn vrhadd_s16(a: i16x4, b: i16x4) -> i16x4 {
+    vrhadd_s16_(a, b)
+}
+
+/// Vector rounding halving add: `(a + b + 1) >> 1` without overflow.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrhadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(srhadd))]
+pub unsafe fn vrhaddq_s16(a: i16x8, b: i16x8) -> i16x8 {
+    vrhaddq_s16_(a, b)
+}
+
+/// Vector rounding halving add: `(a + b + 1) >> 1` without overflow.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrhadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(urhadd))]
+pub unsafe fn vrhadd_u8(a: u8x8, b: u8x8) -> u8x8 {
+    vrhadd_u8_(a, b)
+}
+
+/// Vector rounding halving add: `(a + b + 1) >> 1` without overflow.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrhadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(urhadd))]
+pub unsafe fn vrhaddq_u8(a: u8x16, b: u8x16) -> u8x16 {
+    vrhaddq_u8_(a, b)
+}
+
+/// Vector rounding halving add: `(a + b + 1) >> 1` without overflow.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrhadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(urhadd))]
+pub unsafe fn vrhadd_u16(a: u16x4, b: u16x4) -> u16x4 {
+    vrhadd_u16_(a, b)
+}
+
+/// Vector rounding halving add: `(a + b + 1) >> 1` without overflow.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrhadd))]
+#[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(urhadd))]
+pub unsafe fn vrhaddq_u16(a: u16x8, b: u16x8) -> u16x8 {
+    vrhaddq_u16_(a, b)
+}
+
 #[cfg(test)]
 mod tests {
     use stdsimd_test::simd_test;
@@ -608,4 +1035,85 @@ mod tests {
         let r = neon::vmovl_u32(a);
         assert_eq!(r, e);
     }
+
+    #[simd_test = "neon"]
+    unsafe fn vpmin_s8() {
+        let a = i8x8::new(1, -2, 3, -4, 5, 6, 7, 8);
+        let b = i8x8::new(0, 3, 2, 5, 4, 7, 6, 9);
+        let e = i8x8::new(-2, -4, 5, 7, 0, 2, 4, 6);
+        let r = neon::vpmin_s8(a, b);
+        assert_eq!(r, e);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vpmin_u16() {
+        let a = u16x4::new(1, 2, 3, 4);
+        let b = u16x4::new(0, 3, 2, 5);
+        let e = u16x4::new(1, 3, 0, 2);
+        let r = neon::vpmin_u16(a, b);
+        assert_eq!(r, e);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vpmax_s32() {
+        let a = i32x2::new(1, -2);
+        let b = i32x2::new(0, 3);
+        let e = i32x2::new(1, 3);
+        let r = neon::vpmax_s32(a, b);
+        assert_eq!(r, e);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vpmax_f32() {
+        let a = f32x2::new(1., -2.);
+        let b = f32x2::new(0., 3.);
+        let e = f32x2::new(1., 3.);
+        let r = neon::vpmax_f32(a, b);
+        assert_eq!(r, e);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vpadd_u8() {
+        let a = u8x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = u8x8::new(0, 255, 2, 5, 4, 7, 6, 9);
+        let e = u8x8::new(3, 7, 11, 15, 255, 7, 11, 15);
+        let r = neon::vpadd_u8(a, b);
+        assert_eq!(r, e);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vrecpe_f32() {
+        let a = f32x2::new(2., 0.25);
+        let r = neon::vrecpe_f32(a);
+        assert!((r.extract(0) - 0.5).abs() <= 0.5 / 256.);
+        assert!((r.extract(1) - 4.).abs() <= 4. / 256.);
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vrsqrteq_f32() {
+        let a = f32x4::new(4., 0.25, 1., 16.);
+        let e = f32x4::new(0.5, 2., 1., 0.25);
+        let r = neon::vrsqrteq_f32(a);
+        for i in 0..4 {
+            let (r, e) = (r.extract(i), e.extract(i));
+            assert!((r - e).abs() <= e / 256.);
+        }
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vrhaddq_u8() {
+        let a = u8x16::splat(255);
+        let b = u8x16::splat(254);
+        let r = neon::vrhaddq_u8(a, b);
+        assert_eq!(r, u8x16::splat(255));
+    }
+
+    #[simd_test = "neon"]
+    unsafe fn vrhadd_s16() {
+        let a = i16x4::new(-3, -2, 1, 32767);
+        let b = i16x4::new(0, -1, 2, 32767);
+        let e = i16x4::new(-1, -1, 2, 32767);
+        let r = neon::vrhadd_s16(a, b);
+        assert_eq!(r, e);
+    }
 }
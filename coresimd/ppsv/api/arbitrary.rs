@@ -0,0 +1,84 @@
+//! Implements `quickcheck::Arbitrary` when the `quickcheck` feature is
+//! enabled.
+//!
+//! The lanes are generated independently. Half of them are drawn from a
+//! set of special values: the extremes of integer lanes, and `NaN`, `±0`,
+//! `±∞`, subnormals, and the extremes of floating-point lanes. The others
+//! are generated by the `Arbitrary` implementation of the scalar type.
+//! Half-precision vectors are converted from arbitrary single-precision
+//! vectors.
+
+macro_rules! impl_arbitrary {
+    ($id:ident, $elem_ty:ident, $kind:ident) => {
+        #[cfg(feature = "quickcheck")]
+        impl ::quickcheck::Arbitrary for $id {
+            fn arbitrary<G: ::quickcheck::Gen>(g: &mut G) -> Self {
+                let mut v: $id = Default::default();
+                for i in 0..$id::lanes() {
+                    v = v.replace(i, arbitrary_lane!($kind, $elem_ty, g));
+                }
+                v
+            }
+        }
+    };
+    ($id:ident, from_f32 $f32_ty:ident) => {
+        #[cfg(feature = "quickcheck")]
+        impl ::quickcheck::Arbitrary for $id {
+            fn arbitrary<G: ::quickcheck::Gen>(g: &mut G) -> Self {
+                use ::quickcheck::Arbitrary;
+                $id::from_f32($f32_ty::arbitrary(g))
+            }
+        }
+    };
+}
+
+/// Generates an arbitrary lane of type `$elem_ty`.
+macro_rules! arbitrary_lane {
+    (bool, $elem_ty:ident, $g:ident) => {
+        <bool as ::quickcheck::Arbitrary>::arbitrary($g)
+    };
+    (int, $elem_ty:ident, $g:ident) => {{
+        let specials = [
+            $elem_ty::min_value(),
+            $elem_ty::max_value(),
+            $elem_ty::min_value().wrapping_add(1),
+            $elem_ty::max_value().wrapping_sub(1),
+            0 as $elem_ty,
+            1 as $elem_ty,
+            (0 as $elem_ty).wrapping_sub(1),
+        ];
+        arbitrary_lane!(@pick $g, specials, {
+            // `i64` is the widest lane type that `quickcheck` generates:
+            <i64 as ::quickcheck::Arbitrary>::arbitrary($g) as $elem_ty
+        })
+    }};
+    (float, $elem_ty:ident, $g:ident) => {{
+        let min_positive = ::$elem_ty::MIN_POSITIVE;
+        let specials = [
+            ::$elem_ty::NAN,
+            0 as $elem_ty,
+            -(0 as $elem_ty),
+            ::$elem_ty::INFINITY,
+            ::$elem_ty::NEG_INFINITY,
+            min_positive,
+            -min_positive,
+            // Subnormals:
+            min_positive / 2.,
+            -min_positive / 4.,
+            ::$elem_ty::MAX,
+            ::$elem_ty::MIN,
+            ::$elem_ty::EPSILON,
+        ];
+        arbitrary_lane!(@pick $g, specials, {
+            <$elem_ty as ::quickcheck::Arbitrary>::arbitrary($g)
+        })
+    }};
+    // Returns one of the `$specials` for half of the generated values, and
+    // `$other` otherwise.
+    (@pick $g:ident, $specials:ident, $other:block) => {{
+        let c = <u32 as ::quickcheck::Arbitrary>::arbitrary($g) as usize;
+        if <bool as ::quickcheck::Arbitrary>::arbitrary($g) {
+            $specials[c % $specials.len()]
+        } else $other
+    }};
+}
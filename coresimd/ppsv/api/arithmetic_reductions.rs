@@ -4,16 +4,56 @@ macro_rules! impl_arithmetic_reductions {
     ($id:ident, $elem_ty:ident) => {
         impl $id {
             /// Lane-wise addition of the vector elements.
+            ///
+            /// For floating-point vectors, the lanes are currently added
+            /// from left to right on every target, but this order is not
+            /// guaranteed. See `sum_ordered` and `sum_tree`.
             #[inline]
             pub fn sum(self) -> $elem_ty {
                 ReduceAdd::reduce_add(self)
             }
             /// Lane-wise multiplication of the vector elements.
+            ///
+            /// For floating-point vectors, the lanes are currently multiplied
+            /// from left to right on every target, but this order is not
+            /// guaranteed. See `product_ordered` and `product_tree`.
             #[inline]
             pub fn product(self) -> $elem_ty {
                 ReduceMul::reduce_mul(self)
             }
         }
+
+        impl ::iter::Sum for $id {
+            /// Lane-wise sum of the vectors yielded by `iter`.
+            #[inline]
+            fn sum<I: Iterator<Item = $id>>(iter: I) -> $id {
+                iter.fold($id::splat(0 as $elem_ty), ops::Add::add)
+            }
+        }
+
+        impl<'a> ::iter::Sum<&'a $id> for $id {
+            /// Lane-wise sum of the vectors yielded by `iter`.
+            #[inline]
+            fn sum<I: Iterator<Item = &'a $id>>(iter: I) -> $id {
+                iter.fold($id::splat(0 as $elem_ty), |a, &b| a + b)
+            }
+        }
+
+        impl ::iter::Product for $id {
+            /// Lane-wise product of the vectors yielded by `iter`.
+            #[inline]
+            fn product<I: Iterator<Item = $id>>(iter: I) -> $id {
+                iter.fold($id::splat(1 as $elem_ty), ops::Mul::mul)
+            }
+        }
+
+        impl<'a> ::iter::Product<&'a $id> for $id {
+            /// Lane-wise product of the vectors yielded by `iter`.
+            #[inline]
+            fn product<I: Iterator<Item = &'a $id>>(iter: I) -> $id {
+                iter.fold($id::splat(1 as $elem_ty), |a, &b| a * b)
+            }
+        }
     }
 }
 
@@ -58,5 +98,31 @@ macro_rules! test_arithmetic_reductions {
             let v = alternating(f);
             assert_eq!(v.product(), (2_usize.pow(($id::lanes() / f) as u32) as $elem_ty));
         }
+        #[test]
+        fn iter_sum() {
+            use ::coresimd::simd::$id;
+            use ::std::iter::{self, Iterator};
+            let v = alternating(2);
+            let s: $id = iter::repeat(v).take(0).sum();
+            assert_eq!(s, $id::splat(0 as $elem_ty));
+            let s: $id = iter::repeat(v).take(3).sum();
+            assert_eq!(s, v + v + v);
+            let a = [v, v, v];
+            let s: $id = a.iter().sum();
+            assert_eq!(s, v + v + v);
+        }
+        #[test]
+        fn iter_product() {
+            use ::coresimd::simd::$id;
+            use ::std::iter::{self, Iterator};
+            let v = alternating(2);
+            let p: $id = iter::repeat(v).take(0).product();
+            assert_eq!(p, $id::splat(1 as $elem_ty));
+            let p: $id = iter::repeat(v).take(3).product();
+            assert_eq!(p, v * v * v);
+            let a = [v, v, v];
+            let p: $id = a.iter().product();
+            assert_eq!(p, v * v * v);
+        }
     }
 }
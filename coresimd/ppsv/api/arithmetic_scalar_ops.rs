@@ -0,0 +1,100 @@
+//! Lane-wise addition and multiplication between vectors and scalars.
+//!
+//! The scalar operand is broadcast to all vector lanes.
+
+macro_rules! impl_arithmetic_scalar_ops {
+    ($id:ident, $elem_ty:ident) => {
+        impl ops::Add<$elem_ty> for $id {
+            type Output = Self;
+            #[inline]
+            fn add(self, other: $elem_ty) -> Self {
+                self + $id::splat(other)
+            }
+        }
+
+        impl ops::Add<$id> for $elem_ty {
+            type Output = $id;
+            #[inline]
+            fn add(self, other: $id) -> $id {
+                $id::splat(self) + other
+            }
+        }
+
+        impl ops::Mul<$elem_ty> for $id {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: $elem_ty) -> Self {
+                self * $id::splat(other)
+            }
+        }
+
+        impl ops::Mul<$id> for $elem_ty {
+            type Output = $id;
+            #[inline]
+            fn mul(self, other: $id) -> $id {
+                $id::splat(self) * other
+            }
+        }
+
+        impl ops::AddAssign<$elem_ty> for $id {
+            #[inline]
+            fn add_assign(&mut self, other: $elem_ty) {
+                *self = *self + other;
+            }
+        }
+
+        impl ops::MulAssign<$elem_ty> for $id {
+            #[inline]
+            fn mul_assign(&mut self, other: $elem_ty) {
+                *self = *self * other;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_arithmetic_scalar_ops {
+    ($id:ident, $elem_ty:ident) => {
+        #[test]
+        fn arithmetic_scalar() {
+            use ::coresimd::simd::$id;
+            let zi = 0 as $elem_ty;
+            let oi = 1 as $elem_ty;
+            let ti = 2 as $elem_ty;
+            let fi = 4 as $elem_ty;
+            let z = $id::splat(zi);
+            let o = $id::splat(oi);
+            let t = $id::splat(ti);
+            let f = $id::splat(fi);
+
+            // add
+            assert_eq!(zi + z, z);
+            assert_eq!(z + zi, z);
+            assert_eq!(oi + z, o);
+            assert_eq!(o + zi, o);
+            assert_eq!(ti + t, f);
+            assert_eq!(t + ti, f);
+            // mul
+            assert_eq!(zi * z, z);
+            assert_eq!(z * zi, z);
+            assert_eq!(zi * o, z);
+            assert_eq!(z * oi, z);
+            assert_eq!(ti * o, t);
+            assert_eq!(t * oi, t);
+            assert_eq!(ti * t, f);
+            assert_eq!(t * ti, f);
+
+            {
+                let mut v = z;
+                assert_eq!(v, z);
+                v += oi;  // add_assign
+                assert_eq!(v, o);
+                v = t;
+                v *= oi; // mul_assign
+                assert_eq!(v, t);
+                v *= ti;
+                assert_eq!(v, f);
+            }
+        }
+    };
+}
@@ -0,0 +1,162 @@
+//! Implements conversions between vectors and arrays, and lane indexing.
+
+macro_rules! impl_array {
+    ($id:ident, $elem_ty:ident, $elem_count:expr) => {
+        impl From<[$elem_ty; $elem_count]> for $id {
+            #[inline]
+            fn from(array: [$elem_ty; $elem_count]) -> Self {
+                unsafe { mem::transmute(array) }
+            }
+        }
+
+        impl From<$id> for [$elem_ty; $elem_count] {
+            #[inline]
+            fn from(vec: $id) -> Self {
+                unsafe { mem::transmute(vec) }
+            }
+        }
+
+        impl ops::Index<usize> for $id {
+            type Output = $elem_ty;
+            /// Returns a reference to the lane at `index`.
+            ///
+            /// # Panics
+            ///
+            /// If `index >= Self::lanes()`.
+            #[inline]
+            fn index(&self, index: usize) -> &$elem_ty {
+                assert!(index < $elem_count);
+                unsafe {
+                    &*(self as *const Self as *const $elem_ty)
+                        .offset(index as isize)
+                }
+            }
+        }
+
+        impl ops::IndexMut<usize> for $id {
+            /// Returns a mutable reference to the lane at `index`.
+            ///
+            /// # Panics
+            ///
+            /// If `index >= Self::lanes()`.
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut $elem_ty {
+                assert!(index < $elem_count);
+                unsafe {
+                    &mut *(self as *mut Self as *mut $elem_ty)
+                        .offset(index as isize)
+                }
+            }
+        }
+    }
+}
+
+/// Boolean vectors do not store their lanes as `bool`s, so they cannot hand
+/// out references to them and do not implement `Index`/`IndexMut`.
+macro_rules! impl_bool_array {
+    ($id:ident, $elem_count:expr) => {
+        impl From<[bool; $elem_count]> for $id {
+            #[inline]
+            fn from(array: [bool; $elem_count]) -> Self {
+                let mut vec = Self::splat(false);
+                for i in 0..$elem_count {
+                    vec = unsafe { vec.replace_unchecked(i, array[i]) };
+                }
+                vec
+            }
+        }
+
+        impl From<$id> for [bool; $elem_count] {
+            #[inline]
+            fn from(vec: $id) -> Self {
+                let mut array = [false; $elem_count];
+                for i in 0..$elem_count {
+                    array[i] = unsafe { vec.extract_unchecked(i) };
+                }
+                array
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_array {
+    ($id:ident, $elem_ty:ident) => {
+        #[test]
+        fn array() {
+            use ::coresimd::simd::$id;
+            use ::std::convert::{From, Into};
+
+            let mut array = [0 as $elem_ty; $id::lanes()];
+            for i in 0..$id::lanes() {
+                array[i] = i as $elem_ty;
+            }
+
+            // From<[T; N]>:
+            let vec = $id::from(array);
+            for i in 0..$id::lanes() {
+                assert_eq!(vec.extract(i), i as $elem_ty);
+            }
+
+            // Into<[T; N]>:
+            let round_trip: [$elem_ty; $id::lanes()] = vec.into();
+            assert_eq!(&round_trip[..], &array[..]);
+        }
+
+        #[test]
+        fn index() {
+            use ::coresimd::simd::$id;
+
+            let mut vec = $id::splat(0 as $elem_ty);
+            for i in 0..$id::lanes() {
+                vec[i] = i as $elem_ty;
+            }
+            for i in 0..$id::lanes() {
+                assert_eq!(vec[i], i as $elem_ty);
+                assert_eq!(vec[i], vec.extract(i));
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn index_panic_on_out_of_bounds() {
+            use ::coresimd::simd::$id;
+            let vec = $id::splat(0 as $elem_ty);
+            let _ = vec[$id::lanes()];
+        }
+
+        #[test]
+        #[should_panic]
+        fn index_mut_panic_on_out_of_bounds() {
+            use ::coresimd::simd::$id;
+            let mut vec = $id::splat(0 as $elem_ty);
+            vec[$id::lanes()] = 1 as $elem_ty;
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_bool_array {
+    ($id:ident) => {
+        #[test]
+        fn array() {
+            use ::coresimd::simd::$id;
+            use ::std::convert::{From, Into};
+
+            let mut array = [false; $id::lanes()];
+            for i in 0..$id::lanes() {
+                array[i] = i % 2 == 0;
+            }
+
+            // From<[bool; N]>:
+            let vec = $id::from(array);
+            for i in 0..$id::lanes() {
+                assert_eq!(vec.extract(i), i % 2 == 0);
+            }
+
+            // Into<[bool; N]>:
+            let round_trip: [bool; $id::lanes()] = vec.into();
+            assert_eq!(&round_trip[..], &array[..]);
+        }
+    }
+}
@@ -0,0 +1,270 @@
+//! `bfloat16` floating-point vector types.
+//!
+//! These are storage-only types: they do not support arithmetic, but can be
+//! converted from and to single-precision floating-point vectors with the
+//! same number of lanes. Their lanes are stored as the `u16` bit patterns of
+//! `bfloat16` values, that is, the 16 most significant bits of the
+//! corresponding `f32` values, which can be accessed via `FromBits`.
+//!
+//! All conversions are implemented with portable shuffles and integer
+//! operations.
+
+/// Returns the even and the odd lanes of the vector `$v` with `N` lanes as
+/// two vectors of type `$half` with `N / 2` lanes.
+macro_rules! even_odd_lanes {
+    ($half:ident, 8, $v:expr) => {{
+        let v = $v;
+        let even: $half = simd_shuffle4(v, v, [0, 2, 4, 6]);
+        let odd: $half = simd_shuffle4(v, v, [1, 3, 5, 7]);
+        (even, odd)
+    }};
+    ($half:ident, 16, $v:expr) => {{
+        let v = $v;
+        let even: $half = simd_shuffle8(v, v, [0, 2, 4, 6, 8, 10, 12, 14]);
+        let odd: $half = simd_shuffle8(v, v, [1, 3, 5, 7, 9, 11, 13, 15]);
+        (even, odd)
+    }};
+}
+
+/// Defines a portable packed SIMD `bfloat16` floating-point vector type.
+macro_rules! simd_bf16_ty {
+    ($id:ident : $elem_count:tt, $f32_ty:ident, $bits_ty:ident,
+     $wide_ty:ident, $dot_ty:ident, $test_mod:ident |
+     $($elem_tys:ident),+ | $(#[$doc:meta])*) => {
+        $(#[$doc])*
+        #[repr(simd)]
+        #[derive(Copy)]
+        #[allow(non_camel_case_types)]
+        pub struct $id($($elem_tys),*);
+
+        #[cfg_attr(feature = "cargo-clippy", allow(expl_impl_clone_on_copy))]
+        impl Clone for $id {
+            #[inline] // currently needed for correctness
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl Default for $id {
+            /// Returns a vector of positive zeros.
+            #[inline]
+            fn default() -> Self {
+                unsafe { mem::transmute($bits_ty::splat(0)) }
+            }
+        }
+
+        impl_f16_serde!($id, $f32_ty, $elem_count);
+        impl_arbitrary!($id, from_f32 $f32_ty);
+
+        impl fmt::Debug for $id {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}(", stringify!($id))?;
+                let v = self.to_f32();
+                for i in 0..$elem_count {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    v.extract(i).fmt(f)?;
+                }
+                write!(f, ")")
+            }
+        }
+
+        impl $id {
+            /// The number of vector lanes.
+            pub const LANES: usize = $elem_count;
+
+            /// Returns the number of vector lanes.
+            #[inline]
+            pub const fn lanes() -> usize {
+                $elem_count
+            }
+
+            /// Converts a single-precision floating-point vector into a
+            /// `bfloat16` one, rounding each lane to the nearest
+            /// representable value (ties to even).
+            ///
+            /// Values too large to be represented become infinities, and
+            /// NaNs remain NaNs.
+            #[inline]
+            pub fn from_f32(x: $f32_ty) -> Self {
+                let bits: $wide_ty = ::simd::FromBits::from_bits(x);
+                // Adding `0x7fff` and the lowest retained bit carries into
+                // the retained bits if the discarded ones are above half,
+                // or exactly half and the retained bits are odd:
+                let odd = (bits >> 16) & $wide_ty::splat(1);
+                let rounded = (bits + $wide_ty::splat(0x7fff) + odd) >> 16;
+                Self::from_wide_bits(x, rounded)
+            }
+
+            /// Converts a single-precision floating-point vector into a
+            /// `bfloat16` one, rounding each lane towards zero by
+            /// discarding the 16 least significant bits of its mantissa.
+            ///
+            /// NaNs remain NaNs.
+            #[inline]
+            pub fn from_f32_truncated(x: $f32_ty) -> Self {
+                let bits: $wide_ty = ::simd::FromBits::from_bits(x);
+                Self::from_wide_bits(x, bits >> 16)
+            }
+
+            /// Narrows the `bfloat16` bit patterns `bits` of `x`, quieting
+            /// its NaN lanes, which might otherwise become infinities.
+            #[inline]
+            fn from_wide_bits(x: $f32_ty, bits: $wide_ty) -> Self {
+                let f32_bits: $wide_ty = ::simd::FromBits::from_bits(x);
+                let nan = (f32_bits >> 16) | $wide_ty::splat(0x40);
+                let bits: $wide_ty =
+                    unsafe { simd_select(x.ne(x), nan, bits) };
+                unsafe { mem::transmute($bits_ty::from(bits)) }
+            }
+
+            /// Converts the vector into a single-precision floating-point
+            /// vector. This conversion is exact.
+            #[inline]
+            pub fn to_f32(self) -> $f32_ty {
+                let bits: $bits_ty = unsafe { mem::transmute(self) };
+                ::simd::FromBits::from_bits($wide_ty::from(bits) << 16)
+            }
+
+            /// Pairwise widening dot product: the lane `i` of the result is
+            /// `self[2 * i] * other[2 * i] + self[2 * i + 1] * other[2 * i +
+            /// 1]`, computed in single precision.
+            ///
+            /// The products are exact, and their sum is rounded once.
+            #[inline]
+            pub fn dot_pairs_widening(self, other: Self) -> $dot_ty {
+                let p = self.to_f32() * other.to_f32();
+                let (even, odd) =
+                    unsafe { even_odd_lanes!($dot_ty, $elem_count, p) };
+                even + odd
+            }
+        }
+
+        #[cfg(test)]
+        mod $test_mod {
+            test_bf16!($id, $f32_ty, $bits_ty, $dot_ty);
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_bf16 {
+    ($id:ident, $f32_ty:ident, $bits_ty:ident, $dot_ty:ident) => {
+        /// Scalar reference of the conversion of `x` into the bit pattern
+        /// of a `bfloat16`, rounding to nearest (ties to even) if `round`,
+        /// and towards zero otherwise.
+        fn ref_from_f32(x: f32, round: bool) -> u16 {
+            let bits = x.to_bits();
+            let (hi, lo) = (bits >> 16, bits & 0xffff);
+            let above_half = lo > 0x8000 || (lo == 0x8000 && hi & 1 == 1);
+            if x.is_nan() {
+                (hi | 0x40) as u16
+            } else if round && above_half {
+                (hi + 1) as u16
+            } else {
+                hi as u16
+            }
+        }
+
+        /// Scalar reference of the conversion of the bit pattern of a
+        /// `bfloat16` into a single-precision float.
+        fn ref_to_f32(h: u16) -> f32 {
+            f32::from_bits((h as u32) << 16)
+        }
+
+        const VALUES: [u32; 16] = [
+            0x3f80_0000, // 1
+            0xc020_0000, // -2.5
+            0x3f80_8000, // tie, rounds down to even
+            0x3f81_8000, // tie, rounds up to even
+            0x3f80_8001, // above half
+            0xbf80_7fff, // below half
+            0x7f7f_ffff, // max, rounds to infinity
+            0x0000_ffff, // subnormal
+            0x8000_0000, // -0
+            0x7f80_0000, // infinity
+            0x7f80_0001, // signaling NaN, with a payload in the low bits
+            0xffc0_0000, // quiet NaN
+            0x4049_0fdb, // pi
+            0x3eaa_aaab, // 1 / 3
+            0xc2f6_e979, // -123.456
+            0x0080_0000, // smallest normal
+        ];
+
+        #[test]
+        fn bf16_from_f32() {
+            use ::coresimd::simd::{$bits_ty, $f32_ty, $id, FromBits};
+
+            for offset in 0..VALUES.len() {
+                let mut x = $f32_ty::splat(0.);
+                for i in 0..$id::lanes() {
+                    let v = VALUES[(i + offset) % VALUES.len()];
+                    x = x.replace(i, f32::from_bits(v));
+                }
+                let r = $bits_ty::from_bits($id::from_f32(x));
+                let t = $bits_ty::from_bits($id::from_f32_truncated(x));
+                for i in 0..$id::lanes() {
+                    let x = x.extract(i);
+                    assert_eq!(r.extract(i), ref_from_f32(x, true));
+                    assert_eq!(t.extract(i), ref_from_f32(x, false));
+                }
+            }
+        }
+
+        #[test]
+        fn bf16_to_f32() {
+            use ::coresimd::simd::{$bits_ty, $id, FromBits};
+
+            for offset in 0..VALUES.len() {
+                let mut b = $bits_ty::splat(0);
+                for i in 0..$id::lanes() {
+                    let v = VALUES[(i + offset) % VALUES.len()];
+                    b = b.replace(i, (v >> 16) as u16);
+                }
+                let x = $id::from_bits(b).to_f32();
+                for i in 0..$id::lanes() {
+                    let e = ref_to_f32(b.extract(i));
+                    assert_eq!(x.extract(i).to_bits(), e.to_bits());
+                }
+            }
+        }
+
+        #[test]
+        fn bf16_dot_pairs_widening() {
+            use ::coresimd::simd::{$bits_ty, $dot_ty, $id, FromBits};
+
+            let mut a = $bits_ty::splat(0);
+            let mut b = $bits_ty::splat(0);
+            for i in 0..$id::lanes() {
+                // finite values only:
+                let x = f32::from_bits(VALUES[i % 6]) * (i as f32 + 0.7);
+                let y = f32::from_bits(VALUES[12 + i % 4]) / (i as f32 + 1.);
+                a = a.replace(i, ref_from_f32(x, true));
+                b = b.replace(i, ref_from_f32(y, true));
+            }
+            let d = $id::from_bits(a).dot_pairs_widening($id::from_bits(b));
+            for i in 0..$dot_ty::lanes() {
+                let p = |j: usize| {
+                    ref_to_f32(a.extract(j)) * ref_to_f32(b.extract(j))
+                };
+                let e = p(2 * i) + p(2 * i + 1);
+                assert_eq!(d.extract(i).to_bits(), e.to_bits());
+            }
+        }
+
+        #[test]
+        fn bf16_debug() {
+            use ::coresimd::simd::{$f32_ty, $id};
+            use ::std::prelude::v1::*;
+
+            let h = $id::from_f32($f32_ty::splat(1.5));
+            let s = format!("{:?}", h);
+            let e = format!("{:?}", $f32_ty::splat(1.5))
+                .replace(stringify!($f32_ty), stringify!($id));
+            assert_eq!(s, e);
+            assert_eq!($id::default().to_f32(), $f32_ty::splat(0.));
+            assert_eq!($id::LANES, $f32_ty::lanes());
+        }
+    }
+}
@@ -26,6 +26,9 @@ macro_rules! impl_bool_minimal {
                 [0 as $elem_ty, !(0 as $elem_ty)][x as usize]
             }
 
+            /// The number of vector lanes.
+            pub const LANES: usize = $elem_count;
+
             /// Returns the number of vector lanes.
             #[inline]
             pub const fn lanes() -> usize {
@@ -103,6 +106,7 @@ macro_rules! test_bool_minimal {
 
             // lanes:
             assert_eq!($elem_count, $id::lanes());
+            assert_eq!($elem_count, $id::LANES);
 
             // splat and extract / extract_unchecked:
             let vec = $id::splat(true);
@@ -145,3 +149,46 @@ macro_rules! test_bool_minimal {
         }
     }
 }
+
+/// Tests the minimal API and the reductions of boolean vectors with a
+/// single lane, to which some of the tests above do not apply.
+#[cfg(test)]
+macro_rules! test_bool_single_lane {
+    ($id:ident) => {
+        #[test]
+        fn single_lane() {
+            use ::coresimd::simd::$id;
+            assert_eq!(1, $id::lanes());
+            assert_eq!(1, $id::LANES);
+
+            for &x in &[false, true] {
+                let v = $id::splat(x);
+                assert_eq!(x, v.extract(0));
+                assert_eq!(x, unsafe { v.extract_unchecked(0) });
+                assert_eq!(!x, v.replace(0, !x).extract(0));
+                let new_vec = unsafe { v.replace_unchecked(0, !x) };
+                assert_eq!(!x, new_vec.extract(0));
+
+                // the reductions of a single lane are the lane itself:
+                assert_eq!(x, v.all());
+                assert_eq!(x, v.any());
+                assert_eq!(!x, v.none());
+                assert_eq!(x, v.and());
+                assert_eq!(x, v.or());
+                assert_eq!(x, v.xor());
+            }
+        }
+        #[test]
+        #[should_panic]
+        fn single_lane_extract_panic_on_out_of_bounds() {
+            use ::coresimd::simd::$id;
+            let _ = $id::splat(false).extract(1);
+        }
+        #[test]
+        #[should_panic]
+        fn single_lane_replace_panic_on_out_of_bounds() {
+            use ::coresimd::simd::$id;
+            let _ = $id::splat(false).replace(1, true);
+        }
+    }
+}
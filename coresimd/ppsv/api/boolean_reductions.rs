@@ -6,17 +6,17 @@ macro_rules! impl_bool_reductions {
             /// Are `all` vector lanes `true`?
             #[inline]
             pub fn all(self) -> bool {
-                self.and()
+                AllAny::all(self)
             }
             /// Is `any` vector lanes `true`?
             #[inline]
             pub fn any(self) -> bool {
-                self.or()
+                AllAny::any(self)
             }
             /// Are `all` vector lanes `false`?
             #[inline]
             pub fn none(self) -> bool {
-                !self.or()
+                !AllAny::any(self)
             }
         }
     }
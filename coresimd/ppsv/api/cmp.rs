@@ -1,4 +1,9 @@
 //! Lane-wise vector comparisons returning boolean vectors.
+//!
+//! For floating-point vectors, all comparisons except `ne` are ordered: they
+//! are `false` for the lanes in which either operand is `NaN`, while `ne` is
+//! `true` for these lanes. The unordered comparisons are implemented in
+//! `float_class`.
 
 macro_rules! impl_cmp {
     ($id:ident, $bool_ty:ident) => {
@@ -0,0 +1,208 @@
+//! Implements the arithmetic of complex numbers stored as interleaved
+//! `(re, im)` lane pairs of floating-point vectors, e.g., `f32x4` holds two
+//! complex numbers `(x0 + i * x1, x2 + i * x3)`.
+
+/// Shuffles the interleaved `(re, im)` lane pairs of vectors with `N` lanes:
+///
+/// * `re`: duplicates the real lanes, `[x0, x0, x2, x2, ...]`,
+/// * `im`: duplicates the imaginary lanes, `[x1, x1, x3, x3, ...]`,
+/// * `swap`: swaps the lanes of each pair, `[x1, x0, x3, x2, ...]`,
+/// * `alternate`: takes the real lanes of `$a` and the imaginary lanes of
+///   `$b`, `[a0, b1, a2, b3, ...]`.
+macro_rules! complex_lanes {
+    ($id:ident, re, 2, $v:expr) => {{
+        let v = $v;
+        let r: $id = simd_shuffle2(v, v, [0, 0]);
+        r
+    }};
+    ($id:ident, im, 2, $v:expr) => {{
+        let v = $v;
+        let r: $id = simd_shuffle2(v, v, [1, 1]);
+        r
+    }};
+    ($id:ident, swap, 2, $v:expr) => {{
+        let v = $v;
+        let r: $id = simd_shuffle2(v, v, [1, 0]);
+        r
+    }};
+    ($id:ident, alternate, 2, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle2($a, $b, [0, 3]);
+        r
+    }};
+    ($id:ident, re, 4, $v:expr) => {{
+        let v = $v;
+        let r: $id = simd_shuffle4(v, v, [0, 0, 2, 2]);
+        r
+    }};
+    ($id:ident, im, 4, $v:expr) => {{
+        let v = $v;
+        let r: $id = simd_shuffle4(v, v, [1, 1, 3, 3]);
+        r
+    }};
+    ($id:ident, swap, 4, $v:expr) => {{
+        let v = $v;
+        let r: $id = simd_shuffle4(v, v, [1, 0, 3, 2]);
+        r
+    }};
+    ($id:ident, alternate, 4, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle4($a, $b, [0, 5, 2, 7]);
+        r
+    }};
+    ($id:ident, re, 8, $v:expr) => {{
+        let v = $v;
+        let r: $id = simd_shuffle8(v, v, [0, 0, 2, 2, 4, 4, 6, 6]);
+        r
+    }};
+    ($id:ident, im, 8, $v:expr) => {{
+        let v = $v;
+        let r: $id = simd_shuffle8(v, v, [1, 1, 3, 3, 5, 5, 7, 7]);
+        r
+    }};
+    ($id:ident, swap, 8, $v:expr) => {{
+        let v = $v;
+        let r: $id = simd_shuffle8(v, v, [1, 0, 3, 2, 5, 4, 7, 6]);
+        r
+    }};
+    ($id:ident, alternate, 8, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle8($a, $b, [0, 9, 2, 11, 4, 13, 6, 15]);
+        r
+    }};
+    ($id:ident, re, 16, $v:expr) => {{
+        let v = $v;
+        let r: $id = simd_shuffle16(
+            v,
+            v,
+            [0, 0, 2, 2, 4, 4, 6, 6, 8, 8, 10, 10, 12, 12, 14, 14],
+        );
+        r
+    }};
+    ($id:ident, im, 16, $v:expr) => {{
+        let v = $v;
+        let r: $id = simd_shuffle16(
+            v,
+            v,
+            [1, 1, 3, 3, 5, 5, 7, 7, 9, 9, 11, 11, 13, 13, 15, 15],
+        );
+        r
+    }};
+    ($id:ident, swap, 16, $v:expr) => {{
+        let v = $v;
+        let r: $id = simd_shuffle16(
+            v,
+            v,
+            [1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14],
+        );
+        r
+    }};
+    ($id:ident, alternate, 16, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle16(
+            $a,
+            $b,
+            [0, 17, 2, 19, 4, 21, 6, 23, 8, 25, 10, 27, 12, 29, 14, 31],
+        );
+        r
+    }};
+}
+
+macro_rules! impl_complex {
+    ($id:ident: $elem_count:tt, $elem_ty:ident, $test_mod:ident) => {
+        impl $id {
+            /// Complex multiplication of the `(re, im)` lane pairs of
+            /// `self` and `other`.
+            ///
+            /// On `x86`/`x86_64` this uses `addsubps`/`addsubpd` when
+            /// `sse3` is enabled (`vaddsubps`/`vaddsubpd` for 256-bit
+            /// vectors when `avx` is enabled), and `vfmaddsub` when `fma`
+            /// is enabled.
+            #[inline]
+            pub fn complex_mul(self, other: Self) -> Self {
+                use super::codegen::complex::MulAddSub;
+                unsafe {
+                    let re = complex_lanes!($id, re, $elem_count, other);
+                    let im = complex_lanes!($id, im, $elem_count, other);
+                    let swap = complex_lanes!($id, swap, $elem_count, self);
+                    MulAddSub::mul_addsub(self, re, swap * im)
+                }
+            }
+
+            /// Complex fused multiply-add `self * b + c` of the
+            /// `(re, im)` lane pairs of `self`, `b`, and `c`.
+            ///
+            /// The products and sums are fused into a single rounding per
+            /// step when `fma` is enabled on `x86`/`x86_64`.
+            #[inline]
+            pub fn complex_mul_add(self, b: Self, c: Self) -> Self {
+                use super::codegen::complex::MulAddSub;
+                unsafe {
+                    let re = complex_lanes!($id, re, $elem_count, b);
+                    let im = complex_lanes!($id, im, $elem_count, b);
+                    let swap = complex_lanes!($id, swap, $elem_count, self);
+                    let c = MulAddSub::mul_addsub(swap, im, c);
+                    MulAddSub::mul_addsub(self, re, c)
+                }
+            }
+
+            /// Complex conjugate of the `(re, im)` lane pairs: negates
+            /// the imaginary lanes.
+            #[inline]
+            pub fn complex_conj(self) -> Self {
+                unsafe {
+                    complex_lanes!($id, alternate, $elem_count, self, -self)
+                }
+            }
+
+            /// Squared magnitude `re * re + im * im` of the `(re, im)` lane
+            /// pairs, stored in both lanes of each pair.
+            ///
+            /// The reciprocal of the complex numbers is thus
+            /// `x.complex_conj() / x.complex_norm_sqr()`.
+            #[inline]
+            pub fn complex_norm_sqr(self) -> Self {
+                let sq = self * self;
+                sq + unsafe { complex_lanes!($id, swap, $elem_count, sq) }
+            }
+        }
+
+        #[cfg(test)]
+        mod $test_mod {
+            use ::coresimd::simd::$id;
+
+            #[test]
+            fn complex() {
+                let mut a = $id::splat(0 as $elem_ty);
+                let mut b = a;
+                let mut c = a;
+                for i in 0..$id::lanes() {
+                    a = a.replace(i, i as $elem_ty + 1.);
+                    b = b.replace(i, 3. - i as $elem_ty);
+                    c = c.replace(i, (i % 3) as $elem_ty - 1.);
+                }
+                let mul = a.complex_mul(b);
+                let mul_add = a.complex_mul_add(b, c);
+                let conj = a.complex_conj();
+                let norm_sqr = a.complex_norm_sqr();
+                for i in 0..$id::lanes() / 2 {
+                    let (re, im) = (2 * i, 2 * i + 1);
+                    let (ar, ai) = (a.extract(re), a.extract(im));
+                    let (br, bi) = (b.extract(re), b.extract(im));
+                    let (er, ei) = (ar * br - ai * bi, ar * bi + ai * br);
+                    assert_eq!(mul.extract(re), er);
+                    assert_eq!(mul.extract(im), ei);
+                    assert_eq!(mul_add.extract(re), er + c.extract(re));
+                    assert_eq!(mul_add.extract(im), ei + c.extract(im));
+                    assert_eq!(conj.extract(re), ar);
+                    assert_eq!(conj.extract(im), -ai);
+                    assert_eq!(norm_sqr.extract(re), ar * ar + ai * ai);
+                    assert_eq!(norm_sqr.extract(im), ar * ar + ai * ai);
+                }
+
+                // The reciprocal:
+                let p = a.complex_mul(conj / norm_sqr);
+                for i in 0..$id::lanes() / 2 {
+                    assert!((p.extract(2 * i) - 1.).abs() < 1e-5);
+                    assert!(p.extract(2 * i + 1).abs() < 1e-5);
+                }
+            }
+        }
+    };
+}
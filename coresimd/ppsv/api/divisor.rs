@@ -0,0 +1,155 @@
+//! Implements the high half of the widening multiplication of unsigned
+//! integer vectors, and their division by precomputed `Divisor`s.
+
+macro_rules! impl_divisor {
+    ($id:ident: $elem_ty:ident, $wide_elem_ty:ident, $test_mod:ident) => {
+        impl $id {
+            /// Lane-wise high half of the widening multiplication: the lane
+            /// `i` of the result is `(self[i] * other[i]) >> bits`, where
+            /// `bits` is the width of the lanes, computed as if in lanes
+            /// twice as wide.
+            ///
+            /// On `x86`/`x86_64` this uses `pmulhuw` for `u16x8` and
+            /// `pmuludq` for `u32x4` when `sse2` is enabled, and their
+            /// 256-bit versions when `avx2` is enabled.
+            #[inline]
+            pub fn mul_high(self, other: Self) -> Self {
+                use super::codegen::mul_high::MulHigh;
+                MulHigh::mul_high(self, other)
+            }
+        }
+
+        impl Divisor<$id> {
+            /// Precomputes the division of the lanes of vectors by `d`.
+            ///
+            /// # Panics
+            ///
+            /// If `d == 0`.
+            #[inline]
+            pub fn new(d: $elem_ty) -> Self {
+                assert!(d != 0, "attempt to divide by zero");
+                let bits = mem::size_of::<$elem_ty>() as u32 * 8;
+                let d_wide = d as $wide_elem_ty;
+                // `l = ceil(log2(d))`:
+                let mut l = 0;
+                while (1 as $wide_elem_ty) << l < d_wide {
+                    l += 1;
+                }
+                // `mul = floor(2^bits * (2^l - d) / d) + 1`, which is less
+                // than `2^bits`:
+                let mul = (((1 as $wide_elem_ty) << l) - d_wide) << bits;
+                let mul = mul / d_wide + 1;
+                Divisor {
+                    divisor: $id::splat(d),
+                    mul: $id::splat(mul as $elem_ty),
+                    shift1: if l == 0 { 0 } else { 1 },
+                    shift2: if l == 0 { 0 } else { l - 1 },
+                }
+            }
+
+            /// Returns the divisor.
+            #[inline]
+            pub fn divisor(&self) -> $elem_ty {
+                self.divisor.extract(0)
+            }
+
+            /// Returns the lane-wise quotient and remainder of `x` divided
+            /// by the divisor.
+            #[inline]
+            pub fn div_rem(&self, x: $id) -> ($id, $id) {
+                let q = x / *self;
+                (q, x - q * self.divisor)
+            }
+        }
+
+        impl ops::Div<Divisor<$id>> for $id {
+            type Output = Self;
+            /// Lane-wise quotient of `self` divided by `d`.
+            #[inline]
+            fn div(self, d: Divisor<$id>) -> Self {
+                let q = self.mul_high(d.mul);
+                // `q <= self`, and `q + (self - q) / 2` cannot overflow,
+                // unlike `(q + self) / 2`:
+                (q + ((self - q) >> d.shift1)) >> d.shift2
+            }
+        }
+
+        impl ops::Rem<Divisor<$id>> for $id {
+            type Output = Self;
+            /// Lane-wise remainder of `self` divided by `d`.
+            #[inline]
+            fn rem(self, d: Divisor<$id>) -> Self {
+                self - (self / d) * d.divisor
+            }
+        }
+
+        #[cfg(test)]
+        mod $test_mod {
+            use ::coresimd::simd::{$id, Divisor};
+
+            #[test]
+            fn mul_high() {
+                let max = !(0 as $elem_ty);
+                let mut a = $id::splat(0);
+                let mut b = $id::splat(0);
+                for i in 0..$id::lanes() {
+                    a = a.replace(i, max - (i as $elem_ty) * 0x1111);
+                    b = b.replace(i, (i as $elem_ty + 1) * 0x0f0f);
+                }
+                let r = a.mul_high(b);
+                for i in 0..$id::lanes() {
+                    let (a, b) = (a.extract(i), b.extract(i));
+                    let e = (a as $wide_elem_ty * b as $wide_elem_ty)
+                        >> (::std::mem::size_of::<$elem_ty>() * 8);
+                    assert_eq!(r.extract(i), e as $elem_ty);
+                }
+            }
+
+            #[test]
+            fn divisor() {
+                let max = !(0 as $elem_ty);
+                let divisors = [
+                    1, 2, 3, 5, 6, 7, 10, 64, 641, 0x7ff, max / 3, max / 2,
+                    max / 2 + 1, max - 1, max,
+                ];
+                let mut seed: u64 = 0x9e37_79b9_7f4a_7c15;
+                for &d in divisors.iter() {
+                    let divisor = Divisor::<$id>::new(d);
+                    assert_eq!(divisor.divisor(), d);
+                    let edges = [
+                        0, 1, d - 1, d, d.wrapping_add(1), max - 1, max,
+                        d.wrapping_mul(3),
+                    ];
+                    for round in 0..16 {
+                        let mut x = $id::splat(0);
+                        for i in 0..$id::lanes() {
+                            seed = seed
+                                .wrapping_mul(6364136223846793005)
+                                .wrapping_add(1442695040888963407);
+                            let v = if round == 0 {
+                                edges[i % edges.len()]
+                            } else {
+                                (seed >> 32) as $elem_ty
+                            };
+                            x = x.replace(i, v);
+                        }
+                        let (q, r) = divisor.div_rem(x);
+                        assert_eq!(x / divisor, q);
+                        assert_eq!(x % divisor, r);
+                        for i in 0..$id::lanes() {
+                            let x = x.extract(i);
+                            assert_eq!(q.extract(i), x / d, "{} / {}", x, d);
+                            assert_eq!(r.extract(i), x % d, "{} % {}", x, d);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn divisor_zero() {
+                let _ = Divisor::<$id>::new(0);
+            }
+        }
+    };
+}
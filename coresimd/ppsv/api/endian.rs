@@ -0,0 +1,323 @@
+//! Implements endianness conversions of integer vectors.
+//!
+//! The bytes of the lanes are reversed with a byte shuffle, which LLVM
+//! lowers to `pshufb` on `x86`/`x86_64` with `ssse3`, and to `vrev16`,
+//! `vrev32`, or `vrev64` on ARM and AArch64. These are the vector
+//! counterparts of the scalar `_bswap`/`_bswap64` (`x86`) and `_rev_u32`
+//! (ARM) intrinsics.
+
+/// Reverses the order of the bytes of each `L`-byte lane of the byte vector
+/// `$v` of `N` bytes.
+macro_rules! swap_lane_bytes {
+    ($id:ident, 2, 4, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle4(v, v, [1, 0, 3, 2]);
+        r
+    }};
+    ($id:ident, 2, 8, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle8(v, v, [1, 0, 3, 2, 5, 4, 7, 6]);
+        r
+    }};
+    ($id:ident, 2, 16, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle16(
+            v,
+            v,
+            [1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14],
+        );
+        r
+    }};
+    ($id:ident, 2, 32, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle32(
+            v,
+            v,
+            [
+                1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14, 17, 16,
+                19, 18, 21, 20, 23, 22, 25, 24, 27, 26, 29, 28, 31, 30
+            ],
+        );
+        r
+    }};
+    ($id:ident, 2, 64, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle64(
+            v,
+            v,
+            [
+                1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14, 17, 16,
+                19, 18, 21, 20, 23, 22, 25, 24, 27, 26, 29, 28, 31, 30, 33, 32,
+                35, 34, 37, 36, 39, 38, 41, 40, 43, 42, 45, 44, 47, 46, 49, 48,
+                51, 50, 53, 52, 55, 54, 57, 56, 59, 58, 61, 60, 63, 62
+            ],
+        );
+        r
+    }};
+    ($id:ident, 4, 8, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle8(v, v, [3, 2, 1, 0, 7, 6, 5, 4]);
+        r
+    }};
+    ($id:ident, 4, 16, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle16(
+            v,
+            v,
+            [3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12],
+        );
+        r
+    }};
+    ($id:ident, 4, 32, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle32(
+            v,
+            v,
+            [
+                3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12, 19, 18,
+                17, 16, 23, 22, 21, 20, 27, 26, 25, 24, 31, 30, 29, 28
+            ],
+        );
+        r
+    }};
+    ($id:ident, 4, 64, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle64(
+            v,
+            v,
+            [
+                3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12, 19, 18,
+                17, 16, 23, 22, 21, 20, 27, 26, 25, 24, 31, 30, 29, 28, 35, 34,
+                33, 32, 39, 38, 37, 36, 43, 42, 41, 40, 47, 46, 45, 44, 51, 50,
+                49, 48, 55, 54, 53, 52, 59, 58, 57, 56, 63, 62, 61, 60
+            ],
+        );
+        r
+    }};
+    ($id:ident, 8, 16, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle16(
+            v,
+            v,
+            [7, 6, 5, 4, 3, 2, 1, 0, 15, 14, 13, 12, 11, 10, 9, 8],
+        );
+        r
+    }};
+    ($id:ident, 8, 32, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle32(
+            v,
+            v,
+            [
+                7, 6, 5, 4, 3, 2, 1, 0, 15, 14, 13, 12, 11, 10, 9, 8, 23, 22,
+                21, 20, 19, 18, 17, 16, 31, 30, 29, 28, 27, 26, 25, 24
+            ],
+        );
+        r
+    }};
+    ($id:ident, 8, 64, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle64(
+            v,
+            v,
+            [
+                7, 6, 5, 4, 3, 2, 1, 0, 15, 14, 13, 12, 11, 10, 9, 8, 23, 22,
+                21, 20, 19, 18, 17, 16, 31, 30, 29, 28, 27, 26, 25, 24, 39, 38,
+                37, 36, 35, 34, 33, 32, 47, 46, 45, 44, 43, 42, 41, 40, 55, 54,
+                53, 52, 51, 50, 49, 48, 63, 62, 61, 60, 59, 58, 57, 56
+            ],
+        );
+        r
+    }};
+    ($id:ident, 16, 16, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle16(
+            v,
+            v,
+            [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+        );
+        r
+    }};
+    ($id:ident, 16, 32, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle32(
+            v,
+            v,
+            [
+                15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 31, 30,
+                29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16
+            ],
+        );
+        r
+    }};
+    ($id:ident, 16, 64, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle64(
+            v,
+            v,
+            [
+                15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 31, 30,
+                29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16, 47, 46,
+                45, 44, 43, 42, 41, 40, 39, 38, 37, 36, 35, 34, 33, 32, 63, 62,
+                61, 60, 59, 58, 57, 56, 55, 54, 53, 52, 51, 50, 49, 48
+            ],
+        );
+        r
+    }};
+}
+
+macro_rules! impl_endian {
+    ($id:ident: 1, $elem_ty:ident, $bytes_ty:ident, $bytes:tt,
+     $test_mod:ident) => {
+        impl $id {
+            /// Reverses the byte order of the lanes, which has no effect
+            /// on 8-bit lanes.
+            #[inline]
+            pub fn swap_bytes(self) -> Self {
+                self
+            }
+        }
+
+        impl_endian!(@common $id, $elem_ty, $bytes_ty, $bytes, $test_mod);
+    };
+    ($id:ident: $lane_bytes:tt, $elem_ty:ident, $bytes_ty:ident, $bytes:tt,
+     $test_mod:ident) => {
+        impl $id {
+            /// Reverses the byte order of the lanes.
+            #[inline]
+            pub fn swap_bytes(self) -> Self {
+                let v: $bytes_ty = ::simd::FromBits::from_bits(self);
+                let v = unsafe {
+                    swap_lane_bytes!($bytes_ty, $lane_bytes, $bytes, v)
+                };
+                ::simd::FromBits::from_bits(v)
+            }
+        }
+
+        impl_endian!(@common $id, $elem_ty, $bytes_ty, $bytes, $test_mod);
+    };
+    (@common $id:ident, $elem_ty:ident, $bytes_ty:ident, $bytes:tt,
+     $test_mod:ident) => {
+        impl $id {
+            /// Converts the lanes from little endian to the target's
+            /// endianness.
+            ///
+            /// On little endian targets this is a no-op. On big endian
+            /// targets the bytes of the lanes are swapped.
+            #[inline]
+            pub fn from_le(x: Self) -> Self {
+                x.to_le()
+            }
+
+            /// Converts the lanes from big endian to the target's
+            /// endianness.
+            ///
+            /// On big endian targets this is a no-op. On little endian
+            /// targets the bytes of the lanes are swapped.
+            #[inline]
+            pub fn from_be(x: Self) -> Self {
+                x.to_be()
+            }
+
+            /// Converts the lanes to little endian from the target's
+            /// endianness.
+            ///
+            /// On little endian targets this is a no-op. On big endian
+            /// targets the bytes of the lanes are swapped.
+            #[inline]
+            pub fn to_le(self) -> Self {
+                if cfg!(target_endian = "little") {
+                    self
+                } else {
+                    self.swap_bytes()
+                }
+            }
+
+            /// Converts the lanes to big endian from the target's
+            /// endianness.
+            ///
+            /// On big endian targets this is a no-op. On little endian
+            /// targets the bytes of the lanes are swapped.
+            #[inline]
+            pub fn to_be(self) -> Self {
+                if cfg!(target_endian = "big") {
+                    self
+                } else {
+                    self.swap_bytes()
+                }
+            }
+
+            /// Returns the memory representation of the vector as a byte
+            /// array, with the lanes in little-endian byte order.
+            #[inline]
+            pub fn to_le_bytes(self) -> [u8; $bytes] {
+                let v: $bytes_ty = ::simd::FromBits::from_bits(self.to_le());
+                v.into()
+            }
+
+            /// Returns the memory representation of the vector as a byte
+            /// array, with the lanes in big-endian byte order.
+            #[inline]
+            pub fn to_be_bytes(self) -> [u8; $bytes] {
+                let v: $bytes_ty = ::simd::FromBits::from_bits(self.to_be());
+                v.into()
+            }
+
+            /// Creates a vector from its memory representation as a byte
+            /// array, with the lanes in little-endian byte order.
+            #[inline]
+            pub fn from_le_bytes(bytes: [u8; $bytes]) -> Self {
+                let v = $bytes_ty::from(bytes);
+                Self::from_le(::simd::FromBits::from_bits(v))
+            }
+
+            /// Creates a vector from its memory representation as a byte
+            /// array, with the lanes in big-endian byte order.
+            #[inline]
+            pub fn from_be_bytes(bytes: [u8; $bytes]) -> Self {
+                let v = $bytes_ty::from(bytes);
+                Self::from_be(::simd::FromBits::from_bits(v))
+            }
+        }
+
+        #[cfg(test)]
+        mod $test_mod {
+            use ::coresimd::simd::$id;
+
+            #[test]
+            fn endian() {
+                let n = ::mem::size_of::<$elem_ty>();
+                let mut v = $id::splat(0);
+                for i in 0..$id::lanes() {
+                    let mut x = 0 as $elem_ty;
+                    for j in 0..n {
+                        x |= ((i * n + j + 1) as u8 as $elem_ty) << (8 * j);
+                    }
+                    v = v.replace(i, x);
+                }
+                let s = v.swap_bytes();
+                let le = v.to_le_bytes();
+                let be = v.to_be_bytes();
+                for i in 0..$id::lanes() {
+                    let x = v.extract(i);
+                    assert_eq!(s.extract(i), x.swap_bytes());
+                    assert_eq!(v.to_le().extract(i), x.to_le());
+                    assert_eq!(v.to_be().extract(i), x.to_be());
+                    let r = $id::from_le(v).extract(i);
+                    assert_eq!(r, $elem_ty::from_le(x));
+                    let r = $id::from_be(v).extract(i);
+                    assert_eq!(r, $elem_ty::from_be(x));
+                    for j in 0..n {
+                        // The lane `i` has the bytes `i * n + 1..` from the
+                        // least significant one:
+                        let b = (i * n + j + 1) as u8;
+                        assert_eq!(le[i * n + j], b);
+                        assert_eq!(be[i * n + n - 1 - j], b);
+                    }
+                }
+                assert_eq!($id::from_le_bytes(le), v);
+                assert_eq!($id::from_be_bytes(be), v);
+            }
+        }
+    };
+}
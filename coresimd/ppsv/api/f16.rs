@@ -0,0 +1,155 @@
+//! Half-precision floating-point vector types.
+//!
+//! These are storage-only types: they do not support arithmetic, but can be
+//! converted from and to single-precision floating-point vectors with the
+//! same number of lanes. Their lanes are stored as the `u16` bit patterns of
+//! IEEE 754 `binary16` values, which can be accessed via `FromBits`.
+
+/// Defines a portable packed SIMD half-precision floating-point vector type.
+macro_rules! simd_f16_ty {
+    ($id:ident : $elem_count:expr, $f32_ty:ident, $bits_ty:ident,
+     $test_mod:ident | $($elem_tys:ident),+ | $(#[$doc:meta])*) => {
+        $(#[$doc])*
+        #[repr(simd)]
+        #[derive(Copy)]
+        #[allow(non_camel_case_types)]
+        pub struct $id($($elem_tys),*);
+
+        #[cfg_attr(feature = "cargo-clippy", allow(expl_impl_clone_on_copy))]
+        impl Clone for $id {
+            #[inline] // currently needed for correctness
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl Default for $id {
+            /// Returns a vector of positive zeros.
+            #[inline]
+            fn default() -> Self {
+                unsafe { mem::transmute($bits_ty::splat(0)) }
+            }
+        }
+
+        impl_f16_serde!($id, $f32_ty, $elem_count);
+        impl_arbitrary!($id, from_f32 $f32_ty);
+
+        impl fmt::Debug for $id {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}(", stringify!($id))?;
+                let v = self.to_f32();
+                for i in 0..$elem_count {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    v.extract(i).fmt(f)?;
+                }
+                write!(f, ")")
+            }
+        }
+
+        impl $id {
+            /// The number of vector lanes.
+            pub const LANES: usize = $elem_count;
+
+            /// Returns the number of vector lanes.
+            #[inline]
+            pub const fn lanes() -> usize {
+                $elem_count
+            }
+
+            /// Converts a single-precision floating-point vector into a
+            /// half-precision one, rounding each lane to the nearest
+            /// representable value (ties to even).
+            ///
+            /// Values too large to be represented become infinities, and
+            /// NaNs remain NaNs.
+            #[inline]
+            pub fn from_f32(x: $f32_ty) -> Self {
+                use super::codegen::f16::ConvertF16;
+                unsafe { mem::transmute($bits_ty::f16_from_f32(x)) }
+            }
+
+            /// Converts the vector into a single-precision floating-point
+            /// vector. This conversion is exact.
+            #[inline]
+            pub fn to_f32(self) -> $f32_ty {
+                use super::codegen::f16::ConvertF16;
+                let bits: $bits_ty = unsafe { mem::transmute(self) };
+                bits.f16_to_f32()
+            }
+        }
+
+        #[cfg(test)]
+        mod $test_mod {
+            test_f16!($id, $f32_ty, $bits_ty);
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_f16 {
+    ($id:ident, $f32_ty:ident, $bits_ty:ident) => {
+        #[test]
+        fn f16_round_trip() {
+            use ::coresimd::simd::{$bits_ty, $f32_ty, $id, FromBits};
+
+            let values = [1., -2., 0.5, 65504., 0., -0.25, 3., 1. / 16384.];
+            let bits = [
+                0x3c00, 0xc000, 0x3800, 0x7bff, 0x0000, 0xb400, 0x4200, 0x0400,
+            ];
+            let mut x = $f32_ty::splat(0.);
+            let mut b = $bits_ty::splat(0);
+            for i in 0..$id::lanes() {
+                x = x.replace(i, values[i % values.len()]);
+                b = b.replace(i, bits[i % bits.len()]);
+            }
+
+            let h = $id::from_f32(x);
+            assert_eq!($bits_ty::from_bits(h), b);
+            assert_eq!(h.to_f32(), x);
+            assert_eq!($id::from_bits(b).to_f32(), x);
+        }
+
+        #[test]
+        fn f16_rounding() {
+            use ::coresimd::simd::{$bits_ty, $f32_ty, $id, FromBits};
+
+            // ties to even:
+            let h = $id::from_f32($f32_ty::splat(1. + 1. / 2048.));
+            assert_eq!($bits_ty::from_bits(h), $bits_ty::splat(0x3c00));
+            let h = $id::from_f32($f32_ty::splat(1. + 3. / 2048.));
+            assert_eq!($bits_ty::from_bits(h), $bits_ty::splat(0x3c02));
+            // overflow:
+            let h = $id::from_f32($f32_ty::splat(1e6));
+            assert_eq!($bits_ty::from_bits(h), $bits_ty::splat(0x7c00));
+            let h = $id::from_f32($f32_ty::splat(-1. / 0.));
+            assert_eq!($bits_ty::from_bits(h), $bits_ty::splat(0xfc00));
+            // underflow to subnormals and zero:
+            let h = $id::from_f32($f32_ty::splat(1. / 16777216.));
+            assert_eq!($bits_ty::from_bits(h), $bits_ty::splat(0x0001));
+            let h = $id::from_f32($f32_ty::splat(1e-10));
+            assert_eq!($bits_ty::from_bits(h), $bits_ty::splat(0x0000));
+            // NaN:
+            let h = $id::from_f32($f32_ty::splat(0. / 0.));
+            let v = h.to_f32();
+            for i in 0..$id::lanes() {
+                assert!(v.extract(i).is_nan());
+            }
+        }
+
+        #[test]
+        fn f16_debug() {
+            use ::coresimd::simd::{$f32_ty, $id};
+            use ::std::prelude::v1::*;
+
+            let h = $id::from_f32($f32_ty::splat(1.5));
+            let s = format!("{:?}", h);
+            let e = format!("{:?}", $f32_ty::splat(1.5))
+                .replace(stringify!($f32_ty), stringify!($id));
+            assert_eq!(s, e);
+            assert_eq!($id::default().to_f32(), $f32_ty::splat(0.));
+            assert_eq!($id::LANES, $f32_ty::lanes());
+        }
+    }
+}
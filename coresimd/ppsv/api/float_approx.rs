@@ -0,0 +1,137 @@
+//! Implements the approximate reciprocal and reciprocal square root of
+//! floating-point vectors.
+
+macro_rules! impl_float_approx {
+    ($id:ident) => {
+        impl $id {
+            /// Lane-wise approximation of the reciprocal `1 / self`.
+            ///
+            /// The maximum relative error of the approximation is:
+            ///
+            /// * `1.5 * 2^-12` on `x86`/`x86_64` for `f32x2`, `f32x4`, and
+            ///   `f64x2` when `sse` (`sse2` for `f64x2`) is enabled, and for
+            ///   `f32x8` and `f64x4` when `avx` is enabled,
+            /// * `2^-8` on ARM and AArch64 for `f32x2` and `f32x4` when
+            ///   `neon` is enabled.
+            ///
+            /// Otherwise, the reciprocal is computed precisely.
+            ///
+            /// The approximation of `f64` lanes is computed in single
+            /// precision. Lanes for which the approximation is not
+            /// accurate, e.g., subnormal lanes or `f64` lanes out of the
+            /// range of the normal `f32`s, are computed precisely.
+            #[inline]
+            pub fn recip_approx(self) -> Self {
+                use super::codegen::estimate::Estimate;
+                Estimate::recip_estimate(self, 0)
+            }
+
+            /// Lane-wise approximation of the reciprocal `1 / self`,
+            /// refined by `steps` Newton-Raphson steps.
+            ///
+            /// Each step roughly doubles the number of correct bits of
+            /// `recip_approx`, up to the precision of the lanes. The steps
+            /// are skipped when the reciprocal is computed precisely.
+            #[inline]
+            pub fn recip_approx_refined(self, steps: u32) -> Self {
+                use super::codegen::estimate::Estimate;
+                Estimate::recip_estimate(self, steps)
+            }
+
+            /// Lane-wise approximation of the reciprocal square root
+            /// `1 / self.sqrt()`.
+            ///
+            /// Its maximum relative error is the same as that of
+            /// `recip_approx`.
+            #[inline]
+            pub fn rsqrt_approx(self) -> Self {
+                use super::codegen::estimate::Estimate;
+                Estimate::rsqrt_estimate(self, 0)
+            }
+
+            /// Lane-wise approximation of the reciprocal square root
+            /// `1 / self.sqrt()`, refined by `steps` Newton-Raphson steps.
+            ///
+            /// Each step roughly doubles the number of correct bits of
+            /// `rsqrt_approx`, up to the precision of the lanes. The steps
+            /// are skipped when the reciprocal square root is computed
+            /// precisely.
+            #[inline]
+            pub fn rsqrt_approx_refined(self, steps: u32) -> Self {
+                use super::codegen::estimate::Estimate;
+                Estimate::rsqrt_estimate(self, steps)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_float_approx {
+    ($id:ident, $elem_ty:ident) => {
+        #[test]
+        fn float_approx() {
+            use ::coresimd::simd::$id;
+            use ::std::$elem_ty::{EPSILON, INFINITY, MAX, MIN_POSITIVE};
+            let mut x = $id::splat(0 as $elem_ty);
+            for i in 0..$id::lanes() {
+                x = x.replace(i, (i as $elem_ty + 0.5) * 3.25);
+            }
+            let check = |r: $id, s: $id, max_err: $elem_ty| {
+                for i in 0..$id::lanes() {
+                    let x = x.extract(i);
+                    let r_err = (r.extract(i) * x - 1.).abs();
+                    let s = s.extract(i);
+                    let s_err = (s * s * x - 1.).abs();
+                    assert!(r_err <= max_err, "{} {}", x, r_err);
+                    assert!(s_err <= 2.1 * max_err, "{} {}", x, s_err);
+                }
+            };
+            check(x.recip_approx(), x.rsqrt_approx(), 1. / 256.);
+            check(
+                x.recip_approx_refined(2),
+                x.rsqrt_approx_refined(2),
+                1e-6,
+            );
+            check(
+                x.recip_approx_refined(4),
+                x.rsqrt_approx_refined(4),
+                8. * EPSILON,
+            );
+
+            // Subnormal lanes, and lanes out of the range of the normal
+            // `f32`s, are accurate:
+            let close = |r: $elem_ty, e: $elem_ty| {
+                r == e || (r.is_nan() && e.is_nan())
+                    || ((r - e) / e).abs() <= 1. / 128.
+            };
+            let big = MAX / 16.;
+            let small = MIN_POSITIVE * 16.;
+            let subnormal = MIN_POSITIVE / 16.;
+            let values = [big, -big, small, -small, subnormal, -subnormal];
+            for offset in 0..values.len() {
+                let mut x = $id::splat(0 as $elem_ty);
+                for i in 0..$id::lanes() {
+                    x = x.replace(i, values[(i + offset) % values.len()]);
+                }
+                for steps in 0..3 {
+                    let r = x.recip_approx_refined(steps);
+                    let s = x.rsqrt_approx_refined(steps);
+                    for i in 0..$id::lanes() {
+                        let v = x.extract(i);
+                        let (r, s) = (r.extract(i), s.extract(i));
+                        assert!(close(r, 1. / v), "{} {}", v, r);
+                        assert!(close(s, 1. / v.sqrt()), "{} {}", v, s);
+                    }
+                }
+            }
+
+            // Zero and infinite lanes are exact:
+            let x = $id::splat(0 as $elem_ty);
+            assert_eq!(x.recip_approx_refined(2), $id::splat(INFINITY));
+            assert_eq!(x.rsqrt_approx_refined(2), $id::splat(INFINITY));
+            let x = $id::splat(INFINITY);
+            assert_eq!(x.recip_approx_refined(2), $id::splat(0.));
+            assert_eq!(x.rsqrt_approx_refined(2), $id::splat(0.));
+        }
+    }
+}
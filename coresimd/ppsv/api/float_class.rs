@@ -0,0 +1,315 @@
+//! Lane-wise classification, unordered comparisons, and total ordering of
+//! floating-point vectors.
+//!
+//! These are implemented by inspecting the bits of the lanes, using the
+//! signed integer vector with the same lane width and number of lanes.
+
+macro_rules! impl_float_class {
+    ($id:ident: $elem_ty:ident, $int_ty:ident, $bool_ty:ident,
+     $test_mod:ident) => {
+        impl $id {
+            /// Returns a vector of the lane bits.
+            #[inline]
+            fn bits(self) -> $int_ty {
+                ::simd::FromBits::from_bits(self)
+            }
+
+            /// Returns a vector of the lane bits with the sign bit cleared.
+            #[inline]
+            fn abs_bits(self) -> $int_ty {
+                let sign = $int_ty::splat(1)
+                    << (mem::size_of::<$elem_ty>() * 8 - 1);
+                self.bits() & !sign
+            }
+
+            /// Returns a vector of the bits of positive infinity.
+            #[inline]
+            fn inf_bits() -> $int_ty {
+                $id::splat(1. / 0.).bits()
+            }
+
+            /// Lane-wise `true` if the lane is `NaN`.
+            #[inline]
+            pub fn is_nan(self) -> $bool_ty {
+                self.ne(self)
+            }
+
+            /// Lane-wise `true` if the lane is positive or negative
+            /// infinity.
+            #[inline]
+            pub fn is_infinite(self) -> $bool_ty {
+                self.abs_bits().eq(Self::inf_bits())
+            }
+
+            /// Lane-wise `true` if the lane is neither infinite nor `NaN`.
+            #[inline]
+            pub fn is_finite(self) -> $bool_ty {
+                self.abs_bits().lt(Self::inf_bits())
+            }
+
+            /// Lane-wise `true` if the lane is neither zero, infinite,
+            /// subnormal, nor `NaN`.
+            #[inline]
+            pub fn is_normal(self) -> $bool_ty {
+                let inf = Self::inf_bits();
+                let exp = self.abs_bits() & inf;
+                exp.ne($int_ty::splat(0)) & exp.ne(inf)
+            }
+
+            /// Lane-wise `true` if the sign bit of the lane is set,
+            /// including for `-0.0` and `NaN`s with a negative sign bit.
+            #[inline]
+            pub fn is_sign_negative(self) -> $bool_ty {
+                self.bits().lt($int_ty::splat(0))
+            }
+
+            /// Lane-wise `true` if the sign bit of the lane is not set,
+            /// including for `+0.0` and `NaN`s with a positive sign bit.
+            #[inline]
+            pub fn is_sign_positive(self) -> $bool_ty {
+                self.bits().ge($int_ty::splat(0))
+            }
+
+            /// Lane-wise `true` if neither `self` nor `other` is `NaN`.
+            #[inline]
+            pub fn is_ordered(self, other: Self) -> $bool_ty {
+                self.eq(self) & other.eq(other)
+            }
+
+            /// Lane-wise `true` if `self` or `other` is `NaN`.
+            #[inline]
+            pub fn is_unordered(self, other: Self) -> $bool_ty {
+                self.ne(self) | other.ne(other)
+            }
+
+            /// Lane-wise unordered equality comparison: `true` if the lanes
+            /// are equal or either of them is `NaN`.
+            #[inline]
+            pub fn eq_or_nan(self, other: Self) -> $bool_ty {
+                self.eq(other) | self.is_unordered(other)
+            }
+
+            /// Lane-wise ordered inequality comparison: `true` if the lanes
+            /// are not equal and neither of them is `NaN`.
+            ///
+            /// Note that `ne` is unordered, that is, it returns `true` if
+            /// either lane is `NaN`.
+            #[inline]
+            pub fn ne_and_not_nan(self, other: Self) -> $bool_ty {
+                self.lt(other) | self.gt(other)
+            }
+
+            /// Lane-wise unordered less-than comparison: `true` if `self`
+            /// is less than `other` or either of them is `NaN`.
+            #[inline]
+            pub fn lt_or_nan(self, other: Self) -> $bool_ty {
+                !self.ge(other)
+            }
+
+            /// Lane-wise unordered less-than-or-equal comparison: `true` if
+            /// `self` is less than or equal to `other` or either of them is
+            /// `NaN`.
+            #[inline]
+            pub fn le_or_nan(self, other: Self) -> $bool_ty {
+                !self.gt(other)
+            }
+
+            /// Lane-wise unordered greater-than comparison: `true` if
+            /// `self` is greater than `other` or either of them is `NaN`.
+            #[inline]
+            pub fn gt_or_nan(self, other: Self) -> $bool_ty {
+                !self.le(other)
+            }
+
+            /// Lane-wise unordered greater-than-or-equal comparison: `true`
+            /// if `self` is greater than or equal to `other` or either of
+            /// them is `NaN`.
+            #[inline]
+            pub fn ge_or_nan(self, other: Self) -> $bool_ty {
+                !self.lt(other)
+            }
+
+            /// Returns the lanes as integers whose order is the IEEE 754
+            /// `totalOrder` of the floating-point lanes.
+            #[inline]
+            fn total_order_key(self) -> $int_ty {
+                let bits = self.bits();
+                let shift = mem::size_of::<$elem_ty>() * 8 - 1;
+                let max = !($int_ty::splat(1) << shift);
+                // Negative values have their non-sign bits flipped, so that
+                // they are ordered in reverse:
+                bits ^ ((bits >> shift) & max)
+            }
+
+            /// Lane-wise less-than comparison according to the IEEE 754
+            /// `totalOrder` predicate.
+            ///
+            /// The lanes are ordered as follows: negative quiet `NaN`,
+            /// negative signaling `NaN`, negative infinity, negative
+            /// numbers, negative subnormals, `-0.0`, `+0.0`, positive
+            /// subnormals, positive numbers, positive infinity, positive
+            /// signaling `NaN`, positive quiet `NaN`.
+            #[inline]
+            pub fn total_lt(self, other: Self) -> $bool_ty {
+                self.total_order_key().lt(other.total_order_key())
+            }
+
+            /// Lane-wise less-than-or-equal comparison according to the
+            /// IEEE 754 `totalOrder` predicate (see `total_lt`).
+            #[inline]
+            pub fn total_le(self, other: Self) -> $bool_ty {
+                self.total_order_key().le(other.total_order_key())
+            }
+
+            /// Lane-wise greater-than comparison according to the IEEE 754
+            /// `totalOrder` predicate (see `total_lt`).
+            #[inline]
+            pub fn total_gt(self, other: Self) -> $bool_ty {
+                self.total_order_key().gt(other.total_order_key())
+            }
+
+            /// Lane-wise greater-than-or-equal comparison according to the
+            /// IEEE 754 `totalOrder` predicate (see `total_lt`).
+            #[inline]
+            pub fn total_ge(self, other: Self) -> $bool_ty {
+                self.total_order_key().ge(other.total_order_key())
+            }
+
+            /// Lexicographically compares the lanes of `self` and `other`,
+            /// ordering each pair of lanes according to the IEEE 754
+            /// `totalOrder` predicate (see `total_lt`).
+            #[inline]
+            pub fn total_cmp(&self, other: &Self) -> ::cmp::Ordering {
+                let a = self.total_order_key();
+                let b = other.total_order_key();
+                for i in 0..$id::lanes() {
+                    let (a, b) = unsafe {
+                        (a.extract_unchecked(i), b.extract_unchecked(i))
+                    };
+                    if a < b {
+                        return ::cmp::Ordering::Less;
+                    }
+                    if a > b {
+                        return ::cmp::Ordering::Greater;
+                    }
+                }
+                ::cmp::Ordering::Equal
+            }
+        }
+
+        #[cfg(test)]
+        mod $test_mod {
+            use ::coresimd::simd::{$bool_ty, $id};
+            use std::prelude::v1::*;
+            use std::$elem_ty;
+
+            /// Values covering all classes, in increasing total order.
+            fn values() -> [$elem_ty; 12] {
+                [
+                    -$elem_ty::NAN,
+                    $elem_ty::NEG_INFINITY,
+                    $elem_ty::MIN,
+                    -1.,
+                    -$elem_ty::MIN_POSITIVE / 2.,
+                    -0.,
+                    0.,
+                    $elem_ty::MIN_POSITIVE / 2.,
+                    $elem_ty::MIN_POSITIVE,
+                    $elem_ty::MAX,
+                    $elem_ty::INFINITY,
+                    $elem_ty::NAN,
+                ]
+            }
+
+            /// Returns a vector of the values starting at `offset`, and a
+            /// mask computed from them with the scalar predicate `f`.
+            fn vector<F>(offset: usize, f: F) -> ($id, $bool_ty)
+            where
+                F: Fn($elem_ty) -> bool,
+            {
+                let values = values();
+                let mut v = $id::splat(0.);
+                let mut m = $bool_ty::splat(false);
+                for i in 0..$id::lanes() {
+                    let x = values[(offset + i) % values.len()];
+                    v = v.replace(i, x);
+                    m = m.replace(i, f(x));
+                }
+                (v, m)
+            }
+
+            #[test]
+            fn classify() {
+                for offset in 0..values().len() {
+                    let (v, e) = vector(offset, |x| x.is_nan());
+                    assert_eq!(v.is_nan(), e);
+                    let (v, e) = vector(offset, |x| x.is_infinite());
+                    assert_eq!(v.is_infinite(), e);
+                    let (v, e) = vector(offset, |x| x.is_finite());
+                    assert_eq!(v.is_finite(), e);
+                    let (v, e) = vector(offset, |x| x.is_normal());
+                    assert_eq!(v.is_normal(), e);
+                    let (v, e) = vector(offset, |x| x.is_sign_negative());
+                    assert_eq!(v.is_sign_negative(), e);
+                    let (v, e) = vector(offset, |x| x.is_sign_positive());
+                    assert_eq!(v.is_sign_positive(), e);
+                }
+            }
+
+            #[test]
+            fn unordered_cmp() {
+                let n = values().len();
+                for offset in 0..n {
+                    let (a, _) = vector(offset, |_| false);
+                    let (b, _) = vector(n - 1 - offset, |_| false);
+                    for i in 0..$id::lanes() {
+                        let (x, y) = (a.extract(i), b.extract(i));
+                        let nan = x.is_nan() || y.is_nan();
+                        assert_eq!(a.is_unordered(b).extract(i), nan);
+                        assert_eq!(a.is_ordered(b).extract(i), !nan);
+                        assert_eq!(a.eq_or_nan(b).extract(i), nan || x == y);
+                        assert_eq!(
+                            a.ne_and_not_nan(b).extract(i), !nan && x != y
+                        );
+                        assert_eq!(a.lt_or_nan(b).extract(i), nan || x < y);
+                        assert_eq!(a.le_or_nan(b).extract(i), nan || x <= y);
+                        assert_eq!(a.gt_or_nan(b).extract(i), nan || x > y);
+                        assert_eq!(a.ge_or_nan(b).extract(i), nan || x >= y);
+                        // The ordered comparisons are false for NaNs:
+                        assert_eq!(a.lt(b).extract(i), !nan && x < y);
+                        assert_eq!(a.ge(b).extract(i), !nan && x >= y);
+                    }
+                }
+            }
+
+            #[test]
+            fn total_order() {
+                use std::cmp::Ordering;
+                let n = values().len();
+                for oa in 0..n {
+                    for ob in 0..n {
+                        let (a, _) = vector(oa, |_| false);
+                        let (b, _) = vector(ob, |_| false);
+                        for i in 0..$id::lanes() {
+                            // The index in `values` gives the total order:
+                            let ia = (oa + i) % n;
+                            let ib = (ob + i) % n;
+                            assert_eq!(a.total_lt(b).extract(i), ia < ib);
+                            assert_eq!(a.total_le(b).extract(i), ia <= ib);
+                            assert_eq!(a.total_gt(b).extract(i), ia > ib);
+                            assert_eq!(a.total_ge(b).extract(i), ia >= ib);
+                        }
+                        let e = if oa == ob {
+                            Ordering::Equal
+                        } else if oa < ob {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        };
+                        assert_eq!(a.total_cmp(&b), e);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Implements the lane-wise absolute value and square root of
+//! floating-point vectors.
+
+macro_rules! impl_float_math {
+    ($id:ident) => {
+        impl $id {
+            /// Lane-wise absolute value: clears the sign bit of each lane.
+            #[inline]
+            pub fn abs(self) -> Self {
+                use super::codegen::float_math::FloatMath;
+                FloatMath::abs(self)
+            }
+
+            /// Lane-wise square root, correctly rounded.
+            ///
+            /// Negative lanes, other than `-0.0`, produce `NaN`.
+            #[inline]
+            pub fn sqrt(self) -> Self {
+                use super::codegen::float_math::FloatMath;
+                FloatMath::sqrt(self)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_float_math {
+    ($id:ident, $elem_ty:ident) => {
+        #[test]
+        fn float_math() {
+            use ::coresimd::simd::{$id, SimdFloat};
+            use ::std::$elem_ty::{INFINITY, NAN};
+            let mut x = $id::splat(0 as $elem_ty);
+            for i in 0..$id::lanes() {
+                let v = (i as $elem_ty + 0.5) * 3.25;
+                x = x.replace(i, if i % 2 == 0 { v } else { -v });
+            }
+            let a = x.abs();
+            let s = a.sqrt();
+            for i in 0..$id::lanes() {
+                let v = x.extract(i);
+                assert_eq!(a.extract(i), v.abs());
+                assert_eq!(s.extract(i), v.abs().sqrt());
+            }
+            assert_eq!(SimdFloat::abs(x), a);
+            assert_eq!(SimdFloat::sqrt(a), s);
+            assert_eq!(SimdFloat::is_nan(x), x.is_nan());
+
+            let neg_zero = $id::splat(-0.);
+            assert!(neg_zero.abs().is_sign_positive().all());
+            assert!(neg_zero.sqrt().is_sign_negative().all());
+            assert_eq!($id::splat(-INFINITY).abs(), $id::splat(INFINITY));
+            assert!($id::splat(-1.).sqrt().is_nan().all());
+            assert!($id::splat(NAN).abs().is_nan().all());
+        }
+    }
+}
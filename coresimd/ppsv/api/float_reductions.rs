@@ -0,0 +1,289 @@
+//! Implements floating-point vector reductions with a specified order of
+//! evaluation.
+//!
+//! The order in which `sum`, `product`, `min`, and `max` combine the vector
+//! lanes is unspecified, and their results may differ between targets and
+//! LLVM versions. The reductions implemented here always combine the lanes
+//! in the same order, so that their results are bit-reproducible.
+
+macro_rules! impl_float_reductions {
+    ($id:ident, $elem_ty:ident, $elem_count:expr) => {
+        impl $id {
+            /// Sum of the vector lanes, added strictly from left to right:
+            /// `((x0 + x1) + x2) + ...`.
+            #[inline]
+            pub fn sum_ordered(self) -> $elem_ty {
+                let a: [$elem_ty; $elem_count] = self.into();
+                let mut r = a[0];
+                for i in 1..$elem_count {
+                    r += a[i];
+                }
+                r
+            }
+
+            /// Sum of the vector lanes, added as a pairwise tree.
+            ///
+            /// While more than one lane remains, the upper half of the
+            /// remaining lanes is added lane-wise to the lower half, that
+            /// is, for four lanes: `(x0 + x2) + (x1 + x3)`.
+            #[inline]
+            pub fn sum_tree(self) -> $elem_ty {
+                let mut a: [$elem_ty; $elem_count] = self.into();
+                let mut n = $elem_count;
+                while n > 1 {
+                    n /= 2;
+                    for i in 0..n {
+                        a[i] += a[i + n];
+                    }
+                }
+                a[0]
+            }
+
+            /// Compensated sum of the vector lanes, added from left to
+            /// right.
+            ///
+            /// Uses the Kahan-Babuška-Neumaier summation algorithm, which
+            /// tracks the rounding error of each addition and adds it back
+            /// to the result, which is thus often more accurate than the
+            /// result of `sum_ordered`.
+            #[inline]
+            pub fn sum_compensated(self) -> $elem_ty {
+                #[inline]
+                fn abs(x: $elem_ty) -> $elem_ty {
+                    if x < 0. { -x } else { x }
+                }
+                let a: [$elem_ty; $elem_count] = self.into();
+                let mut sum = a[0];
+                let mut c = 0.;
+                for i in 1..$elem_count {
+                    let x = a[i];
+                    let t = sum + x;
+                    if abs(sum) >= abs(x) {
+                        c += (sum - t) + x;
+                    } else {
+                        c += (x - t) + sum;
+                    }
+                    sum = t;
+                }
+                sum + c
+            }
+
+            /// Product of the vector lanes, multiplied strictly from left
+            /// to right: `((x0 * x1) * x2) * ...`.
+            #[inline]
+            pub fn product_ordered(self) -> $elem_ty {
+                let a: [$elem_ty; $elem_count] = self.into();
+                let mut r = a[0];
+                for i in 1..$elem_count {
+                    r *= a[i];
+                }
+                r
+            }
+
+            /// Product of the vector lanes, multiplied as a pairwise tree
+            /// in the same order as `sum_tree`.
+            #[inline]
+            pub fn product_tree(self) -> $elem_ty {
+                let mut a: [$elem_ty; $elem_count] = self.into();
+                let mut n = $elem_count;
+                while n > 1 {
+                    n /= 2;
+                    for i in 0..n {
+                        a[i] *= a[i + n];
+                    }
+                }
+                a[0]
+            }
+
+            /// Smallest vector value, ignoring `NaN`s.
+            ///
+            /// Returns `NaN` only if all lanes are `NaN`. Otherwise, returns
+            /// the left-most smallest non-`NaN` lane, that is, if the
+            /// smallest value is zero, the sign of the result is that of the
+            /// left-most zero lane.
+            #[inline]
+            pub fn min_ignore_nan(self) -> $elem_ty {
+                let a: [$elem_ty; $elem_count] = self.into();
+                let mut r = a[0];
+                for i in 1..$elem_count {
+                    if r != r || a[i] < r {
+                        r = a[i];
+                    }
+                }
+                r
+            }
+
+            /// Largest vector value, ignoring `NaN`s.
+            ///
+            /// Returns `NaN` only if all lanes are `NaN`. Otherwise, returns
+            /// the left-most largest non-`NaN` lane, that is, if the largest
+            /// value is zero, the sign of the result is that of the
+            /// left-most zero lane.
+            #[inline]
+            pub fn max_ignore_nan(self) -> $elem_ty {
+                let a: [$elem_ty; $elem_count] = self.into();
+                let mut r = a[0];
+                for i in 1..$elem_count {
+                    if r != r || a[i] > r {
+                        r = a[i];
+                    }
+                }
+                r
+            }
+
+            /// Smallest vector value, propagating `NaN`s.
+            ///
+            /// Returns the left-most `NaN` lane if any lane is `NaN`.
+            /// Otherwise, returns the left-most smallest lane, that is, if
+            /// the smallest value is zero, the sign of the result is that of
+            /// the left-most zero lane.
+            #[inline]
+            pub fn min_propagate_nan(self) -> $elem_ty {
+                let a: [$elem_ty; $elem_count] = self.into();
+                let mut r = a[0];
+                for i in 1..$elem_count {
+                    if r != r {
+                        break;
+                    }
+                    if a[i] != a[i] || a[i] < r {
+                        r = a[i];
+                    }
+                }
+                r
+            }
+
+            /// Largest vector value, propagating `NaN`s.
+            ///
+            /// Returns the left-most `NaN` lane if any lane is `NaN`.
+            /// Otherwise, returns the left-most largest lane, that is, if
+            /// the largest value is zero, the sign of the result is that of
+            /// the left-most zero lane.
+            #[inline]
+            pub fn max_propagate_nan(self) -> $elem_ty {
+                let a: [$elem_ty; $elem_count] = self.into();
+                let mut r = a[0];
+                for i in 1..$elem_count {
+                    if r != r {
+                        break;
+                    }
+                    if a[i] != a[i] || a[i] > r {
+                        r = a[i];
+                    }
+                }
+                r
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_float_reductions {
+    ($id:ident, $elem_ty:ident) => {
+        /// Returns a vector whose lanes repeat `values`.
+        fn repeat(values: &[$elem_ty]) -> ::coresimd::simd::$id {
+            use ::coresimd::simd::$id;
+            let mut v = $id::splat(0 as $elem_ty);
+            for i in 0..$id::lanes() {
+                v = v.replace(i, values[i % values.len()]);
+            }
+            v
+        }
+
+        #[test]
+        fn sum_order() {
+            use ::coresimd::simd::$id;
+            use ::std::prelude::v1::*;
+            let v = repeat(&[0.1, 0.7, -0.3, 1e-3, 3.3, -2.1, 1e3]);
+            let mut ordered = v.extract(0);
+            let mut product = v.extract(0);
+            for i in 1..$id::lanes() {
+                ordered += v.extract(i);
+                product *= v.extract(i);
+            }
+            assert_eq!(v.sum_ordered(), ordered);
+            assert_eq!(v.product_ordered(), product);
+
+            // For four lanes: (x0 + x2) + (x1 + x3):
+            let tree = |f: &Fn($elem_ty, $elem_ty) -> $elem_ty| {
+                let mut a = ::std::vec::Vec::new();
+                for i in 0..$id::lanes() {
+                    a.push(v.extract(i));
+                }
+                while a.len() > 1 {
+                    let n = a.len() / 2;
+                    let hi = a.split_off(n);
+                    for i in 0..n {
+                        a[i] = f(a[i], hi[i]);
+                    }
+                }
+                a[0]
+            };
+            assert_eq!(v.sum_tree(), tree(&|a, b| a + b));
+            assert_eq!(v.product_tree(), tree(&|a, b| a * b));
+        }
+
+        #[test]
+        fn sum_cancellation() {
+            use ::coresimd::simd::$id;
+            let v = $id::splat(1.5);
+            let s = $id::lanes() as $elem_ty * 1.5;
+            assert_eq!(v.sum_ordered(), s);
+            assert_eq!(v.sum_tree(), s);
+            assert_eq!(v.sum_compensated(), s);
+
+            if $id::lanes() >= 4 {
+                // The large values cancel out, but absorb the small ones
+                // when added from left to right:
+                let big = 1e30 as $elem_ty;
+                let v = repeat(&[1., big, 1., -big]);
+                let exact = ($id::lanes() / 2) as $elem_ty;
+                assert_eq!(v.sum_ordered(), 0.);
+                assert_eq!(v.sum_tree(), exact);
+                assert_eq!(v.sum_compensated(), exact);
+            }
+        }
+
+        #[test]
+        fn minmax_nan() {
+            use ::coresimd::simd::$id;
+            use ::std::prelude::v1::*;
+            use ::std::$elem_ty::NAN;
+            let v = repeat(&[3., -1., 7., 2.]);
+            assert_eq!(v.min_ignore_nan(), v.min());
+            assert_eq!(v.max_ignore_nan(), v.max());
+            assert_eq!(v.min_propagate_nan(), v.min());
+            assert_eq!(v.max_propagate_nan(), v.max());
+
+            for i in 0..$id::lanes() {
+                let w = v.replace(i, NAN);
+                assert!(w.min_propagate_nan().is_nan());
+                assert!(w.max_propagate_nan().is_nan());
+                if $id::lanes() > 1 {
+                    let mut min = None;
+                    let mut max = None;
+                    for j in 0..$id::lanes() {
+                        if j != i {
+                            let x = v.extract(j);
+                            min = Some(min.map_or(x, |m: $elem_ty| m.min(x)));
+                            max = Some(max.map_or(x, |m: $elem_ty| m.max(x)));
+                        }
+                    }
+                    assert_eq!(Some(w.min_ignore_nan()), min);
+                    assert_eq!(Some(w.max_ignore_nan()), max);
+                }
+            }
+            let v = $id::splat(NAN);
+            assert!(v.min_ignore_nan().is_nan());
+            assert!(v.max_ignore_nan().is_nan());
+            assert!(v.min().is_nan());
+            assert!(v.max().is_nan());
+
+            // The left-most zero is returned:
+            let v = $id::splat(0.).replace(0, -0.);
+            assert!(v.min_ignore_nan().is_sign_negative());
+            assert!(v.max_ignore_nan().is_sign_negative());
+            assert!(v.min_propagate_nan().is_sign_negative());
+            assert!(v.max_propagate_nan().is_sign_negative());
+        }
+    }
+}
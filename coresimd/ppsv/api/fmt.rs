@@ -1,20 +1,60 @@
 //! Implements formating traits.
+//!
+//! The vectors are formatted as `id(x0, x1, ...)`, where each lane is
+//! formatted with the same trait and options (width, precision, flags, ...)
+//! as the vector. Boolean vectors are formatted compactly by `Debug`, as
+//! `id[1,0,...]`.
 
-macro_rules! impl_hex_fmt {
-    ($id:ident, $elem_ty:ident) => {
-        impl fmt::LowerHex for $id {
-            fn fmt(&self, f: &mut fmt::Formatter)
-                   -> fmt::Result {
-                write!(f, "{}(", stringify!($id))?;
-                let n = mem::size_of_val(self)
-                    / mem::size_of::<$elem_ty>();
-                for i in 0..n {
+/// Implements the formatting trait `fmt::$tr` by formatting each lane with
+/// it.
+macro_rules! impl_fmt_lanes {
+    ($id:ident, $($tr:ident),+) => {
+        $(
+            impl fmt::$tr for $id {
+                fn fmt(&self, f: &mut fmt::Formatter)
+                       -> fmt::Result {
+                    write!(f, "{}(", stringify!($id))?;
+                    for i in 0..$id::lanes() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+                        fmt::$tr::fmt(&self.extract(i), f)?;
+                    }
+                    write!(f, ")")
+                }
+            }
+        )+
+    }
+}
+
+macro_rules! impl_int_fmt {
+    ($id:ident) => {
+        impl_fmt_lanes!(
+            $id, Debug, Display, LowerHex, UpperHex, Octal, Binary
+        );
+    }
+}
+
+macro_rules! impl_float_fmt {
+    ($id:ident) => {
+        impl_fmt_lanes!($id, Debug, Display, LowerExp, UpperExp);
+    }
+}
+
+macro_rules! impl_bool_fmt {
+    ($id:ident) => {
+        impl_fmt_lanes!($id, Display);
+
+        impl fmt::Debug for $id {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}[", stringify!($id))?;
+                for i in 0..$id::lanes() {
                     if i > 0 {
-                        write!(f, ", ")?;
+                        write!(f, ",")?;
                     }
-                    self.extract(i).fmt(f)?;
+                    write!(f, "{}", if self.extract(i) { 1 } else { 0 })?;
                 }
-                write!(f, ")")
+                write!(f, "]")
             }
         }
     }
@@ -51,3 +91,87 @@ macro_rules! test_hex_fmt {
         test_hex_fmt_impl!($id, $elem_ty, 0 as $elem_ty, !(0 as $elem_ty), (1 as $elem_ty));
     }
 }
+
+/// Tests that the vector `$vec` is formatted lane-by-lane with each of the
+/// format strings `$fmt`.
+#[cfg(test)]
+macro_rules! test_fmt_lanes {
+    ($id:ident, $vec:expr, $($fmt:tt),+) => {{
+        use ::std::prelude::v1::*;
+        let vec = $vec;
+        $(
+            let lanes: Vec<String> = (0..$id::lanes())
+                .map(|i| format!($fmt, vec.extract(i)))
+                .collect();
+            let e = format!("{}({})", stringify!($id), lanes.join(", "));
+            assert_eq!(format!($fmt, vec), e);
+        )+
+    }}
+}
+
+#[cfg(test)]
+macro_rules! test_int_fmt {
+    ($id:ident, $elem_ty:ident) => {
+        #[test]
+        fn int_fmt() {
+            use ::coresimd::simd::$id;
+            let mut vec = $id::splat(0 as $elem_ty);
+            for i in 0..$id::lanes() {
+                vec = vec.replace(i, (i * 37) as $elem_ty);
+            }
+            vec = vec.replace(0, !(0 as $elem_ty));
+            test_fmt_lanes!(
+                $id, vec,
+                "{:?}", "{}", "{:+}", "{:>6}", "{:<6}", "{:^6}", "{:06}",
+                "{:x}", "{:#x}", "{:X}", "{:#X}", "{:o}", "{:#o}", "{:b}",
+                "{:#010b}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_float_fmt {
+    ($id:ident, $elem_ty:ident) => {
+        #[test]
+        fn float_fmt() {
+            use ::coresimd::simd::$id;
+            let mut vec = $id::splat(0 as $elem_ty);
+            for i in 0..$id::lanes() {
+                vec = vec.replace(i, (i as $elem_ty - 1.) * 1.625e3);
+            }
+            test_fmt_lanes!(
+                $id, vec,
+                "{:?}", "{}", "{:+}", "{:.2}", "{:>10.1}", "{:+08.3}",
+                "{:e}", "{:E}", "{:.3e}", "{:+E}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_bool_fmt {
+    ($id:ident) => {
+        #[test]
+        fn bool_fmt() {
+            use ::coresimd::simd::$id;
+            use ::std::prelude::v1::*;
+            let mut vec = $id::splat(false);
+            let mut e = format!("{}[", stringify!($id));
+            for i in 0..$id::lanes() {
+                if i > 0 {
+                    e.push(',');
+                }
+                if i % 3 == 0 {
+                    vec = vec.replace(i, true);
+                    e.push('1');
+                } else {
+                    e.push('0');
+                }
+            }
+            e.push(']');
+            assert_eq!(format!("{:?}", vec), e);
+            test_fmt_lanes!($id, vec, "{}", "{:>6}");
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Implements lane-wise absolute values, absolute differences, averages, and
+//! signs of integer vectors.
+
+macro_rules! impl_signed_int_math {
+    ($id:ident: $elem_ty:ident, $uid:ident, $test_mod:ident) => {
+        impl $id {
+            /// Lane-wise absolute value.
+            ///
+            /// Like the other vector arithmetic operations, this wraps
+            /// around on overflow: the absolute value of the minimum value
+            /// is the minimum value itself. Use `unsigned_abs` to obtain
+            /// the exact result.
+            #[inline]
+            pub fn abs(self) -> Self {
+                self.wrapping_abs()
+            }
+
+            /// Lane-wise wrapping absolute value: the absolute value of the
+            /// minimum value is the minimum value itself.
+            ///
+            /// On `x86`/`x86_64` this uses `pabs{b,w,d}` when `ssse3`
+            /// (`vpabs{b,w,d}` for 256-bit vectors when `avx2`) is enabled.
+            #[inline]
+            pub fn wrapping_abs(self) -> Self {
+                use super::codegen::abs::WrappingAbs;
+                WrappingAbs::wrapping_abs(self)
+            }
+
+            /// Lane-wise absolute value, without overflow.
+            #[inline]
+            pub fn unsigned_abs(self) -> $uid {
+                ::simd::FromBits::from_bits(self.wrapping_abs())
+            }
+
+            /// Lane-wise absolute difference of `self` and `other`, without
+            /// overflow.
+            #[inline]
+            pub fn abs_diff(self, other: Self) -> $uid {
+                let d = self.lanewise_max(other) - self.lanewise_min(other);
+                // The difference wraps around, but is exact in the unsigned
+                // lanes:
+                ::simd::FromBits::from_bits(d)
+            }
+
+            /// Lane-wise average of `self` and `other`, rounded up, without
+            /// overflow.
+            #[inline]
+            pub fn average(self, other: Self) -> Self {
+                use super::codegen::avg::RoundingAverage;
+                self.rounding_average(other)
+            }
+
+            /// Lane-wise sign: `-1` if the lane is negative, `0` if it is
+            /// zero, and `1` if it is positive.
+            #[inline]
+            pub fn signum(self) -> Self {
+                use super::codegen::signum::Signum;
+                Signum::signum(self)
+            }
+        }
+
+        #[cfg(test)]
+        mod $test_mod {
+            use ::coresimd::simd::$id;
+
+            /// Returns a vector of values covering the range of the lanes.
+            fn values(offset: usize) -> $id {
+                let xs = [
+                    $elem_ty::min_value(),
+                    $elem_ty::min_value() + 1,
+                    -7,
+                    -1,
+                    0,
+                    1,
+                    6,
+                    $elem_ty::max_value() - 1,
+                    $elem_ty::max_value(),
+                ];
+                let mut v = $id::splat(0);
+                for i in 0..$id::lanes() {
+                    v = v.replace(i, xs[(offset + i) % xs.len()]);
+                }
+                v
+            }
+
+            #[test]
+            fn int_math() {
+                use ::coresimd::simd::{$uid, FromBits};
+                use std::prelude::v1::*;
+                for oa in 0..9 {
+                    let a = values(oa);
+                    let mut abs = a;
+                    for i in 0..$id::lanes() {
+                        let x = a.extract(i);
+                        abs = abs.replace(i, x.wrapping_abs());
+                        assert_eq!(a.signum().extract(i), x.signum());
+                    }
+                    assert_eq!(a.abs(), abs);
+                    assert_eq!(a.wrapping_abs(), abs);
+                    // The wrapped absolute values are exact when
+                    // reinterpreted as unsigned:
+                    assert_eq!(a.unsigned_abs(), $uid::from_bits(abs));
+                    for ob in 0..9 {
+                        let b = values(ob);
+                        let mut diff = a;
+                        let mut avg = a;
+                        for i in 0..$id::lanes() {
+                            let (x, y) = (a.extract(i), b.extract(i));
+                            let d = x.max(y).wrapping_sub(x.min(y));
+                            diff = diff.replace(i, d);
+                            let m = (x >> 1) + (y >> 1) + ((x | y) & 1);
+                            avg = avg.replace(i, m);
+                        }
+                        assert_eq!(a.abs_diff(b), $uid::from_bits(diff));
+                        assert_eq!(a.average(b), avg);
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_unsigned_int_math {
+    ($id:ident: $elem_ty:ident, $test_mod:ident) => {
+        impl $id {
+            /// Lane-wise absolute difference of `self` and `other`.
+            #[inline]
+            pub fn abs_diff(self, other: Self) -> Self {
+                self.lanewise_max(other) - self.lanewise_min(other)
+            }
+
+            /// Lane-wise average of `self` and `other`, rounded up, without
+            /// overflow.
+            #[inline]
+            pub fn average(self, other: Self) -> Self {
+                use super::codegen::avg::RoundingAverage;
+                self.rounding_average(other)
+            }
+        }
+
+        #[cfg(test)]
+        mod $test_mod {
+            use ::coresimd::simd::$id;
+
+            /// Returns a vector of values covering the range of the lanes.
+            fn values(offset: usize) -> $id {
+                let xs = [
+                    0,
+                    1,
+                    6,
+                    7,
+                    $elem_ty::max_value() / 2,
+                    $elem_ty::max_value() - 1,
+                    $elem_ty::max_value(),
+                ];
+                let mut v = $id::splat(0);
+                for i in 0..$id::lanes() {
+                    v = v.replace(i, xs[(offset + i) % xs.len()]);
+                }
+                v
+            }
+
+            #[test]
+            fn int_math() {
+                for oa in 0..7 {
+                    let a = values(oa);
+                    for ob in 0..7 {
+                        let b = values(ob);
+                        let d = a.abs_diff(b);
+                        let m = a.average(b);
+                        for i in 0..$id::lanes() {
+                            let x = a.extract(i);
+                            let y = b.extract(i);
+                            let e = if x > y { x - y } else { y - x };
+                            assert_eq!(d.extract(i), e);
+                            let e = x / 2 + y / 2 + ((x % 2) | (y % 2));
+                            assert_eq!(m.extract(i), e);
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
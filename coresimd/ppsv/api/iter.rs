@@ -0,0 +1,146 @@
+//! Implements iterator traits for vector types.
+
+macro_rules! impl_iter {
+    ($id:ident, $elem_ty:ident, $elem_count:expr) => {
+        impl IntoIterator for $id {
+            type Item = $elem_ty;
+            type IntoIter = ::simd::IntoIter<$id>;
+            /// Returns an iterator over the vector lanes, starting at lane
+            /// `0`.
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                ::simd::IntoIter { vec: self, index: 0 }
+            }
+        }
+
+        impl Iterator for ::simd::IntoIter<$id> {
+            type Item = $elem_ty;
+            #[inline]
+            fn next(&mut self) -> ::option::Option<$elem_ty> {
+                if self.index < $elem_count {
+                    let x = unsafe { self.vec.extract_unchecked(self.index) };
+                    self.index += 1;
+                    ::option::Option::Some(x)
+                } else {
+                    ::option::Option::None
+                }
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, ::option::Option<usize>) {
+                let remaining = $elem_count - self.index;
+                (remaining, ::option::Option::Some(remaining))
+            }
+        }
+
+        impl ::iter::ExactSizeIterator for ::simd::IntoIter<$id> {}
+
+        impl ::iter::FromIterator<$elem_ty> for $id {
+            /// Creates a vector from an iterator yielding exactly
+            /// `Self::lanes()` elements.
+            ///
+            /// # Panics
+            ///
+            /// If the iterator yields fewer or more than `Self::lanes()`
+            /// elements. See `try_from_iter` for a non-panicking alternative.
+            #[inline]
+            fn from_iter<I: IntoIterator<Item = $elem_ty>>(iter: I) -> Self {
+                match $id::try_from_iter(iter) {
+                    ::option::Option::Some(vec) => vec,
+                    ::option::Option::None => panic!(
+                        "iterator does not yield exactly {} elements",
+                        $elem_count
+                    ),
+                }
+            }
+        }
+
+        impl $id {
+            /// Creates a vector from an iterator yielding exactly
+            /// `Self::lanes()` elements.
+            ///
+            /// Returns `None` if the iterator yields fewer or more than
+            /// `Self::lanes()` elements.
+            #[inline]
+            pub fn try_from_iter<I>(iter: I) -> ::option::Option<Self>
+            where
+                I: IntoIterator<Item = $elem_ty>,
+            {
+                let mut iter = iter.into_iter();
+                let mut vec = $id::splat(<$elem_ty as Default>::default());
+                for i in 0..$elem_count {
+                    match iter.next() {
+                        ::option::Option::Some(x) => {
+                            vec = unsafe { vec.replace_unchecked(i, x) };
+                        }
+                        ::option::Option::None => {
+                            return ::option::Option::None
+                        }
+                    }
+                }
+                if iter.next().is_some() {
+                    return ::option::Option::None;
+                }
+                ::option::Option::Some(vec)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_iter {
+    ($id:ident, $elem_ty:ident, $value:expr) => {
+        #[test]
+        fn into_iter() {
+            use ::coresimd::simd::$id;
+            use ::std::iter::{ExactSizeIterator, IntoIterator, Iterator};
+
+            let vec = $id::splat($value);
+            let mut iter = vec.into_iter();
+            assert_eq!(iter.len(), $id::lanes());
+            for i in 0..$id::lanes() {
+                assert_eq!(iter.next(), Some(vec.extract(i)));
+                assert_eq!(iter.len(), $id::lanes() - i - 1);
+            }
+            assert_eq!(iter.next(), None);
+            assert_eq!(vec.into_iter().count(), $id::lanes());
+        }
+
+        #[test]
+        fn from_iter() {
+            use ::coresimd::simd::$id;
+            use ::std::iter::{self, Iterator};
+
+            let vec: $id = iter::repeat($value).take($id::lanes()).collect();
+            assert_eq!(vec, $id::splat($value));
+
+            assert_eq!(
+                $id::try_from_iter(iter::repeat($value).take($id::lanes())),
+                Some($id::splat($value))
+            );
+            assert_eq!(
+                $id::try_from_iter(iter::repeat($value).take($id::lanes() - 1)),
+                None
+            );
+            assert_eq!(
+                $id::try_from_iter(iter::repeat($value).take($id::lanes() + 1)),
+                None
+            );
+        }
+
+        #[test]
+        #[should_panic]
+        fn from_iter_panic_on_too_few_elements() {
+            use ::coresimd::simd::$id;
+            use ::std::iter::{self, Iterator};
+            let _: $id = iter::repeat($value).take($id::lanes() - 1).collect();
+        }
+
+        #[test]
+        #[should_panic]
+        fn from_iter_panic_on_too_many_elements() {
+            use ::coresimd::simd::$id;
+            use ::std::iter::{self, Iterator};
+            let _: $id = iter::repeat($value).take($id::lanes() + 1).collect();
+        }
+    }
+}
@@ -19,6 +19,9 @@ macro_rules! impl_minimal {
                 $id($($elem_name),*)
             }
 
+            /// The number of vector lanes.
+            pub const LANES: usize = $elem_count;
+
             /// Returns the number of vector lanes.
             #[inline]
             pub const fn lanes() -> usize {
@@ -95,6 +98,7 @@ macro_rules! test_minimal {
 
             // lanes:
             assert_eq!($elem_count, $id::lanes());
+            assert_eq!($elem_count, $id::LANES);
 
             // splat and extract / extract_unchecked:
             const VAL: $elem_ty = 7 as $elem_ty;
@@ -140,3 +144,51 @@ macro_rules! test_minimal {
         }
     }
 }
+
+/// Tests the minimal API and the reductions of integer vectors with a
+/// single lane, to which some of the tests above do not apply.
+#[cfg(test)]
+macro_rules! test_single_lane {
+    ($id:ident, $elem_ty:ident) => {
+        #[test]
+        fn single_lane() {
+            use ::coresimd::simd::$id;
+            assert_eq!(1, $id::lanes());
+            assert_eq!(1, $id::LANES);
+
+            for &x in &[
+                7 as $elem_ty,
+                $elem_ty::min_value(),
+                $elem_ty::max_value(),
+            ] {
+                let v = $id::splat(x);
+                assert_eq!(x, v.extract(0));
+                assert_eq!(x, unsafe { v.extract_unchecked(0) });
+                assert_eq!(42 as $elem_ty, v.replace(0, 42).extract(0));
+                let new_vec = unsafe { v.replace_unchecked(0, 42) };
+                assert_eq!(42 as $elem_ty, new_vec.extract(0));
+
+                // the reductions of a single lane are the lane itself:
+                assert_eq!(x, v.sum());
+                assert_eq!(x, v.product());
+                assert_eq!(x, v.max());
+                assert_eq!(x, v.min());
+                assert_eq!(x, v.and());
+                assert_eq!(x, v.or());
+                assert_eq!(x, v.xor());
+            }
+        }
+        #[test]
+        #[should_panic]
+        fn single_lane_extract_panic_on_out_of_bounds() {
+            use ::coresimd::simd::$id;
+            let _ = $id::splat(7 as $elem_ty).extract(1);
+        }
+        #[test]
+        #[should_panic]
+        fn single_lane_replace_panic_on_out_of_bounds() {
+            use ::coresimd::simd::$id;
+            let _ = $id::splat(7 as $elem_ty).replace(1, 42 as $elem_ty);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Implements lane-wise `min`/`max` of vectors.
+//!
+//! The lanes are selected with a mask of the same lane width as the vector:
+//! the vector itself for integer vectors, and the signed integer vector
+//! with the same lane width for floating-point vectors.
+
+macro_rules! impl_minmax {
+    ($id:ident, $mask_ty:ident) => {
+        impl $id {
+            /// Lane-wise minimum of `self` and `other`.
+            ///
+            /// For floating-point vectors, if either lane is `NaN`, the lane
+            /// of `other` is returned.
+            #[inline]
+            pub fn lanewise_min(self, other: Self) -> Self {
+                unsafe {
+                    let m: $mask_ty = simd_lt(self, other);
+                    simd_select(m, self, other)
+                }
+            }
+
+            /// Lane-wise maximum of `self` and `other`.
+            ///
+            /// For floating-point vectors, if either lane is `NaN`, the lane
+            /// of `other` is returned.
+            #[inline]
+            pub fn lanewise_max(self, other: Self) -> Self {
+                unsafe {
+                    let m: $mask_ty = simd_gt(self, other);
+                    simd_select(m, self, other)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_minmax {
+    ($id:ident, $elem_ty:ident) => {
+        #[test]
+        fn lanewise_minmax() {
+            use ::coresimd::simd::$id;
+            let mut a = $id::splat(0 as $elem_ty);
+            let mut b = $id::splat(0 as $elem_ty);
+            for i in 0..$id::lanes() {
+                if i % 2 == 0 {
+                    a = a.replace(i, 1 as $elem_ty);
+                } else {
+                    b = b.replace(i, 2 as $elem_ty);
+                }
+            }
+            let min = a.lanewise_min(b);
+            let max = a.lanewise_max(b);
+            for i in 0..$id::lanes() {
+                assert_eq!(min.extract(i), 0 as $elem_ty);
+                if i % 2 == 0 {
+                    assert_eq!(max.extract(i), 1 as $elem_ty);
+                } else {
+                    assert_eq!(max.extract(i), 2 as $elem_ty);
+                }
+            }
+            assert_eq!(a.lanewise_min(a), a);
+            assert_eq!(b.lanewise_max(b), b);
+        }
+    }
+}
@@ -4,11 +4,21 @@ macro_rules! impl_minmax_reductions {
     ($id:ident, $elem_ty:ident) => {
         impl $id {
             /// Largest vector value.
+            ///
+            /// For floating-point vectors, if some lanes are `NaN`, the
+            /// result is either `NaN` or the largest non-`NaN` lane, and if
+            /// the largest value is zero, its sign is unspecified. See
+            /// `max_ignore_nan` and `max_propagate_nan`.
             #[inline]
             pub fn max(self) -> $elem_ty {
                 ReduceMax::reduce_max(self)
             }
             /// Smallest vector value.
+            ///
+            /// For floating-point vectors, if some lanes are `NaN`, the
+            /// result is either `NaN` or the smallest non-`NaN` lane, and if
+            /// the smallest value is zero, its sign is unspecified. See
+            /// `min_ignore_nan` and `min_propagate_nan`.
             #[inline]
             pub fn min(self) -> $elem_ty {
                 ReduceMin::reduce_min(self)
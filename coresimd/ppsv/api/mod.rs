@@ -8,11 +8,19 @@
 //!
 //! * [x] `Copy`,
 //! * [x] `Clone`,
-//! * [x] `Debug`,
+//! * [x] `Debug` (boolean vectors compactly, as `b8x4[1,0,0,1]`),
+//! * [x] `fmt::Display`,
 //! * [x] `Default`
 //! * [x] `PartialEq`
 //! * [x] `PartialOrd` (TODO: re-write in term of
 //!        comparison operations and boolean reductions),
+//! * [x] `IntoIterator` over the vector lanes, and
+//! * [x] `FromIterator` from exactly `lanes()` elements.
+//!
+//! Integer and floating-point vector types also implement:
+//!
+//! * [x] `iter::Sum` and `iter::Product` (lane-wise) over iterators of
+//!       vectors.
 //!
 //! Non-floating-point vector types also implement:
 //!
@@ -22,7 +30,34 @@
 //!
 //! Integer vector types also implement:
 //!
-//! * [x] `fmt::LowerHex`.
+//! * [x] `fmt::LowerHex`, `fmt::UpperHex`, `fmt::Octal`, and
+//!       `fmt::Binary`.
+//!
+//! Floating-point vector types also implement:
+//!
+//! * [x] `fmt::LowerExp` and `fmt::UpperExp`.
+//!
+//! All formatting traits format the vectors lane by lane, honoring the
+//! width, precision, and flags of the format string for each lane.
+//!
+//! With the `serde` feature, all portable vector types except for vectors of
+//! pointers also implement:
+//!
+//! * [x] `serde::Serialize` and `serde::Deserialize`, as tuples of lanes.
+//!
+//! With the `quickcheck` feature, all portable vector types except for
+//! vectors of pointers also implement:
+//!
+//! * [x] `quickcheck::Arbitrary`, generating special values (extremes,
+//!       `NaN`, `±0`, `±∞`, subnormals, ...) for half of the lanes.
+//!
+//! Non-boolean vector types also implement:
+//!
+//! * [x] `Index<usize>`/`IndexMut<usize>` for lane access.
+//!
+//! All portable vector types also implement the sealed `Simd` trait, and
+//! depending on their element type, one of `SimdFloat`, `SimdInt`, or
+//! `SimdBool` (see the `traits` module).
 //!
 //! ## Conversions
 //!
@@ -30,6 +65,8 @@
 //!        the same size (i.e., same `mem::size_of`).
 //! * [x]: `From/Into`: casts between vectors with the same number of lanes
 //!        (potentially lossy).
+//! * [x]: `From/Into`: conversions from and to arrays with the same number of
+//!        lanes.
 //!
 //! ## Inherent methods
 //!
@@ -41,17 +78,61 @@
 //! * [x] comparison API: vector lane-wise comparison producing
 //!       boolean vectors - implemented by all vectors.
 //! * [x] arithmetic operations: implemented by all non-boolean vectors.
+//! * [x] addition and multiplication between vectors and scalars (on
+//!       either side): implemented by all non-boolean vectors.
 //! * [x] `std::ops::Neg`: implemented by signed-integer and floating-point
 //!       vectors.
 //! * [x] bitwise operations: implemented by integer and boolean
 //!       vectors.
 //! * [x] shift operations: implemented by integer vectors.
+//! * [x] endianness conversions (`swap_bytes`, `to_be`, `from_le`, ...),
+//!       and conversions from and to byte arrays (`to_be_bytes`,
+//!       `from_le_bytes`, ...): implemented by integer vectors.
+//! * [x] absolute differences (`abs_diff`) and rounding averages
+//!       (`average`): implemented by integer vectors, and absolute values
+//!       (`abs`, `wrapping_abs`, `unsigned_abs`) and signs (`signum`):
+//!       implemented by signed-integer vectors.
 //! * [x] arithmetic reductions: implemented by integer and floating-point
 //!       vectors.
+//! * [x] widening sums (`sum_widening`, `sum_pairs_widening`): implemented
+//!       by integer vectors with 8-, 16-, and 32-bit lanes.
 //! * [x] bitwise reductions: implemented by integer and boolean
 //!       vectors.
+//! * [x] floating-point reductions with a specified order of evaluation
+//!       (`sum_ordered`, `sum_tree`, `sum_compensated`, ...) and `NaN`
+//!       handling (`min_ignore_nan`, `min_propagate_nan`, ...): implemented
+//!       by floating-point vectors.
 //! * [x] boolean reductions: implemented by boolean vectors.
+//! * [x] lane-wise minimum and maximum (`lanewise_min`/`lanewise_max`), and
+//!       sorting of the lanes with bitonic networks (`sort_lanes`,
+//!       `sort_lanes_desc`, `merge_sorted`): implemented by integer and
+//!       floating-point vectors.
+//! * [x] scans of the lanes (`prefix_sum`, `prefix_sum_exclusive`,
+//!       `scan_max`, and `scan`/`scan_exclusive` with a custom operation):
+//!       implemented by integer and floating-point vectors, and `scan_or`:
+//!       implemented by integer vectors.
+//! * [x] floating-point classification (`is_nan`, `is_normal`, ...),
+//!       unordered comparisons (`lt_or_nan`, ...), and IEEE 754 total
+//!       ordering (`total_lt`, `total_cmp`, ...): implemented by
+//!       floating-point vectors.
+//! * [x] absolute values (`abs`) and square roots (`sqrt`): implemented by
+//!       floating-point vectors.
+//! * [x] half-precision floating-point vectors (`f16x{4,8,16}`): storage
+//!       only, converted from and to `f32` vectors with `from_f32`/`to_f32`.
+//! * [x] `bfloat16` vectors (`bf16x{8,16}`): storage only, converted from
+//!       and to `f32` vectors with `from_f32`/`from_f32_truncated`/`to_f32`,
+//!       and pairwise widening dot products (`dot_pairs_widening`).
+//! * [x] high half of the widening multiplication (`mul_high`) and division
+//!       by precomputed divisors (`Divisor<u32x8>`, ...): implemented by
+//!       `u16` and `u32` vectors with up to 256 bits.
 //! * [ ] portable shuffles: `shufflevector`.
+//! * [x] lane rotations and shifts (`rotate_lanes_left`,
+//!       `shift_lanes_left`, ...), and extraction from the concatenation of
+//!       two vectors (`slide`): implemented by all integer, floating-point,
+//!       and boolean vectors.
+//! * [x] vectors of pointers (`cptrx{2,4,8,16}<T>`/`mptrx{2,4,8,16}<T>`):
+//!       lane-wise pointer arithmetic, null checks, and (masked) reads and
+//!       writes through the pointers.
 //! * [ ] portable `gather`/`scatter`:
 
 /// Adds the vector type `$id`, with elements of types `$elem_tys`.
@@ -59,17 +140,25 @@ macro_rules! define_ty {
     ($id:ident, $($elem_tys:ident),+ | $(#[$doc:meta])*) => {
         $(#[$doc])*
             #[repr(simd)]
-        #[derive(Copy, Debug, /*FIXME:*/ PartialOrd)]
+        #[derive(Copy, /*FIXME:*/ PartialOrd)]
         #[allow(non_camel_case_types)]
         pub struct $id($($elem_tys),*);
     }
 }
 
+#[macro_use]
+mod arbitrary;
 #[macro_use]
 mod arithmetic_ops;
 #[macro_use]
 mod arithmetic_reductions;
 #[macro_use]
+mod arithmetic_scalar_ops;
+#[macro_use]
+mod array;
+#[macro_use]
+mod bf16;
+#[macro_use]
 mod bitwise_ops;
 #[macro_use]
 mod bitwise_reductions;
@@ -80,10 +169,26 @@ mod bool_vectors;
 #[macro_use]
 mod cmp;
 #[macro_use]
+mod complex;
+#[macro_use]
 mod default;
 #[macro_use]
+mod divisor;
+#[macro_use]
+mod endian;
+#[macro_use]
 mod eq;
 #[macro_use]
+mod f16;
+#[macro_use]
+mod float_approx;
+#[macro_use]
+mod float_class;
+#[macro_use]
+mod float_math;
+#[macro_use]
+mod float_reductions;
+#[macro_use]
 mod fmt;
 #[macro_use]
 mod from;
@@ -92,15 +197,23 @@ mod from_bits;
 #[macro_use]
 mod hash;
 #[macro_use]
+mod int_math;
+#[macro_use]
+mod iter;
+#[macro_use]
 mod load_store;
 #[macro_use]
 mod minimal;
 #[macro_use]
+mod minmax;
+#[macro_use]
 mod minmax_reductions;
 #[macro_use]
 mod neg;
 #[macro_use]
 mod partial_eq;
+#[macro_use]
+mod ptr;
 // TODO:
 //#[macro_use]
 //mod partial_ord;
@@ -111,7 +224,19 @@ mod partial_eq;
 //#[macro_use]
 //mod gather_scatter;
 #[macro_use]
+mod scan;
+#[macro_use]
+mod serde;
+#[macro_use]
 mod shifts;
+#[macro_use]
+mod slide;
+#[macro_use]
+mod sort;
+#[macro_use]
+mod sum_widening;
+#[macro_use]
+mod traits;
 
 /// Imports required to implement vector types using the macros.
 
@@ -130,7 +255,7 @@ macro_rules! simd_api_imports {
         use convert::{From, Into};
         use slice::SliceExt;
         #[allow(unused_imports)]
-        use iter::Iterator;
+        use iter::{IntoIterator, Iterator};
         #[allow(unused_imports)]
         use default::Default;
         use clone::Clone;
@@ -141,142 +266,233 @@ macro_rules! simd_api_imports {
         use super::codegen::xor::{ReduceXor};
         use super::codegen::min::{ReduceMin};
         use super::codegen::max::{ReduceMax};
+        use super::codegen::all_any::{AllAny};
     }
 }
 
+/// Expands to the tests `$multi` for vectors with more than one lane, and to
+/// the tests `$single` for vectors with a single lane, to which some of the
+/// former do not apply (e.g., replacing the lane with index `1`).
+#[cfg(test)]
+macro_rules! test_by_lane_count {
+    (1, { $($multi:tt)* }, { $($single:tt)* }) => {
+        $($single)*
+    };
+    ($elem_count:tt, { $($multi:tt)* }, { $($single:tt)* }) => {
+        $($multi)*
+    };
+}
+
 /// Defines a portable packed SIMD floating-point vector type.
 macro_rules! simd_f_ty {
     ($id:ident : $elem_count:expr, $elem_ty:ident, $bool_ty:ident, $test_mod:ident |
      $($elem_tys:ident),+ | $($elem_name:ident),+ | $(#[$doc:meta])*) => {
         define_ty!($id, $($elem_tys),+ | $(#[$doc])*);
         impl_minimal!($id, $elem_ty, $elem_count, $($elem_name),*);
+        impl_array!($id, $elem_ty, $elem_count);
+        impl_iter!($id, $elem_ty, $elem_count);
         impl_load_store!($id, $elem_ty, $elem_count);
         impl_cmp!($id, $bool_ty);
         impl_arithmetic_ops!($id);
+        impl_arithmetic_scalar_ops!($id, $elem_ty);
         impl_arithmetic_reductions!($id, $elem_ty);
         impl_minmax_reductions!($id, $elem_ty);
+        impl_float_reductions!($id, $elem_ty, $elem_count);
+        impl_float_approx!($id);
+        impl_float_math!($id);
+        impl_float_fmt!($id);
+        impl_arbitrary!($id, $elem_ty, float);
+        impl_serde!($id, $elem_ty, $elem_count);
         impl_neg_op!($id, $elem_ty);
         impl_partial_eq!($id);
         impl_default!($id, $elem_ty);
+        impl_simd!($id, $elem_ty, $bool_ty, $elem_count);
+        impl_simd_num!($id, $elem_ty, $bool_ty);
+        impl_simd_float!($id, $bool_ty);
 
         #[cfg(test)]
         mod $test_mod {
             test_minimal!($id, $elem_ty, $elem_count);
+            test_array!($id, $elem_ty);
+            test_iter!($id, $elem_ty, 42 as $elem_ty);
             test_load_store!($id, $elem_ty);
             test_cmp!($id, $elem_ty, $bool_ty, 1. as $elem_ty, 0. as $elem_ty);
             test_arithmetic_ops!($id, $elem_ty);
+            test_arithmetic_scalar_ops!($id, $elem_ty);
             test_arithmetic_reductions!($id, $elem_ty);
             test_minmax_reductions!($id, $elem_ty);
+            test_minmax!($id, $elem_ty);
+            test_float_reductions!($id, $elem_ty);
+            test_float_approx!($id, $elem_ty);
+            test_float_math!($id, $elem_ty);
+            test_float_fmt!($id, $elem_ty);
             test_neg_op!($id, $elem_ty);
             test_partial_eq!($id, 1. as $elem_ty, 0. as $elem_ty);
             test_default!($id, $elem_ty);
+            test_simd!($id, $bool_ty, 1. as $elem_ty, 0. as $elem_ty);
+            test_simd_num!($id, $elem_ty);
         }
     }
 }
 
 /// Defines a portable packed SIMD signed-integer vector type.
 macro_rules! simd_i_ty {
-    ($id:ident : $elem_count:expr, $elem_ty:ident, $bool_ty:ident, $test_mod:ident |
+    ($id:ident : $elem_count:tt, $elem_ty:ident, $bool_ty:ident, $test_mod:ident |
      $($elem_tys:ident),+ | $($elem_name:ident),+ | $(#[$doc:meta])*) => {
         define_ty!($id, $($elem_tys),+ | $(#[$doc])*);
         impl_minimal!($id, $elem_ty, $elem_count, $($elem_name),*);
+        impl_array!($id, $elem_ty, $elem_count);
+        impl_iter!($id, $elem_ty, $elem_count);
         impl_load_store!($id, $elem_ty, $elem_count);
         impl_cmp!($id, $bool_ty);
         impl_hash!($id, $elem_ty);
         impl_arithmetic_ops!($id);
+        impl_arithmetic_scalar_ops!($id, $elem_ty);
         impl_arithmetic_reductions!($id, $elem_ty);
         impl_minmax_reductions!($id, $elem_ty);
+        impl_minmax!($id, $id);
         impl_neg_op!($id, $elem_ty);
         impl_bitwise_ops!($id, !(0 as $elem_ty));
         impl_bitwise_reductions!($id, $elem_ty);
         impl_all_shifts!($id, $elem_ty);
-        impl_hex_fmt!($id, $elem_ty);
+        impl_int_fmt!($id);
+        impl_arbitrary!($id, $elem_ty, int);
+        impl_serde!($id, $elem_ty, $elem_count);
         impl_eq!($id);
         impl_partial_eq!($id);
         impl_default!($id, $elem_ty);
+        impl_simd!($id, $elem_ty, $bool_ty, $elem_count);
+        impl_simd_num!($id, $elem_ty, $bool_ty);
+        impl_simd_int!($id, $elem_ty);
 
         #[cfg(test)]
         mod $test_mod {
-            test_minimal!($id, $elem_ty, $elem_count);
+            test_by_lane_count!($elem_count, {
+                test_minimal!($id, $elem_ty, $elem_count);
+                test_arithmetic_reductions!($id, $elem_ty);
+                test_minmax_reductions!($id, $elem_ty);
+                test_bitwise_reductions!($id, !(0 as $elem_ty));
+            }, {
+                test_single_lane!($id, $elem_ty);
+            });
+            test_array!($id, $elem_ty);
+            test_iter!($id, $elem_ty, 42 as $elem_ty);
             test_load_store!($id, $elem_ty);
             test_cmp!($id, $elem_ty, $bool_ty, 1 as $elem_ty, 0 as $elem_ty);
             test_hash!($id, $elem_ty);
             test_arithmetic_ops!($id, $elem_ty);
-            test_arithmetic_reductions!($id, $elem_ty);
-            test_minmax_reductions!($id, $elem_ty);
+            test_arithmetic_scalar_ops!($id, $elem_ty);
+            test_minmax!($id, $elem_ty);
             test_neg_op!($id, $elem_ty);
             test_int_bitwise_ops!($id, $elem_ty);
-            test_bitwise_reductions!($id, !(0 as $elem_ty));
             test_all_shift_ops!($id, $elem_ty);
             test_hex_fmt!($id, $elem_ty);
+            test_int_fmt!($id, $elem_ty);
             test_partial_eq!($id, 1 as $elem_ty, 0 as $elem_ty);
             test_default!($id, $elem_ty);
+            test_simd!($id, $bool_ty, 1 as $elem_ty, 0 as $elem_ty);
+            test_simd_num!($id, $elem_ty);
         }
     }
 }
 
 /// Defines a portable packed SIMD unsigned-integer vector type.
 macro_rules! simd_u_ty {
-    ($id:ident : $elem_count:expr, $elem_ty:ident, $bool_ty:ident, $test_mod:ident |
+    ($id:ident : $elem_count:tt, $elem_ty:ident, $bool_ty:ident, $test_mod:ident |
      $($elem_tys:ident),+ | $($elem_name:ident),+ | $(#[$doc:meta])*) => {
         define_ty!($id, $($elem_tys),+ | $(#[$doc])*);
         impl_minimal!($id, $elem_ty, $elem_count, $($elem_name),*);
+        impl_array!($id, $elem_ty, $elem_count);
+        impl_iter!($id, $elem_ty, $elem_count);
         impl_load_store!($id, $elem_ty, $elem_count);
         impl_cmp!($id, $bool_ty);
         impl_hash!($id, $elem_ty);
         impl_arithmetic_ops!($id);
+        impl_arithmetic_scalar_ops!($id, $elem_ty);
         impl_arithmetic_reductions!($id, $elem_ty);
         impl_minmax_reductions!($id, $elem_ty);
+        impl_minmax!($id, $id);
         impl_bitwise_ops!($id, !(0 as $elem_ty));
         impl_bitwise_reductions!($id, $elem_ty);
         impl_all_shifts!($id, $elem_ty);
-        impl_hex_fmt!($id, $elem_ty);
+        impl_int_fmt!($id);
+        impl_arbitrary!($id, $elem_ty, int);
+        impl_serde!($id, $elem_ty, $elem_count);
         impl_eq!($id);
         impl_partial_eq!($id);
         impl_default!($id, $elem_ty);
+        impl_simd!($id, $elem_ty, $bool_ty, $elem_count);
+        impl_simd_num!($id, $elem_ty, $bool_ty);
+        impl_simd_int!($id, $elem_ty);
 
         #[cfg(test)]
         mod $test_mod {
-            test_minimal!($id, $elem_ty, $elem_count);
+            test_by_lane_count!($elem_count, {
+                test_minimal!($id, $elem_ty, $elem_count);
+                test_arithmetic_reductions!($id, $elem_ty);
+                test_minmax_reductions!($id, $elem_ty);
+                test_bitwise_reductions!($id, !(0 as $elem_ty));
+            }, {
+                test_single_lane!($id, $elem_ty);
+            });
+            test_array!($id, $elem_ty);
+            test_iter!($id, $elem_ty, 42 as $elem_ty);
             test_load_store!($id, $elem_ty);
             test_cmp!($id, $elem_ty, $bool_ty, 1 as $elem_ty, 0 as $elem_ty);
             test_hash!($id, $elem_ty);
             test_arithmetic_ops!($id, $elem_ty);
-            test_arithmetic_reductions!($id, $elem_ty);
-            test_minmax_reductions!($id, $elem_ty);
+            test_arithmetic_scalar_ops!($id, $elem_ty);
+            test_minmax!($id, $elem_ty);
             test_int_bitwise_ops!($id, $elem_ty);
-            test_bitwise_reductions!($id, !(0 as $elem_ty));
             test_all_shift_ops!($id, $elem_ty);
             test_hex_fmt!($id, $elem_ty);
+            test_int_fmt!($id, $elem_ty);
             test_partial_eq!($id, 1 as $elem_ty, 0 as $elem_ty);
             test_default!($id, $elem_ty);
+            test_simd!($id, $bool_ty, 1 as $elem_ty, 0 as $elem_ty);
+            test_simd_num!($id, $elem_ty);
         }
     }
 }
 
 /// Defines a portable packed SIMD boolean vector type.
 macro_rules! simd_b_ty {
-    ($id:ident : $elem_count:expr, $elem_ty:ident, $test_mod:ident |
+    ($id:ident : $elem_count:tt, $elem_ty:ident, $test_mod:ident |
      $($elem_tys:ident),+ | $($elem_name:ident),+ | $(#[$doc:meta])*) => {
         define_ty!($id, $($elem_tys),+ | $(#[$doc])*);
         impl_bool_minimal!($id, $elem_ty, $elem_count, $($elem_name),*);
+        impl_bool_array!($id, $elem_count);
+        impl_iter!($id, bool, $elem_count);
         impl_bitwise_ops!($id, true);
         impl_bool_bitwise_reductions!($id, bool);
         impl_bool_reductions!($id);
+        impl_bool_fmt!($id);
+        impl_arbitrary!($id, bool, bool);
+        impl_serde!($id, bool, $elem_count);
         impl_bool_cmp!($id, $id);
         impl_eq!($id);
         impl_partial_eq!($id);
         impl_default!($id, bool);
+        impl_simd!($id, bool, $id, $elem_count);
+        impl_simd_bool!($id);
 
         #[cfg(test)]
         mod $test_mod {
-            test_bool_minimal!($id, $elem_count);
+            test_by_lane_count!($elem_count, {
+                test_bool_minimal!($id, $elem_count);
+                test_bool_reductions!($id);
+                test_bitwise_reductions!($id, true);
+            }, {
+                test_bool_single_lane!($id);
+            });
+            test_bool_array!($id);
+            test_iter!($id, bool, true);
             test_bool_bitwise_ops!($id);
-            test_bool_reductions!($id);
-            test_bitwise_reductions!($id, true);
+            test_bool_fmt!($id);
             test_cmp!($id, $elem_ty, $id, true, false);
             test_partial_eq!($id, true, false);
             test_default!($id, bool);
+            test_simd!($id, $id, true, false);
         }
     }
 }
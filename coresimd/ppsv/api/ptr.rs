@@ -0,0 +1,569 @@
+//! Vectors of pointers.
+//!
+//! The lanes of `cptrx{N}<T>` and `mptrx{N}<T>` are `*const T` and `*mut T`
+//! respectively. They are stored as a vector of pointer-sized unsigned
+//! integers, on which the pointer arithmetic is performed lane-wise.
+//!
+//! Reading through a vector of pointers reads the pointed-to values into a
+//! portable vector with `T` lanes, and writing through a vector of mutable
+//! pointers writes the lanes of a portable vector to memory. Both access the
+//! memory one lane at a time.
+//!
+//! Vectors of pointers implement neither `serde::Serialize`/`Deserialize`
+//! nor `quickcheck::Arbitrary`: their lanes are addresses, which are only
+//! meaningful within the running process.
+
+/// Defines a portable packed SIMD vector of `*const T` pointers.
+macro_rules! simd_cptr_ty {
+    ($id:ident : $elem_count:expr, $usize_ty:ident, $isize_ty:ident,
+     $bool_ty:ident, $test_mod:ident | $(#[$doc:meta])*) => {
+        impl_ptr!($id, const, $elem_count, $usize_ty, $isize_ty, $bool_ty
+                  | $(#[$doc])*);
+
+        #[cfg(test)]
+        mod $test_mod {
+            test_ptr!($id, const, $elem_count, $usize_ty, $isize_ty);
+        }
+    }
+}
+
+/// Defines a portable packed SIMD vector of `*mut T` pointers.
+macro_rules! simd_mptr_ty {
+    ($id:ident : $elem_count:expr, $cptr_ty:ident, $usize_ty:ident,
+     $isize_ty:ident, $bool_ty:ident, $test_mod:ident
+     | $(#[$doc:meta])*) => {
+        impl_ptr!($id, mut, $elem_count, $usize_ty, $isize_ty, $bool_ty
+                  | $(#[$doc])*);
+        impl_ptr_write!($id, $cptr_ty, $bool_ty);
+
+        #[cfg(test)]
+        mod $test_mod {
+            test_ptr!($id, mut, $elem_count, $usize_ty, $isize_ty);
+            test_ptr_write!($id, $cptr_ty);
+        }
+    }
+}
+
+macro_rules! impl_ptr {
+    ($id:ident, $m:tt, $elem_count:expr, $usize_ty:ident, $isize_ty:ident,
+     $bool_ty:ident | $(#[$doc:meta])*) => {
+        $(#[$doc])*
+        #[derive(Copy)]
+        #[repr(transparent)]
+        #[allow(non_camel_case_types)]
+        pub struct $id<T>($usize_ty, marker::PhantomData<*$m T>);
+
+        #[cfg_attr(feature = "cargo-clippy", allow(expl_impl_clone_on_copy))]
+        impl<T> Clone for $id<T> {
+            #[inline] // currently needed for correctness
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T> Default for $id<T> {
+            /// Returns a vector of null pointers.
+            #[inline]
+            fn default() -> Self {
+                Self::null()
+            }
+        }
+
+        impl<T> fmt::Debug for $id<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}(", stringify!($id))?;
+                for i in 0..$elem_count {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    fmt::Pointer::fmt(&self.extract(i), f)?;
+                }
+                write!(f, ")")
+            }
+        }
+
+        impl<T> PartialEq for $id<T> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<T> Eq for $id<T> {}
+
+        impl<T> From<[*$m T; $elem_count]> for $id<T> {
+            #[inline]
+            fn from(array: [*$m T; $elem_count]) -> Self {
+                unsafe { mem::transmute(array) }
+            }
+        }
+
+        impl<T> From<$id<T>> for [*$m T; $elem_count] {
+            #[inline]
+            fn from(vec: $id<T>) -> Self {
+                unsafe { mem::transmute(vec) }
+            }
+        }
+
+        impl<T> $id<T> {
+            /// The number of vector lanes.
+            pub const LANES: usize = $elem_count;
+
+            /// Returns the number of vector lanes.
+            #[inline]
+            pub const fn lanes() -> usize {
+                $elem_count
+            }
+
+            /// Constructs a new instance with each lane initialized to
+            /// `ptr`.
+            #[inline]
+            pub fn splat(ptr: *$m T) -> Self {
+                $id($usize_ty::splat(ptr as usize as _), marker::PhantomData)
+            }
+
+            /// Constructs a new instance with all lanes set to null
+            /// pointers.
+            #[inline]
+            pub fn null() -> Self {
+                $id($usize_ty::splat(0), marker::PhantomData)
+            }
+
+            /// Returns a mask of the lanes that contain null pointers.
+            #[inline]
+            pub fn is_null(self) -> $bool_ty {
+                self.0.eq($usize_ty::splat(0))
+            }
+
+            /// Extracts the pointer at `index`.
+            ///
+            /// # Panics
+            ///
+            /// If `index >= Self::lanes()`.
+            #[inline]
+            pub fn extract(self, index: usize) -> *$m T {
+                self.0.extract(index) as usize as *$m T
+            }
+
+            /// Extracts the pointer at `index`.
+            ///
+            /// If `index >= Self::lanes()` the behavior is undefined.
+            #[inline]
+            pub unsafe fn extract_unchecked(self, index: usize) -> *$m T {
+                self.0.extract_unchecked(index) as usize as *$m T
+            }
+
+            /// Returns a new vector where the pointer at `index` is replaced
+            /// by `new_value`.
+            ///
+            /// # Panics
+            ///
+            /// If `index >= Self::lanes()`.
+            #[inline]
+            #[must_use = "replace does not modify the original value - \
+                          it returns a new vector with the value at `index` \
+                          replaced by `new_value`d"]
+            pub fn replace(self, index: usize, new_value: *$m T) -> Self {
+                $id(
+                    self.0.replace(index, new_value as usize as _),
+                    marker::PhantomData,
+                )
+            }
+
+            /// Returns a new vector where the pointer at `index` is replaced
+            /// by `new_value`.
+            ///
+            /// If `index >= Self::lanes()` the behavior is undefined.
+            #[inline]
+            #[must_use = "replace_unchecked does not modify the original \
+                          value - it returns a new vector with the value at \
+                          `index` replaced by `new_value`d"]
+            pub unsafe fn replace_unchecked(
+                self, index: usize, new_value: *$m T,
+            ) -> Self {
+                $id(
+                    self.0.replace_unchecked(index, new_value as usize as _),
+                    marker::PhantomData,
+                )
+            }
+
+            /// Lane-wise equality comparison of the pointer addresses.
+            #[inline]
+            pub fn eq(self, other: Self) -> $bool_ty {
+                self.0.eq(other.0)
+            }
+
+            /// Lane-wise inequality comparison of the pointer addresses.
+            #[inline]
+            pub fn ne(self, other: Self) -> $bool_ty {
+                self.0.ne(other.0)
+            }
+
+            /// Calculates the lane-wise offset from a pointer, in units of
+            /// `T`.
+            ///
+            /// # Safety
+            ///
+            /// Each lane must satisfy the contract of the scalar `offset`
+            /// method of raw pointers: the pointer and its offset must be
+            /// in bounds of, or one byte past the end of, the same
+            /// allocated object, and the offset in bytes must fit in an
+            /// `isize` without overflowing. The result is then the same as
+            /// that of `wrapping_offset`. Use `wrapping_offset` when these
+            /// conditions cannot be guaranteed.
+            #[inline]
+            pub unsafe fn offset(self, count: $isize_ty) -> Self {
+                self.wrapping_offset(count)
+            }
+
+            /// Calculates the lane-wise offset from a pointer, in units of
+            /// `T`, using wrapping arithmetic.
+            ///
+            /// The resulting pointers are not necessarily valid to
+            /// dereference.
+            #[inline]
+            pub fn wrapping_offset(self, count: $isize_ty) -> Self {
+                self.wrapping_add($usize_ty::from_bits(count))
+            }
+
+            /// Calculates the lane-wise offset from a pointer, in units of
+            /// `T`.
+            ///
+            /// # Safety
+            ///
+            /// Each lane must satisfy the contract of the scalar `add`
+            /// method of raw pointers: the pointer and its offset must be
+            /// in bounds of, or one byte past the end of, the same
+            /// allocated object, and the offset in bytes must fit in an
+            /// `isize` without overflowing. The result is then the same as
+            /// that of `wrapping_add`. Use `wrapping_add` when these
+            /// conditions cannot be guaranteed.
+            #[inline]
+            pub unsafe fn add(self, count: $usize_ty) -> Self {
+                self.wrapping_add(count)
+            }
+
+            /// Calculates the lane-wise offset from a pointer, in units of
+            /// `T`, using wrapping arithmetic.
+            ///
+            /// The resulting pointers are not necessarily valid to
+            /// dereference.
+            #[inline]
+            pub fn wrapping_add(self, count: $usize_ty) -> Self {
+                let size = $usize_ty::splat(mem::size_of::<T>() as _);
+                $id(self.0 + count * size, marker::PhantomData)
+            }
+
+            /// Reads the values pointed to by each lane into a vector.
+            ///
+            /// # Safety
+            ///
+            /// The pointer of every lane must be valid for reads.
+            ///
+            /// # Panics
+            ///
+            /// If `V::LANES != Self::lanes()`.
+            #[inline]
+            pub unsafe fn read<V>(self) -> V
+            where
+                V: ::coresimd::simd::Simd<Element = T>,
+                T: marker::Copy,
+            {
+                assert_eq!(V::LANES, $elem_count);
+                let mut r = V::default();
+                for i in 0..$elem_count {
+                    r = r.replace_unchecked(i, ptr::read(self.extract(i)));
+                }
+                r
+            }
+
+            /// Reads the values pointed to by the lanes selected by `mask`
+            /// into a vector. The lanes not selected by `mask` are taken
+            /// from `or`.
+            ///
+            /// # Safety
+            ///
+            /// The pointers of the lanes selected by `mask` must be valid
+            /// for reads. The other lanes are never dereferenced.
+            ///
+            /// # Panics
+            ///
+            /// If `V::LANES != Self::lanes()`.
+            #[inline]
+            pub unsafe fn read_masked<V>(self, mask: $bool_ty, or: V) -> V
+            where
+                V: ::coresimd::simd::Simd<Element = T>,
+                T: marker::Copy,
+            {
+                assert_eq!(V::LANES, $elem_count);
+                let mut r = or;
+                for i in 0..$elem_count {
+                    if mask.extract_unchecked(i) {
+                        r = r.replace_unchecked(
+                            i, ptr::read(self.extract(i))
+                        );
+                    }
+                }
+                r
+            }
+        }
+    }
+}
+
+macro_rules! impl_ptr_write {
+    ($id:ident, $cptr_ty:ident, $bool_ty:ident) => {
+        impl<T> From<$id<T>> for $cptr_ty<T> {
+            #[inline]
+            fn from(vec: $id<T>) -> Self {
+                unsafe { mem::transmute(vec) }
+            }
+        }
+
+        impl<T> $id<T> {
+            /// Writes the lanes of `values` to the memory locations pointed
+            /// to by each lane.
+            ///
+            /// The lanes are written in increasing lane order, that is, if
+            /// several lanes point to the same memory location, the value of
+            /// the last of these lanes is written last.
+            ///
+            /// # Safety
+            ///
+            /// The pointer of every lane must be valid for writes.
+            ///
+            /// # Panics
+            ///
+            /// If `V::LANES != Self::lanes()`.
+            #[inline]
+            pub unsafe fn write<V>(self, values: V)
+            where
+                V: ::coresimd::simd::Simd<Element = T>,
+                T: marker::Copy,
+            {
+                assert_eq!(V::LANES, Self::lanes());
+                for i in 0..Self::lanes() {
+                    ptr::write(self.extract(i), values.extract_unchecked(i));
+                }
+            }
+
+            /// Writes the lanes of `values` selected by `mask` to the memory
+            /// locations pointed to by the corresponding lanes.
+            ///
+            /// The lanes are written in increasing lane order, see
+            /// `write`.
+            ///
+            /// # Safety
+            ///
+            /// The pointers of the lanes selected by `mask` must be valid
+            /// for writes. The other lanes are never dereferenced.
+            ///
+            /// # Panics
+            ///
+            /// If `V::LANES != Self::lanes()`.
+            #[inline]
+            pub unsafe fn write_masked<V>(self, mask: $bool_ty, values: V)
+            where
+                V: ::coresimd::simd::Simd<Element = T>,
+                T: marker::Copy,
+            {
+                assert_eq!(V::LANES, Self::lanes());
+                for i in 0..Self::lanes() {
+                    if mask.extract_unchecked(i) {
+                        ptr::write(
+                            self.extract(i), values.extract_unchecked(i)
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_ptr {
+    ($id:ident, $m:tt, $elem_count:expr, $usize_ty:ident,
+     $isize_ty:ident) => {
+        #[test]
+        fn ptr_null() {
+            use ::coresimd::simd::$id;
+            use std::default::Default;
+            let v = $id::<i32>::null();
+            assert!(v.is_null().all());
+            assert_eq!(v, $id::default());
+            for i in 0..$id::<i32>::lanes() {
+                assert!(v.extract(i).is_null());
+            }
+
+            let mut x = 42_i32;
+            let p = &mut x as *$m i32;
+            let v = $id::splat(p);
+            assert!(v.is_null().none());
+            let v = v.replace(0, 0 as *$m i32);
+            assert!(v.is_null().extract(0));
+            assert!(v.ne($id::splat(p)).extract(0));
+            for i in 1..$id::<i32>::lanes() {
+                assert_eq!(v.extract(i), p);
+                assert!(!v.is_null().extract(i));
+                assert!(v.eq($id::splat(p)).extract(i));
+            }
+        }
+
+        #[test]
+        fn ptr_offset() {
+            use ::coresimd::simd::$id;
+            use super::{$isize_ty, $usize_ty};
+            let mut a = [0_i64; 64];
+            let p = &mut a[32] as *$m i64;
+            let v = $id::splat(p);
+
+            let mut offsets = $isize_ty::splat(0);
+            let mut counts = $usize_ty::splat(0);
+            for i in 0..$id::<i64>::lanes() {
+                offsets = offsets.replace(i, -(i as isize) as _);
+                counts = counts.replace(i, i as _);
+            }
+
+            let o = unsafe { v.offset(offsets) };
+            let wo = v.wrapping_offset(offsets);
+            let ad = unsafe { v.add(counts) };
+            let wa = v.wrapping_add(counts);
+            assert_eq!(o, wo);
+            assert_eq!(ad, wa);
+            for i in 0..$id::<i64>::lanes() {
+                assert_eq!(o.extract(i), unsafe { p.offset(-(i as isize)) });
+                assert_eq!(ad.extract(i), unsafe { p.offset(i as isize) });
+            }
+        }
+
+        #[test]
+        fn ptr_read() {
+            use ::coresimd::simd::*;
+            use super::$usize_ty;
+            let mut a = [0_u32; 64];
+            for i in 0..64 {
+                a[i] = i as u32 * 3;
+            }
+            let p = &mut a[0] as *$m u32;
+
+            // Every other element of `a`, in reverse order:
+            let lanes = $id::<u32>::lanes();
+            let mut counts = $usize_ty::splat(0);
+            for i in 0..lanes {
+                counts = counts.replace(i, (2 * (lanes - 1 - i)) as _);
+            }
+            let v = $id::splat(p).wrapping_add(counts);
+
+            macro_rules! check_read {
+                ($vec:ident) => {
+                    if $vec::lanes() == lanes {
+                        let r: $vec = unsafe { v.read() };
+                        for i in 0..lanes {
+                            assert_eq!(r.extract(i), a[2 * (lanes - 1 - i)]);
+                        }
+
+                        // Null pointers are not dereferenced by masked reads:
+                        let mut mask = v.is_null();
+                        let mut v = v;
+                        for i in 0..lanes {
+                            if i % 2 == 1 {
+                                v = v.replace(i, 0 as *$m u32);
+                            } else {
+                                mask = mask.replace(i, true);
+                            }
+                        }
+                        assert_eq!(mask, !v.is_null());
+                        let or = $vec::splat(42);
+                        let r: $vec = unsafe { v.read_masked(mask, or) };
+                        for i in 0..lanes {
+                            if i % 2 == 1 {
+                                assert_eq!(r.extract(i), 42);
+                            } else {
+                                assert_eq!(
+                                    r.extract(i), a[2 * (lanes - 1 - i)]
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+            check_read!(u32x2);
+            check_read!(u32x4);
+            check_read!(u32x8);
+            check_read!(u32x16);
+        }
+
+        #[test]
+        fn ptr_array() {
+            use ::coresimd::simd::$id;
+            use std::prelude::v1::*;
+            let mut a = [0_u8; 16];
+            let v = $id::<u8>::splat(&mut a[0] as *$m u8);
+            let arr: [*$m u8; $elem_count] = v.into();
+            assert_eq!($id::from(arr), v);
+            for i in 0..$id::<u8>::lanes() {
+                assert_eq!(arr[i], v.extract(i));
+            }
+            let s = format!("{:?}", $id::<u8>::null());
+            assert!(s.starts_with(concat!(stringify!($id), "(0x0")));
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_ptr_write {
+    ($id:ident, $cptr_ty:ident) => {
+        #[test]
+        fn ptr_write() {
+            use ::coresimd::simd::*;
+            use std::convert::Into;
+            let mut a = [0_f32; 64];
+            let lanes = $id::<f32>::lanes();
+            let p = &mut a[0] as *mut f32;
+
+            macro_rules! check_write {
+                ($vec:ident) => {
+                    if $vec::lanes() == lanes {
+                        let mut v = $id::splat(p);
+                        for i in 0..lanes {
+                            v = v.replace(i, unsafe { p.add(3 * i) });
+                        }
+                        let mut x = $vec::splat(0.);
+                        for i in 0..lanes {
+                            x = x.replace(i, i as f32 + 1.);
+                        }
+                        unsafe { v.write(x) };
+                        for i in 0..lanes {
+                            assert_eq!(a[3 * i], i as f32 + 1.);
+                        }
+                        let c: $cptr_ty<f32> = v.into();
+                        let r: $vec = unsafe { c.read() };
+                        assert_eq!(r, x);
+
+                        // Only the even lanes are written:
+                        let mut mask = v.is_null();
+                        for i in 0..lanes {
+                            mask = mask.replace(i, i % 2 == 0);
+                        }
+                        unsafe { v.write_masked(mask, $vec::splat(-1.)) };
+                        for i in 0..lanes {
+                            if i % 2 == 0 {
+                                assert_eq!(a[3 * i], -1.);
+                            } else {
+                                assert_eq!(a[3 * i], i as f32 + 1.);
+                            }
+                        }
+
+                        // The last lane pointing to a location wins:
+                        let v = $id::splat(p);
+                        unsafe { v.write(x) };
+                        assert_eq!(a[0], lanes as f32);
+                    }
+                }
+            }
+            check_write!(f32x2);
+            check_write!(f32x4);
+            check_write!(f32x8);
+            check_write!(f32x16);
+        }
+    }
+}
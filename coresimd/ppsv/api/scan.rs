@@ -0,0 +1,429 @@
+//! Implements lane-wise prefix sums (scans) of the vector lanes.
+//!
+//! A scan of `N` lanes is computed in `log2(N)` steps: at step `k`, the
+//! lanes are shifted up by `2^k` lanes, filling the lowest lanes with the
+//! identity of the operation, and combined lane-wise with the unshifted
+//! lanes. The lane shifts are shuffles with a constant vector, which LLVM
+//! lowers to whole-register byte shifts (`pslldq`, that is,
+//! `_mm_slli_si128`, on `x86`/`x86_64`, and `vext` on ARM and AArch64).
+
+/// Shifts the `N` lanes of `$v` up by `K` lanes: the lane `i` of the result
+/// is the lane `i - K` of `$v` if `i >= K`, and the lane `i` of `$fill`
+/// otherwise.
+macro_rules! shift_lanes_up {
+    ($id:ident, 1, 1, $v:expr, $fill:expr) => {{
+        let _ = $v;
+        let r: $id = $fill;
+        r
+    }};
+    ($id:ident, 2, 1, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle2($v, $fill, [2, 0]);
+        r
+    }};
+    ($id:ident, 4, 1, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle4($v, $fill, [4, 0, 1, 2]);
+        r
+    }};
+    ($id:ident, 4, 2, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle4($v, $fill, [4, 5, 0, 1]);
+        r
+    }};
+    ($id:ident, 8, 1, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle8($v, $fill, [8, 0, 1, 2, 3, 4, 5, 6]);
+        r
+    }};
+    ($id:ident, 8, 2, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle8($v, $fill, [8, 9, 0, 1, 2, 3, 4, 5]);
+        r
+    }};
+    ($id:ident, 8, 4, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle8($v, $fill, [8, 9, 10, 11, 0, 1, 2, 3]);
+        r
+    }};
+    ($id:ident, 16, 1, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle16(
+            $v,
+            $fill,
+            [16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14],
+        );
+        r
+    }};
+    ($id:ident, 16, 2, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle16(
+            $v,
+            $fill,
+            [16, 17, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13],
+        );
+        r
+    }};
+    ($id:ident, 16, 4, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle16(
+            $v,
+            $fill,
+            [16, 17, 18, 19, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+        );
+        r
+    }};
+    ($id:ident, 16, 8, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle16(
+            $v,
+            $fill,
+            [16, 17, 18, 19, 20, 21, 22, 23, 0, 1, 2, 3, 4, 5, 6, 7],
+        );
+        r
+    }};
+    ($id:ident, 32, 1, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle32(
+            $v,
+            $fill,
+            [
+                32, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+                17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30
+            ],
+        );
+        r
+    }};
+    ($id:ident, 32, 2, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle32(
+            $v,
+            $fill,
+            [
+                32, 33, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+                16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29
+            ],
+        );
+        r
+    }};
+    ($id:ident, 32, 4, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle32(
+            $v,
+            $fill,
+            [
+                32, 33, 34, 35, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13,
+                14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27
+            ],
+        );
+        r
+    }};
+    ($id:ident, 32, 8, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle32(
+            $v,
+            $fill,
+            [
+                32, 33, 34, 35, 36, 37, 38, 39, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+                10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23
+            ],
+        );
+        r
+    }};
+    ($id:ident, 32, 16, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle32(
+            $v,
+            $fill,
+            [
+                32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, 1, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle64(
+            $v,
+            $fill,
+            [
+                64, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+                17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+                33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48,
+                49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, 2, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle64(
+            $v,
+            $fill,
+            [
+                64, 65, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+                16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+                32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+                48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, 4, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle64(
+            $v,
+            $fill,
+            [
+                64, 65, 66, 67, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13,
+                14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29,
+                30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45,
+                46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, 8, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle64(
+            $v,
+            $fill,
+            [
+                64, 65, 66, 67, 68, 69, 70, 71, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+                10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+                26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41,
+                42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, 16, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle64(
+            $v,
+            $fill,
+            [
+                64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79,
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33,
+                34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, 32, $v:expr, $fill:expr) => {{
+        let r: $id = simd_shuffle64(
+            $v,
+            $fill,
+            [
+                64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79,
+                80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95,
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31
+            ],
+        );
+        r
+    }};
+}
+
+/// Inclusive scan of the `N` lanes of `$v` with the lane-wise operation
+/// `$f`, whose identity is `$fill`.
+macro_rules! scan_lanes {
+    ($id:ident, 1, $v:expr, $fill:expr, $f:expr) => {{
+        let _ = ($fill, &$f);
+        let v: $id = $v;
+        v
+    }};
+    ($id:ident, 2, $v:expr, $fill:expr, $f:expr) => {{
+        let fill: $id = $fill;
+        let f = &$f;
+        let v: $id = $v;
+        let v = f(shift_lanes_up!($id, 2, 1, v, fill), v);
+        v
+    }};
+    ($id:ident, 4, $v:expr, $fill:expr, $f:expr) => {{
+        let fill: $id = $fill;
+        let f = &$f;
+        let v: $id = $v;
+        let v = f(shift_lanes_up!($id, 4, 1, v, fill), v);
+        let v = f(shift_lanes_up!($id, 4, 2, v, fill), v);
+        v
+    }};
+    ($id:ident, 8, $v:expr, $fill:expr, $f:expr) => {{
+        let fill: $id = $fill;
+        let f = &$f;
+        let v: $id = $v;
+        let v = f(shift_lanes_up!($id, 8, 1, v, fill), v);
+        let v = f(shift_lanes_up!($id, 8, 2, v, fill), v);
+        let v = f(shift_lanes_up!($id, 8, 4, v, fill), v);
+        v
+    }};
+    ($id:ident, 16, $v:expr, $fill:expr, $f:expr) => {{
+        let fill: $id = $fill;
+        let f = &$f;
+        let v: $id = $v;
+        let v = f(shift_lanes_up!($id, 16, 1, v, fill), v);
+        let v = f(shift_lanes_up!($id, 16, 2, v, fill), v);
+        let v = f(shift_lanes_up!($id, 16, 4, v, fill), v);
+        let v = f(shift_lanes_up!($id, 16, 8, v, fill), v);
+        v
+    }};
+    ($id:ident, 32, $v:expr, $fill:expr, $f:expr) => {{
+        let fill: $id = $fill;
+        let f = &$f;
+        let v: $id = $v;
+        let v = f(shift_lanes_up!($id, 32, 1, v, fill), v);
+        let v = f(shift_lanes_up!($id, 32, 2, v, fill), v);
+        let v = f(shift_lanes_up!($id, 32, 4, v, fill), v);
+        let v = f(shift_lanes_up!($id, 32, 8, v, fill), v);
+        let v = f(shift_lanes_up!($id, 32, 16, v, fill), v);
+        v
+    }};
+    ($id:ident, 64, $v:expr, $fill:expr, $f:expr) => {{
+        let fill: $id = $fill;
+        let f = &$f;
+        let v: $id = $v;
+        let v = f(shift_lanes_up!($id, 64, 1, v, fill), v);
+        let v = f(shift_lanes_up!($id, 64, 2, v, fill), v);
+        let v = f(shift_lanes_up!($id, 64, 4, v, fill), v);
+        let v = f(shift_lanes_up!($id, 64, 8, v, fill), v);
+        let v = f(shift_lanes_up!($id, 64, 16, v, fill), v);
+        let v = f(shift_lanes_up!($id, 64, 32, v, fill), v);
+        v
+    }};
+}
+
+macro_rules! impl_scan {
+    ($id:ident: $elem_count:tt, $elem_ty:ident, $max_id:expr) => {
+        impl $id {
+            /// Inclusive scan of the lanes with the lane-wise operation
+            /// `f`: the lane `i` of the result is `x0 ⊕ x1 ⊕ ... ⊕ xi`,
+            /// where `⊕` is `f`.
+            ///
+            /// The operation `f` must be associative, and `identity` must
+            /// be its identity element. The lanes are combined as a tree of
+            /// `log2(lanes)` steps, in an unspecified order.
+            #[inline]
+            pub fn scan<F>(self, identity: $elem_ty, f: F) -> Self
+            where
+                F: ::ops::Fn(Self, Self) -> Self,
+            {
+                let fill = $id::splat(identity);
+                unsafe { scan_lanes!($id, $elem_count, self, fill, f) }
+            }
+
+            /// Exclusive scan of the lanes with the lane-wise operation
+            /// `f`: the lane `i` of the result is
+            /// `identity ⊕ x0 ⊕ ... ⊕ x(i - 1)`, where `⊕` is `f`.
+            ///
+            /// See `scan` for the requirements on `f` and `identity`.
+            #[inline]
+            pub fn scan_exclusive<F>(self, identity: $elem_ty, f: F) -> Self
+            where
+                F: ::ops::Fn(Self, Self) -> Self,
+            {
+                let fill = $id::splat(identity);
+                let v = unsafe {
+                    shift_lanes_up!($id, $elem_count, 1, self, fill)
+                };
+                v.scan(identity, f)
+            }
+
+            /// Inclusive prefix sum of the lanes: the lane `i` of the
+            /// result is the sum of the lanes `0..=i`.
+            #[inline]
+            pub fn prefix_sum(self) -> Self {
+                self.scan(0 as $elem_ty, |a, b| a + b)
+            }
+
+            /// Exclusive prefix sum of the lanes: the lane `i` of the
+            /// result is the sum of the lanes `0..i`, and the lane `0` is
+            /// zero.
+            #[inline]
+            pub fn prefix_sum_exclusive(self) -> Self {
+                self.scan_exclusive(0 as $elem_ty, |a, b| a + b)
+            }
+
+            /// Inclusive maximum scan of the lanes: the lane `i` of the
+            /// result is the largest of the lanes `0..=i`.
+            #[inline]
+            pub fn scan_max(self) -> Self {
+                self.scan($max_id, |a, b| a.lanewise_max(b))
+            }
+        }
+    };
+}
+
+macro_rules! impl_int_scan {
+    ($id:ident: $elem_count:tt, $elem_ty:ident, $test_mod:ident) => {
+        impl_scan!($id: $elem_count, $elem_ty, $elem_ty::min_value());
+
+        impl $id {
+            /// Inclusive bitwise-or scan of the lanes: the lane `i` of the
+            /// result is the bitwise or of the lanes `0..=i`.
+            #[inline]
+            pub fn scan_or(self) -> Self {
+                self.scan(0, |a, b| a | b)
+            }
+        }
+
+        #[cfg(test)]
+        mod $test_mod {
+            use ::coresimd::simd::$id;
+            use std::prelude::v1::*;
+
+            #[test]
+            fn scan() {
+                let mut seed = 42_u64;
+                for _ in 0..16 {
+                    let mut v = $id::splat(0);
+                    let mut a = Vec::new();
+                    for i in 0..$id::lanes() {
+                        seed = seed
+                            .wrapping_mul(6364136223846793005)
+                            .wrapping_add(1442695040888963407);
+                        let x = (seed >> 24) as $elem_ty;
+                        v = v.replace(i, x);
+                        a.push(x);
+                    }
+                    let sum = v.prefix_sum();
+                    let sum_ex = v.prefix_sum_exclusive();
+                    let max = v.scan_max();
+                    let or = v.scan_or();
+                    let (mut s, mut m, mut o) = (0 as $elem_ty, a[0], 0);
+                    for i in 0..$id::lanes() {
+                        assert_eq!(sum_ex.extract(i), s);
+                        s = s.wrapping_add(a[i]);
+                        m = m.max(a[i]);
+                        o |= a[i];
+                        assert_eq!(sum.extract(i), s);
+                        assert_eq!(max.extract(i), m);
+                        assert_eq!(or.extract(i), o);
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_scan {
+    ($id:ident: $elem_count:tt, $elem_ty:ident, $test_mod:ident) => {
+        impl_scan!($id: $elem_count, $elem_ty, -1. / 0.);
+
+        #[cfg(test)]
+        mod $test_mod {
+            use ::coresimd::simd::$id;
+            use std::prelude::v1::*;
+
+            #[test]
+            fn scan() {
+                let mut seed = 42_u64;
+                for _ in 0..16 {
+                    let mut v = $id::splat(0.);
+                    let mut a = Vec::new();
+                    for i in 0..$id::lanes() {
+                        seed = seed
+                            .wrapping_mul(6364136223846793005)
+                            .wrapping_add(1442695040888963407);
+                        // Small integers, whose sums are exact:
+                        let x = ((seed >> 33) % 64) as $elem_ty - 32.;
+                        v = v.replace(i, x);
+                        a.push(x);
+                    }
+                    let sum = v.prefix_sum();
+                    let sum_ex = v.prefix_sum_exclusive();
+                    let max = v.scan_max();
+                    let (mut s, mut m) = (0., a[0]);
+                    for i in 0..$id::lanes() {
+                        assert_eq!(sum_ex.extract(i), s);
+                        s += a[i];
+                        m = m.max(a[i]);
+                        assert_eq!(sum.extract(i), s);
+                        assert_eq!(max.extract(i), m);
+                    }
+                }
+            }
+        }
+    };
+}
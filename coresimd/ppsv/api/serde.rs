@@ -0,0 +1,208 @@
+//! Implements `serde::Serialize` and `serde::Deserialize` when the `serde`
+//! feature is enabled.
+//!
+//! The vectors are serialized as tuples of their lanes, like arrays of the
+//! same length. They can be deserialized from sequences and tuples, which
+//! must contain exactly `lanes()` elements.
+//!
+//! Vectors of pointers are excluded, since their lanes are only meaningful
+//! within the running process. The tests below, which use `serde_test`, are
+//! only compiled with the `serde` feature.
+
+macro_rules! impl_serde {
+    ($id:ident, $elem_ty:ident, $elem_count:expr) => {
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $id {
+            fn serialize<S>(&self, serializer: S)
+                            -> ::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                use ::serde::ser::SerializeTuple;
+                let mut t = serializer.serialize_tuple($elem_count)?;
+                for i in 0..$elem_count {
+                    t.serialize_element(&self.extract(i))?;
+                }
+                t.end()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $id {
+            fn deserialize<D>(deserializer: D)
+                              -> ::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                impl_serde!(@visitor $id, $id, $elem_ty, $elem_count, |v| v);
+                deserializer.deserialize_tuple($elem_count, LanesVisitor)
+            }
+        }
+    };
+    // Defines `LanesVisitor`, which reads `$elem_count` lanes of type
+    // `$elem_ty` into a `$lanes_ty`, and converts it into a `$id` with
+    // `$convert`.
+    (@visitor $id:ident, $lanes_ty:ident, $elem_ty:ident, $elem_count:expr,
+     $convert:expr) => {
+        struct LanesVisitor;
+
+        impl<'de> ::serde::de::Visitor<'de> for LanesVisitor {
+            type Value = $id;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a sequence of {} lanes", $elem_count)
+            }
+
+            fn visit_seq<A>(self, mut seq: A)
+                            -> ::result::Result<$id, A::Error>
+            where
+                A: ::serde::de::SeqAccess<'de>,
+            {
+                let mut v: $lanes_ty = Default::default();
+                for i in 0..$elem_count {
+                    match seq.next_element::<$elem_ty>()? {
+                        ::option::Option::Some(x) => v = v.replace(i, x),
+                        ::option::Option::None => {
+                            return ::result::Result::Err(
+                                ::serde::de::Error::invalid_length(i, &self),
+                            )
+                        }
+                    }
+                }
+                let extra = seq.next_element::<::serde::de::IgnoredAny>()?;
+                if extra.is_some() {
+                    return ::result::Result::Err(
+                        ::serde::de::Error::invalid_length(
+                            $elem_count + 1,
+                            &self,
+                        ),
+                    );
+                }
+                let convert: fn($lanes_ty) -> $id = $convert;
+                ::result::Result::Ok(convert(v))
+            }
+        }
+    };
+}
+
+/// Half-precision vectors are serialized as tuples of `f32` lanes, which
+/// represent their values exactly.
+macro_rules! impl_f16_serde {
+    ($id:ident, $f32_ty:ident, $elem_count:expr) => {
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $id {
+            fn serialize<S>(&self, serializer: S)
+                            -> ::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                ::serde::Serialize::serialize(&self.to_f32(), serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $id {
+            fn deserialize<D>(deserializer: D)
+                              -> ::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                impl_serde!(
+                    @visitor $id, $f32_ty, f32, $elem_count, $id::from_f32
+                );
+                deserializer.deserialize_tuple($elem_count, LanesVisitor)
+            }
+        }
+    };
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use coresimd::simd::*;
+    use serde_test::{assert_de_tokens, assert_de_tokens_error,
+                     assert_ser_tokens, assert_tokens, Token};
+
+    #[test]
+    fn serde_i32x4() {
+        let v = i32x4::new(1, -2, 3, -4);
+        assert_tokens(
+            &v,
+            &[
+                Token::Tuple { len: 4 },
+                Token::I32(1),
+                Token::I32(-2),
+                Token::I32(3),
+                Token::I32(-4),
+                Token::TupleEnd,
+            ],
+        );
+        // Sequences are accepted too:
+        assert_de_tokens(
+            &v,
+            &[
+                Token::Seq { len: Some(4) },
+                Token::I32(1),
+                Token::I32(-2),
+                Token::I32(3),
+                Token::I32(-4),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn serde_f64x2_b8x2() {
+        assert_tokens(
+            &f64x2::new(1.5, -0.25),
+            &[
+                Token::Tuple { len: 2 },
+                Token::F64(1.5),
+                Token::F64(-0.25),
+                Token::TupleEnd,
+            ],
+        );
+        assert_tokens(
+            &b8x2::new(true, false),
+            &[
+                Token::Tuple { len: 2 },
+                Token::Bool(true),
+                Token::Bool(false),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn serde_f16x4() {
+        let v = f16x4::from_f32(f32x4::new(1., -2., 0.5, 65504.));
+        assert_ser_tokens(
+            &v,
+            &[
+                Token::Tuple { len: 4 },
+                Token::F32(1.),
+                Token::F32(-2.),
+                Token::F32(0.5),
+                Token::F32(65504.),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn serde_lane_count() {
+        assert_de_tokens_error::<u8x2>(
+            &[Token::Seq { len: Some(1) }, Token::U8(1), Token::SeqEnd],
+            "invalid length 1, expected a sequence of 2 lanes",
+        );
+        assert_de_tokens_error::<u8x2>(
+            &[
+                Token::Seq { len: Some(3) },
+                Token::U8(1),
+                Token::U8(2),
+                Token::U8(3),
+                Token::SeqEnd,
+            ],
+            "invalid length 3, expected a sequence of 2 lanes",
+        );
+    }
+}
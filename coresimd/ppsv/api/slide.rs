@@ -0,0 +1,464 @@
+//! Implements lane rotations and shifts, and the extraction of a vector
+//! from the concatenation of two vectors.
+//!
+//! These are all implemented with `slide`, which shifts the concatenation
+//! of two vectors by `n` lanes in `log2(lanes)` steps of power-of-two lane
+//! shifts, selected by the bits of `n`. When `n` is a constant, the steps
+//! fold into a single shuffle, which LLVM lowers to `palignr`
+//! (`_mm_alignr_epi8`) on `x86`/`x86_64` with `ssse3`, and to `vext` on ARM
+//! and AArch64 (see `codegen::slide`).
+
+/// Returns the lanes `K..K + N` of the concatenation of `$a` and `$b`.
+macro_rules! slide_lanes_by {
+    ($id:ident, 2, 1, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle2($a, $b, [1, 2]);
+        r
+    }};
+    ($id:ident, 4, 1, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle4($a, $b, [1, 2, 3, 4]);
+        r
+    }};
+    ($id:ident, 4, 2, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle4($a, $b, [2, 3, 4, 5]);
+        r
+    }};
+    ($id:ident, 8, 1, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle8($a, $b, [1, 2, 3, 4, 5, 6, 7, 8]);
+        r
+    }};
+    ($id:ident, 8, 2, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle8($a, $b, [2, 3, 4, 5, 6, 7, 8, 9]);
+        r
+    }};
+    ($id:ident, 8, 4, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle8($a, $b, [4, 5, 6, 7, 8, 9, 10, 11]);
+        r
+    }};
+    ($id:ident, 16, 1, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle16(
+            $a,
+            $b,
+            [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+        );
+        r
+    }};
+    ($id:ident, 16, 2, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle16(
+            $a,
+            $b,
+            [2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17],
+        );
+        r
+    }};
+    ($id:ident, 16, 4, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle16(
+            $a,
+            $b,
+            [4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19],
+        );
+        r
+    }};
+    ($id:ident, 16, 8, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle16(
+            $a,
+            $b,
+            [8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23],
+        );
+        r
+    }};
+    ($id:ident, 32, 1, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle32(
+            $a,
+            $b,
+            [
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+                19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+            ],
+        );
+        r
+    }};
+    ($id:ident, 32, 2, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle32(
+            $a,
+            $b,
+            [
+                2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+                20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33
+            ],
+        );
+        r
+    }};
+    ($id:ident, 32, 4, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle32(
+            $a,
+            $b,
+            [
+                4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+                21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35
+            ],
+        );
+        r
+    }};
+    ($id:ident, 32, 8, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle32(
+            $a,
+            $b,
+            [
+                8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+                24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39
+            ],
+        );
+        r
+    }};
+    ($id:ident, 32, 16, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle32(
+            $a,
+            $b,
+            [
+                16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+                32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, 1, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle64(
+            $a,
+            $b,
+            [
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+                19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34,
+                35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+                51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, 2, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle64(
+            $a,
+            $b,
+            [
+                2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+                20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35,
+                36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51,
+                52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, 4, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle64(
+            $a,
+            $b,
+            [
+                4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+                21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36,
+                37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52,
+                53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, 8, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle64(
+            $a,
+            $b,
+            [
+                8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+                24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39,
+                40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55,
+                56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, 16, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle64(
+            $a,
+            $b,
+            [
+                16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+                32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+                48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+                64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, 32, $a:expr, $b:expr) => {{
+        let r: $id = simd_shuffle64(
+            $a,
+            $b,
+            [
+                32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+                48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+                64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79,
+                80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95
+            ],
+        );
+        r
+    }};
+}
+
+/// Returns the lanes `$n..$n + N` of the concatenation of `$a` and `$b`,
+/// for `$n < N`.
+macro_rules! slide_lanes {
+    ($id:ident, 1, $a:expr, $b:expr, $n:expr) => {{
+        let _ = ($b, $n);
+        let a: $id = $a;
+        a
+    }};
+    ($id:ident, 2, $a:expr, $b:expr, $n:expr) => {{
+        let (mut a, b): ($id, $id) = ($a, $b);
+        let n: u32 = $n;
+        if n & 1 != 0 {
+            a = slide_lanes_by!($id, 2, 1, a, b);
+        }
+        a
+    }};
+    ($id:ident, 4, $a:expr, $b:expr, $n:expr) => {{
+        let (mut a, mut b): ($id, $id) = ($a, $b);
+        let n: u32 = $n;
+        if n & 2 != 0 {
+            let a_ = slide_lanes_by!($id, 4, 2, a, b);
+            b = slide_lanes_by!($id, 4, 2, b, b);
+            a = a_;
+        }
+        if n & 1 != 0 {
+            a = slide_lanes_by!($id, 4, 1, a, b);
+        }
+        a
+    }};
+    ($id:ident, 8, $a:expr, $b:expr, $n:expr) => {{
+        let (mut a, mut b): ($id, $id) = ($a, $b);
+        let n: u32 = $n;
+        if n & 4 != 0 {
+            let a_ = slide_lanes_by!($id, 8, 4, a, b);
+            b = slide_lanes_by!($id, 8, 4, b, b);
+            a = a_;
+        }
+        if n & 2 != 0 {
+            let a_ = slide_lanes_by!($id, 8, 2, a, b);
+            b = slide_lanes_by!($id, 8, 2, b, b);
+            a = a_;
+        }
+        if n & 1 != 0 {
+            a = slide_lanes_by!($id, 8, 1, a, b);
+        }
+        a
+    }};
+    ($id:ident, 16, $a:expr, $b:expr, $n:expr) => {{
+        let (mut a, mut b): ($id, $id) = ($a, $b);
+        let n: u32 = $n;
+        if n & 8 != 0 {
+            let a_ = slide_lanes_by!($id, 16, 8, a, b);
+            b = slide_lanes_by!($id, 16, 8, b, b);
+            a = a_;
+        }
+        if n & 4 != 0 {
+            let a_ = slide_lanes_by!($id, 16, 4, a, b);
+            b = slide_lanes_by!($id, 16, 4, b, b);
+            a = a_;
+        }
+        if n & 2 != 0 {
+            let a_ = slide_lanes_by!($id, 16, 2, a, b);
+            b = slide_lanes_by!($id, 16, 2, b, b);
+            a = a_;
+        }
+        if n & 1 != 0 {
+            a = slide_lanes_by!($id, 16, 1, a, b);
+        }
+        a
+    }};
+    ($id:ident, 32, $a:expr, $b:expr, $n:expr) => {{
+        let (mut a, mut b): ($id, $id) = ($a, $b);
+        let n: u32 = $n;
+        if n & 16 != 0 {
+            let a_ = slide_lanes_by!($id, 32, 16, a, b);
+            b = slide_lanes_by!($id, 32, 16, b, b);
+            a = a_;
+        }
+        if n & 8 != 0 {
+            let a_ = slide_lanes_by!($id, 32, 8, a, b);
+            b = slide_lanes_by!($id, 32, 8, b, b);
+            a = a_;
+        }
+        if n & 4 != 0 {
+            let a_ = slide_lanes_by!($id, 32, 4, a, b);
+            b = slide_lanes_by!($id, 32, 4, b, b);
+            a = a_;
+        }
+        if n & 2 != 0 {
+            let a_ = slide_lanes_by!($id, 32, 2, a, b);
+            b = slide_lanes_by!($id, 32, 2, b, b);
+            a = a_;
+        }
+        if n & 1 != 0 {
+            a = slide_lanes_by!($id, 32, 1, a, b);
+        }
+        a
+    }};
+    ($id:ident, 64, $a:expr, $b:expr, $n:expr) => {{
+        let (mut a, mut b): ($id, $id) = ($a, $b);
+        let n: u32 = $n;
+        if n & 32 != 0 {
+            let a_ = slide_lanes_by!($id, 64, 32, a, b);
+            b = slide_lanes_by!($id, 64, 32, b, b);
+            a = a_;
+        }
+        if n & 16 != 0 {
+            let a_ = slide_lanes_by!($id, 64, 16, a, b);
+            b = slide_lanes_by!($id, 64, 16, b, b);
+            a = a_;
+        }
+        if n & 8 != 0 {
+            let a_ = slide_lanes_by!($id, 64, 8, a, b);
+            b = slide_lanes_by!($id, 64, 8, b, b);
+            a = a_;
+        }
+        if n & 4 != 0 {
+            let a_ = slide_lanes_by!($id, 64, 4, a, b);
+            b = slide_lanes_by!($id, 64, 4, b, b);
+            a = a_;
+        }
+        if n & 2 != 0 {
+            let a_ = slide_lanes_by!($id, 64, 2, a, b);
+            b = slide_lanes_by!($id, 64, 2, b, b);
+            a = a_;
+        }
+        if n & 1 != 0 {
+            a = slide_lanes_by!($id, 64, 1, a, b);
+        }
+        a
+    }};
+}
+
+macro_rules! impl_slide {
+    ($id:ident: $elem_count:tt, $elem_ty:ident, $test_mod:ident) => {
+        impl $id {
+            /// Returns the lanes `n..n + lanes()` of the concatenation of
+            /// `self` and `other`, that is, `self` shifted down by `n`
+            /// lanes and filled with the lowest lanes of `other`.
+            ///
+            /// `n` should be a constant, so that this compiles to a single
+            /// shuffle (`palignr` on `x86`, `vext` on ARM).
+            ///
+            /// # Panics
+            ///
+            /// If `n > lanes()`.
+            #[inline]
+            pub fn slide(self, other: Self, n: u32) -> Self {
+                assert!(n as usize <= $elem_count);
+                if n as usize == $elem_count {
+                    return other;
+                }
+                unsafe { slide_lanes!($id, $elem_count, self, other, n) }
+            }
+
+            /// Rotates the lanes down by `n` lanes: the lane `i` of the
+            /// result is the lane `(i + n) % lanes()` of `self`.
+            ///
+            /// `n` should be a constant, see `slide`.
+            #[inline]
+            pub fn rotate_lanes_left(self, n: u32) -> Self {
+                self.slide(self, n % $elem_count)
+            }
+
+            /// Rotates the lanes up by `n` lanes: the lane `(i + n) %
+            /// lanes()` of the result is the lane `i` of `self`.
+            ///
+            /// `n` should be a constant, see `slide`.
+            #[inline]
+            pub fn rotate_lanes_right(self, n: u32) -> Self {
+                let n = n % $elem_count;
+                self.slide(self, ($elem_count - n) % $elem_count)
+            }
+
+            /// Shifts the lanes down by `n` lanes: the lane `i` of the
+            /// result is the lane `i + n` of `self` if `i + n < lanes()`,
+            /// and `fill` otherwise.
+            #[inline]
+            pub fn shift_lanes_left(self, n: u32, fill: $elem_ty) -> Self {
+                let fill = $id::splat(fill);
+                if n as usize >= $elem_count {
+                    return fill;
+                }
+                self.slide(fill, n)
+            }
+
+            /// Shifts the lanes up by `n` lanes: the lane `i` of the
+            /// result is the lane `i - n` of `self` if `i >= n`, and `fill`
+            /// otherwise.
+            #[inline]
+            pub fn shift_lanes_right(self, n: u32, fill: $elem_ty) -> Self {
+                let fill = $id::splat(fill);
+                if n as usize >= $elem_count {
+                    return fill;
+                }
+                fill.slide(self, $elem_count - n)
+            }
+        }
+
+        #[cfg(test)]
+        mod $test_mod {
+            use ::coresimd::simd::$id;
+
+            /// Returns a vector whose lanes alternate between `x` and `y`
+            /// according to the bits of `seed`.
+            fn vector(seed: u64, x: $elem_ty, y: $elem_ty) -> $id {
+                let mut v = $id::splat(x);
+                for i in 0..$id::lanes() {
+                    if (seed >> (i % 64)) & 1 != 0 {
+                        v = v.replace(i, y);
+                    }
+                }
+                v
+            }
+
+            #[test]
+            fn slide() {
+                let n = $id::lanes();
+                let (x, y, z) = test_slide_values!($elem_ty);
+                let a = vector(0x5a5a_3c3c_0ff0_9669, x, y);
+                let b = vector(0x1234_5678_9abc_def0, y, x);
+                for s in 0..n + 1 {
+                    let r = a.slide(b, s as u32);
+                    for i in 0..n {
+                        let e = if i + s < n {
+                            a.extract(i + s)
+                        } else {
+                            b.extract(i + s - n)
+                        };
+                        assert_eq!(r.extract(i), e);
+                    }
+                }
+                for s in 0..2 * n + 1 {
+                    let l = a.rotate_lanes_left(s as u32);
+                    let r = a.rotate_lanes_right(s as u32);
+                    let sl = a.shift_lanes_left(s as u32, z);
+                    let sr = a.shift_lanes_right(s as u32, z);
+                    for i in 0..n {
+                        assert_eq!(l.extract(i), a.extract((i + s) % n));
+                        assert_eq!(r.extract((i + s) % n), a.extract(i));
+                        let e = if i + s < n { a.extract(i + s) } else { z };
+                        assert_eq!(sl.extract(i), e);
+                        let e = if i >= s { a.extract(i - s) } else { z };
+                        assert_eq!(sr.extract(i), e);
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Returns two distinct lane values and a fill value for the `slide` tests.
+#[cfg(test)]
+macro_rules! test_slide_values {
+    (bool) => {
+        (false, true, true)
+    };
+    ($elem_ty:ident) => {
+        (1 as $elem_ty, 2 as $elem_ty, 3 as $elem_ty)
+    };
+}
@@ -0,0 +1,595 @@
+//! Implements sorting of the vector lanes with bitonic sorting networks.
+//!
+//! The networks only use lane permutations (shuffles) and lane-wise
+//! `min`/`max`:
+//!
+//! * `merge_sorted` merges two sorted vectors by comparing the lanes of the
+//!   first vector with the reversed lanes of the second one, which produces
+//!   two bitonic sequences that are then sorted by bitonic merge networks,
+//! * `sort_lanes` sorts both halves of the vector, and merges them with
+//!   `merge_sorted`.
+//!
+//! The position of `NaN` lanes in sorted floating-point vectors is
+//! unspecified.
+
+/// Compare-exchange step of a bitonic network: each lane of `$v` is
+/// compared with the lane given by `$perm`, and the lane-wise minimum or
+/// maximum is selected by `$blend` (from `0..N` and `N..2 * N`
+/// respectively).
+macro_rules! bitonic_step {
+    ($shuffle:ident, $id:ident, $v:ident, $perm:tt, $blend:tt) => {{
+        let p: $id = $shuffle($v, $v, $perm);
+        let r: $id = $shuffle($v.lanewise_min(p), $v.lanewise_max(p), $blend);
+        r
+    }};
+}
+
+/// Bitonic merge network: sorts the bitonic sequence `$v` of `N` lanes in
+/// ascending order.
+macro_rules! bitonic_merge {
+    ($id:ident, 2, $v:expr) => {{
+        let v: $id = $v;
+        let v = bitonic_step!(
+            simd_shuffle2, $id, v,
+            [1, 0],
+            [0, 3]
+        );
+        v
+    }};
+    ($id:ident, 4, $v:expr) => {{
+        let v: $id = $v;
+        let v = bitonic_step!(
+            simd_shuffle4, $id, v,
+            [2, 3, 0, 1],
+            [0, 1, 6, 7]
+        );
+        let v = bitonic_step!(
+            simd_shuffle4, $id, v,
+            [1, 0, 3, 2],
+            [0, 5, 2, 7]
+        );
+        v
+    }};
+    ($id:ident, 8, $v:expr) => {{
+        let v: $id = $v;
+        let v = bitonic_step!(
+            simd_shuffle8, $id, v,
+            [4, 5, 6, 7, 0, 1, 2, 3],
+            [0, 1, 2, 3, 12, 13, 14, 15]
+        );
+        let v = bitonic_step!(
+            simd_shuffle8, $id, v,
+            [2, 3, 0, 1, 6, 7, 4, 5],
+            [0, 1, 10, 11, 4, 5, 14, 15]
+        );
+        let v = bitonic_step!(
+            simd_shuffle8, $id, v,
+            [1, 0, 3, 2, 5, 4, 7, 6],
+            [0, 9, 2, 11, 4, 13, 6, 15]
+        );
+        v
+    }};
+    ($id:ident, 16, $v:expr) => {{
+        let v: $id = $v;
+        let v = bitonic_step!(
+            simd_shuffle16, $id, v,
+            [8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7],
+            [0, 1, 2, 3, 4, 5, 6, 7, 24, 25, 26, 27, 28, 29, 30, 31]
+        );
+        let v = bitonic_step!(
+            simd_shuffle16, $id, v,
+            [4, 5, 6, 7, 0, 1, 2, 3, 12, 13, 14, 15, 8, 9, 10, 11],
+            [0, 1, 2, 3, 20, 21, 22, 23, 8, 9, 10, 11, 28, 29, 30, 31]
+        );
+        let v = bitonic_step!(
+            simd_shuffle16, $id, v,
+            [2, 3, 0, 1, 6, 7, 4, 5, 10, 11, 8, 9, 14, 15, 12, 13],
+            [0, 1, 18, 19, 4, 5, 22, 23, 8, 9, 26, 27, 12, 13, 30, 31]
+        );
+        let v = bitonic_step!(
+            simd_shuffle16, $id, v,
+            [1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14],
+            [0, 17, 2, 19, 4, 21, 6, 23, 8, 25, 10, 27, 12, 29, 14, 31]
+        );
+        v
+    }};
+    ($id:ident, 32, $v:expr) => {{
+        let v: $id = $v;
+        let v = bitonic_step!(
+            simd_shuffle32, $id, v,
+            [
+                16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15
+            ],
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 48, 49,
+                50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63
+            ]
+        );
+        let v = bitonic_step!(
+            simd_shuffle32, $id, v,
+            [
+                8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7, 24, 25,
+                26, 27, 28, 29, 30, 31, 16, 17, 18, 19, 20, 21, 22, 23
+            ],
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 40, 41, 42, 43, 44, 45, 46, 47, 16, 17,
+                18, 19, 20, 21, 22, 23, 56, 57, 58, 59, 60, 61, 62, 63
+            ]
+        );
+        let v = bitonic_step!(
+            simd_shuffle32, $id, v,
+            [
+                4, 5, 6, 7, 0, 1, 2, 3, 12, 13, 14, 15, 8, 9, 10, 11, 20, 21,
+                22, 23, 16, 17, 18, 19, 28, 29, 30, 31, 24, 25, 26, 27
+            ],
+            [
+                0, 1, 2, 3, 36, 37, 38, 39, 8, 9, 10, 11, 44, 45, 46, 47, 16,
+                17, 18, 19, 52, 53, 54, 55, 24, 25, 26, 27, 60, 61, 62, 63
+            ]
+        );
+        let v = bitonic_step!(
+            simd_shuffle32, $id, v,
+            [
+                2, 3, 0, 1, 6, 7, 4, 5, 10, 11, 8, 9, 14, 15, 12, 13, 18, 19,
+                16, 17, 22, 23, 20, 21, 26, 27, 24, 25, 30, 31, 28, 29
+            ],
+            [
+                0, 1, 34, 35, 4, 5, 38, 39, 8, 9, 42, 43, 12, 13, 46, 47, 16,
+                17, 50, 51, 20, 21, 54, 55, 24, 25, 58, 59, 28, 29, 62, 63
+            ]
+        );
+        let v = bitonic_step!(
+            simd_shuffle32, $id, v,
+            [
+                1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14, 17, 16,
+                19, 18, 21, 20, 23, 22, 25, 24, 27, 26, 29, 28, 31, 30
+            ],
+            [
+                0, 33, 2, 35, 4, 37, 6, 39, 8, 41, 10, 43, 12, 45, 14, 47, 16,
+                49, 18, 51, 20, 53, 22, 55, 24, 57, 26, 59, 28, 61, 30, 63
+            ]
+        );
+        v
+    }};
+    ($id:ident, 64, $v:expr) => {{
+        let v: $id = $v;
+        let v = bitonic_step!(
+            simd_shuffle64, $id, v,
+            [
+                32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+                48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31
+            ],
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 96, 97,
+                98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110,
+                111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122,
+                123, 124, 125, 126, 127
+            ]
+        );
+        let v = bitonic_step!(
+            simd_shuffle64, $id, v,
+            [
+                16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 48, 49,
+                50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 32, 33,
+                34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47
+            ],
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 80, 81,
+                82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 32, 33,
+                34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 112,
+                113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124,
+                125, 126, 127
+            ]
+        );
+        let v = bitonic_step!(
+            simd_shuffle64, $id, v,
+            [
+                8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7, 24, 25,
+                26, 27, 28, 29, 30, 31, 16, 17, 18, 19, 20, 21, 22, 23, 40, 41,
+                42, 43, 44, 45, 46, 47, 32, 33, 34, 35, 36, 37, 38, 39, 56, 57,
+                58, 59, 60, 61, 62, 63, 48, 49, 50, 51, 52, 53, 54, 55
+            ],
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 72, 73, 74, 75, 76, 77, 78, 79, 16, 17,
+                18, 19, 20, 21, 22, 23, 88, 89, 90, 91, 92, 93, 94, 95, 32, 33,
+                34, 35, 36, 37, 38, 39, 104, 105, 106, 107, 108, 109, 110, 111,
+                48, 49, 50, 51, 52, 53, 54, 55, 120, 121, 122, 123, 124, 125,
+                126, 127
+            ]
+        );
+        let v = bitonic_step!(
+            simd_shuffle64, $id, v,
+            [
+                4, 5, 6, 7, 0, 1, 2, 3, 12, 13, 14, 15, 8, 9, 10, 11, 20, 21,
+                22, 23, 16, 17, 18, 19, 28, 29, 30, 31, 24, 25, 26, 27, 36, 37,
+                38, 39, 32, 33, 34, 35, 44, 45, 46, 47, 40, 41, 42, 43, 52, 53,
+                54, 55, 48, 49, 50, 51, 60, 61, 62, 63, 56, 57, 58, 59
+            ],
+            [
+                0, 1, 2, 3, 68, 69, 70, 71, 8, 9, 10, 11, 76, 77, 78, 79, 16,
+                17, 18, 19, 84, 85, 86, 87, 24, 25, 26, 27, 92, 93, 94, 95, 32,
+                33, 34, 35, 100, 101, 102, 103, 40, 41, 42, 43, 108, 109, 110,
+                111, 48, 49, 50, 51, 116, 117, 118, 119, 56, 57, 58, 59, 124,
+                125, 126, 127
+            ]
+        );
+        let v = bitonic_step!(
+            simd_shuffle64, $id, v,
+            [
+                2, 3, 0, 1, 6, 7, 4, 5, 10, 11, 8, 9, 14, 15, 12, 13, 18, 19,
+                16, 17, 22, 23, 20, 21, 26, 27, 24, 25, 30, 31, 28, 29, 34, 35,
+                32, 33, 38, 39, 36, 37, 42, 43, 40, 41, 46, 47, 44, 45, 50, 51,
+                48, 49, 54, 55, 52, 53, 58, 59, 56, 57, 62, 63, 60, 61
+            ],
+            [
+                0, 1, 66, 67, 4, 5, 70, 71, 8, 9, 74, 75, 12, 13, 78, 79, 16,
+                17, 82, 83, 20, 21, 86, 87, 24, 25, 90, 91, 28, 29, 94, 95, 32,
+                33, 98, 99, 36, 37, 102, 103, 40, 41, 106, 107, 44, 45, 110,
+                111, 48, 49, 114, 115, 52, 53, 118, 119, 56, 57, 122, 123, 60,
+                61, 126, 127
+            ]
+        );
+        let v = bitonic_step!(
+            simd_shuffle64, $id, v,
+            [
+                1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14, 17, 16,
+                19, 18, 21, 20, 23, 22, 25, 24, 27, 26, 29, 28, 31, 30, 33, 32,
+                35, 34, 37, 36, 39, 38, 41, 40, 43, 42, 45, 44, 47, 46, 49, 48,
+                51, 50, 53, 52, 55, 54, 57, 56, 59, 58, 61, 60, 63, 62
+            ],
+            [
+                0, 65, 2, 67, 4, 69, 6, 71, 8, 73, 10, 75, 12, 77, 14, 79, 16,
+                81, 18, 83, 20, 85, 22, 87, 24, 89, 26, 91, 28, 93, 30, 95, 32,
+                97, 34, 99, 36, 101, 38, 103, 40, 105, 42, 107, 44, 109, 46,
+                111, 48, 113, 50, 115, 52, 117, 54, 119, 56, 121, 58, 123, 60,
+                125, 62, 127
+            ]
+        );
+        v
+    }};
+}
+
+/// Reverses the order of the `N` lanes of `$v`.
+macro_rules! reverse_lanes {
+    ($id:ident, 2, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle2(v, v, [1, 0]);
+        r
+    }};
+    ($id:ident, 4, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle4(v, v, [3, 2, 1, 0]);
+        r
+    }};
+    ($id:ident, 8, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle8(v, v, [7, 6, 5, 4, 3, 2, 1, 0]);
+        r
+    }};
+    ($id:ident, 16, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle16(
+            v, v,
+            [
+                15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0
+            ],
+        );
+        r
+    }};
+    ($id:ident, 32, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle32(
+            v, v,
+            [
+                31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16,
+                15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, $v:expr) => {{
+        let v: $id = $v;
+        let r: $id = simd_shuffle64(
+            v, v,
+            [
+                63, 62, 61, 60, 59, 58, 57, 56, 55, 54, 53, 52, 51, 50, 49, 48,
+                47, 46, 45, 44, 43, 42, 41, 40, 39, 38, 37, 36, 35, 34, 33, 32,
+                31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16,
+                15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0
+            ],
+        );
+        r
+    }};
+}
+
+/// Splits the `N` lanes of `$v` into two vectors of type `$half`.
+macro_rules! split_lanes {
+    ($half:ident, 4, $v:expr) => {{
+        let v = $v;
+        let lo: $half = simd_shuffle2(v, v, [0, 1]);
+        let hi: $half = simd_shuffle2(v, v, [2, 3]);
+        (lo, hi)
+    }};
+    ($half:ident, 8, $v:expr) => {{
+        let v = $v;
+        let lo: $half = simd_shuffle4(v, v, [0, 1, 2, 3]);
+        let hi: $half = simd_shuffle4(v, v, [4, 5, 6, 7]);
+        (lo, hi)
+    }};
+    ($half:ident, 16, $v:expr) => {{
+        let v = $v;
+        let lo: $half = simd_shuffle8(v, v, [0, 1, 2, 3, 4, 5, 6, 7]);
+        let hi: $half = simd_shuffle8(v, v, [8, 9, 10, 11, 12, 13, 14, 15]);
+        (lo, hi)
+    }};
+    ($half:ident, 32, $v:expr) => {{
+        let v = $v;
+        let lo: $half = simd_shuffle16(
+            v, v,
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15
+            ],
+        );
+        let hi: $half = simd_shuffle16(
+            v, v,
+            [
+                16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31
+            ],
+        );
+        (lo, hi)
+    }};
+    ($half:ident, 64, $v:expr) => {{
+        let v = $v;
+        let lo: $half = simd_shuffle32(
+            v, v,
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31
+            ],
+        );
+        let hi: $half = simd_shuffle32(
+            v, v,
+            [
+                32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+                48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63
+            ],
+        );
+        (lo, hi)
+    }};
+}
+
+/// Concatenates the lanes of `$lo` and `$hi` into a vector of `N` lanes.
+macro_rules! concat_lanes {
+    ($id:ident, 4, $lo:expr, $hi:expr) => {{
+        let r: $id = simd_shuffle4($lo, $hi, [0, 1, 2, 3]);
+        r
+    }};
+    ($id:ident, 8, $lo:expr, $hi:expr) => {{
+        let r: $id = simd_shuffle8($lo, $hi, [0, 1, 2, 3, 4, 5, 6, 7]);
+        r
+    }};
+    ($id:ident, 16, $lo:expr, $hi:expr) => {{
+        let r: $id = simd_shuffle16(
+            $lo, $hi,
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15
+            ],
+        );
+        r
+    }};
+    ($id:ident, 32, $lo:expr, $hi:expr) => {{
+        let r: $id = simd_shuffle32(
+            $lo, $hi,
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31
+            ],
+        );
+        r
+    }};
+    ($id:ident, 64, $lo:expr, $hi:expr) => {{
+        let r: $id = simd_shuffle64(
+            $lo, $hi,
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33,
+                34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49,
+                50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63
+            ],
+        );
+        r
+    }};
+}
+
+/// Sorts the vector `$v` of scalars of type `$elem_ty` in ascending order
+/// with the standard library, the reference for the tests.
+#[cfg(test)]
+macro_rules! sort_vec {
+    (f32, $v:expr) => {
+        $v.sort_by(|a, b| a.partial_cmp(b).unwrap())
+    };
+    (f64, $v:expr) => {
+        $v.sort_by(|a, b| a.partial_cmp(b).unwrap())
+    };
+    ($elem_ty:ident, $v:expr) => {
+        $v.sort_unstable()
+    };
+}
+
+/// Returns a lane value of type `$elem_ty` drawn from the bits of the
+/// pseudo-random `u64` `$x`, whose low bits are the least random ones.
+///
+/// Integer lanes take values from their full range, and floating-point
+/// lanes take signed zeros and negative and positive values of small and
+/// large magnitudes.
+#[cfg(test)]
+macro_rules! sort_value {
+    (f32, $x:expr) => {
+        sort_value!(@float f32, $x)
+    };
+    (f64, $x:expr) => {
+        sort_value!(@float f64, $x)
+    };
+    (@float $elem_ty:ident, $x:expr) => {{
+        let x: u64 = $x;
+        let x = x.rotate_left(32);
+        match x % 8 {
+            0 => 0. as $elem_ty,
+            1 => -0. as $elem_ty,
+            2 => (x as i64 as $elem_ty) * 1e10,
+            3 => (x as i32 as $elem_ty) * 1e-30,
+            _ => (x as i32 as $elem_ty) / 65536.,
+        }
+    }};
+    ($elem_ty:ident, $x:expr) => {{
+        let x: u64 = $x;
+        (((x as u128) << 64) | (x.rotate_left(32) as u128)) as $elem_ty
+    }};
+}
+
+macro_rules! impl_sort_lanes {
+    ($id:ident: 1, $elem_ty:ident, $test_mod:ident) => {
+        impl $id {
+            /// Sorts the lanes in ascending order.
+            #[inline]
+            pub fn sort_lanes(self) -> Self {
+                self
+            }
+
+            /// Sorts the lanes in descending order.
+            #[inline]
+            pub fn sort_lanes_desc(self) -> Self {
+                self
+            }
+
+            /// Merges the sorted vectors `self` and `other`: returns a
+            /// vector with their smallest lanes and a vector with their
+            /// largest lanes, both sorted in ascending order.
+            #[inline]
+            pub fn merge_sorted(self, other: Self) -> (Self, Self) {
+                (self.lanewise_min(other), self.lanewise_max(other))
+            }
+        }
+
+        impl_sort_lanes!(@tests $id, $elem_ty, $test_mod);
+    };
+    ($id:ident: 2, $elem_ty:ident, $test_mod:ident) => {
+        impl $id {
+            /// Sorts the lanes in ascending order.
+            #[inline]
+            pub fn sort_lanes(self) -> Self {
+                unsafe { bitonic_merge!($id, 2, self) }
+            }
+        }
+
+        impl_sort_lanes!(@common $id, 2);
+        impl_sort_lanes!(@tests $id, $elem_ty, $test_mod);
+    };
+    ($id:ident: $elem_count:tt, $half_ty:ident, $elem_ty:ident,
+     $test_mod:ident) => {
+        impl $id {
+            /// Sorts the lanes in ascending order.
+            #[inline]
+            pub fn sort_lanes(self) -> Self {
+                unsafe {
+                    let (lo, hi) = split_lanes!($half_ty, $elem_count, self);
+                    let (lo, hi) =
+                        lo.sort_lanes().merge_sorted(hi.sort_lanes());
+                    concat_lanes!($id, $elem_count, lo, hi)
+                }
+            }
+        }
+
+        impl_sort_lanes!(@common $id, $elem_count);
+        impl_sort_lanes!(@tests $id, $elem_ty, $test_mod);
+    };
+    (@common $id:ident, $elem_count:tt) => {
+        impl $id {
+            /// Sorts the lanes in descending order.
+            #[inline]
+            pub fn sort_lanes_desc(self) -> Self {
+                unsafe { reverse_lanes!($id, $elem_count, self.sort_lanes()) }
+            }
+
+            /// Merges the sorted vectors `self` and `other`: returns a
+            /// vector with their smallest lanes and a vector with their
+            /// largest lanes, both sorted in ascending order.
+            ///
+            /// If `self` or `other` is not sorted in ascending order, the
+            /// lanes of the result are in an unspecified order.
+            #[inline]
+            pub fn merge_sorted(self, other: Self) -> (Self, Self) {
+                unsafe {
+                    let other = reverse_lanes!($id, $elem_count, other);
+                    let lo = self.lanewise_min(other);
+                    let hi = self.lanewise_max(other);
+                    (
+                        bitonic_merge!($id, $elem_count, lo),
+                        bitonic_merge!($id, $elem_count, hi),
+                    )
+                }
+            }
+        }
+    };
+    (@tests $id:ident, $elem_ty:ident, $test_mod:ident) => {
+        #[cfg(test)]
+        mod $test_mod {
+            use ::coresimd::simd::$id;
+            use std::prelude::v1::*;
+
+            /// Returns a vector of pseudo-random values, and these values
+            /// sorted in ascending order.
+            fn random(seed: &mut u64) -> ($id, Vec<$elem_ty>) {
+                let mut v = $id::splat(0 as $elem_ty);
+                let mut e = Vec::new();
+                for i in 0..$id::lanes() {
+                    *seed = seed
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    let x = sort_value!($elem_ty, *seed);
+                    v = v.replace(i, x);
+                    e.push(x);
+                }
+                sort_vec!($elem_ty, e);
+                (v, e)
+            }
+
+            #[test]
+            fn sort_lanes() {
+                let mut seed = 42;
+                for _ in 0..64 {
+                    let (v, e) = random(&mut seed);
+                    let s = v.sort_lanes();
+                    let d = v.sort_lanes_desc();
+                    let n = $id::lanes();
+                    for i in 0..n {
+                        assert_eq!(s.extract(i), e[i]);
+                        assert_eq!(d.extract(i), e[n - 1 - i]);
+                    }
+                }
+            }
+
+            #[test]
+            fn merge_sorted() {
+                let mut seed = 7;
+                for _ in 0..64 {
+                    let (a, mut e) = random(&mut seed);
+                    let (b, eb) = random(&mut seed);
+                    e.extend(eb);
+                    sort_vec!($elem_ty, e);
+                    let (lo, hi) = a.sort_lanes().merge_sorted(b.sort_lanes());
+                    let n = $id::lanes();
+                    for i in 0..n {
+                        assert_eq!(lo.extract(i), e[i]);
+                        assert_eq!(hi.extract(i), e[n + i]);
+                    }
+                }
+            }
+        }
+    };
+}
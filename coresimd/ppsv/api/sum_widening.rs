@@ -0,0 +1,100 @@
+//! Implements widening horizontal sums of integer vectors, which cannot
+//! overflow.
+
+macro_rules! impl_sum_widening {
+    ($id:ident: $elem_ty:ident, $acc_ty:ident, $test_mod:ident) => {
+        impl $id {
+            /// Sum of the vector lanes, computed in a scalar type wider than
+            /// the lanes so that it cannot overflow: 32-bit for 8-bit lanes,
+            /// and 64-bit for 16- and 32-bit lanes.
+            #[inline]
+            pub fn sum_widening(self) -> $acc_ty {
+                use super::codegen::sum_widening::ReduceAddWidening;
+                self.reduce_add_widening()
+            }
+        }
+
+        #[cfg(test)]
+        mod $test_mod {
+            use ::coresimd::simd::$id;
+
+            #[test]
+            fn sum_widening() {
+                let max = $elem_ty::max_value();
+                let min = $elem_ty::min_value();
+                let n = $id::lanes() as i128;
+                assert_eq!($id::splat(0).sum_widening(), 0);
+                assert_eq!($id::splat(max).sum_widening() as i128,
+                           n * max as i128);
+                assert_eq!($id::splat(min).sum_widening() as i128,
+                           n * min as i128);
+
+                let mut v = $id::splat(0);
+                let mut e = 0_i128;
+                for i in 0..$id::lanes() {
+                    let x = match i % 3 {
+                        0 => max,
+                        1 => min,
+                        _ => i as $elem_ty,
+                    };
+                    v = v.replace(i, x);
+                    e += x as i128;
+                }
+                assert_eq!(v.sum_widening() as i128, e);
+            }
+        }
+    }
+}
+
+macro_rules! impl_sum_pairs_widening {
+    ($id:ident: $elem_ty:ident, $wide_ty:ident, $test_mod:ident) => {
+        impl $id {
+            /// Pairwise widening sum: the lane `i` of the result is the sum
+            /// of the lanes `2 * i` and `2 * i + 1` of `self`, computed in
+            /// lanes twice as wide so that it cannot overflow.
+            #[inline]
+            pub fn sum_pairs_widening(self) -> $wide_ty {
+                let shift = mem::size_of::<$elem_ty>() * 8;
+                let bits: $wide_ty = ::simd::FromBits::from_bits(self);
+                // The lanes of `self` are the low and high halves of the
+                // lanes of `bits`, and are sign- or zero-extended by these
+                // shifts:
+                ((bits << shift) >> shift) + (bits >> shift)
+            }
+        }
+
+        #[cfg(test)]
+        mod $test_mod {
+            use ::coresimd::simd::{$id, $wide_ty};
+
+            #[test]
+            fn sum_pairs_widening() {
+                let max = $elem_ty::max_value();
+                let min = $elem_ty::min_value();
+                let r = $id::splat(max).sum_pairs_widening();
+                let e = $wide_ty::splat(max as _);
+                assert_eq!(r, e + e);
+                let r = $id::splat(min).sum_pairs_widening();
+                let e = $wide_ty::splat(min as _);
+                assert_eq!(r, e + e);
+
+                let mut v = $id::splat(0);
+                for i in 0..$id::lanes() {
+                    let x = match i % 3 {
+                        0 => max,
+                        1 => min,
+                        _ => i as $elem_ty,
+                    };
+                    v = v.replace(i, x);
+                }
+                let r = v.sum_pairs_widening();
+                for i in 0..$wide_ty::lanes() {
+                    assert_eq!(
+                        r.extract(i) as i128,
+                        v.extract(2 * i) as i128 + v.extract(2 * i + 1) as i128
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! Implements the portable vector traits (`Simd`, `SimdNum`, `SimdInt`,
+//! `SimdFloat`, and `SimdBool`) in terms of the inherent methods of the
+//! vector types.
+
+macro_rules! impl_simd {
+    ($id:ident, $elem_ty:ident, $bool_ty:ident, $elem_count:expr) => {
+        impl ::coresimd::ppsv::traits::sealed::Sealed for $id {}
+
+        impl ::simd::Simd for $id {
+            type Element = $elem_ty;
+            type Mask = $bool_ty;
+            const LANES: usize = $elem_count;
+            #[inline]
+            fn splat(value: $elem_ty) -> Self {
+                $id::splat(value)
+            }
+            #[inline]
+            fn extract(self, index: usize) -> $elem_ty {
+                $id::extract(self, index)
+            }
+            #[inline]
+            unsafe fn extract_unchecked(self, index: usize) -> $elem_ty {
+                $id::extract_unchecked(self, index)
+            }
+            #[inline]
+            fn replace(self, index: usize, new_value: $elem_ty) -> Self {
+                $id::replace(self, index, new_value)
+            }
+            #[inline]
+            unsafe fn replace_unchecked(
+                self, index: usize, new_value: $elem_ty
+            ) -> Self {
+                $id::replace_unchecked(self, index, new_value)
+            }
+            #[inline]
+            fn lanes_eq(self, other: Self) -> $bool_ty {
+                $id::eq(self, other)
+            }
+            #[inline]
+            fn lanes_ne(self, other: Self) -> $bool_ty {
+                $id::ne(self, other)
+            }
+        }
+    }
+}
+
+macro_rules! impl_simd_num {
+    ($id:ident, $elem_ty:ident, $bool_ty:ident) => {
+        impl ::simd::SimdNum for $id {
+            #[inline]
+            fn load_aligned(slice: &[$elem_ty]) -> Self {
+                $id::load_aligned(slice)
+            }
+            #[inline]
+            fn load_unaligned(slice: &[$elem_ty]) -> Self {
+                $id::load_unaligned(slice)
+            }
+            #[inline]
+            unsafe fn load_aligned_unchecked(slice: &[$elem_ty]) -> Self {
+                $id::load_aligned_unchecked(slice)
+            }
+            #[inline]
+            unsafe fn load_unaligned_unchecked(slice: &[$elem_ty]) -> Self {
+                $id::load_unaligned_unchecked(slice)
+            }
+            #[inline]
+            fn store_aligned(self, slice: &mut [$elem_ty]) {
+                $id::store_aligned(self, slice)
+            }
+            #[inline]
+            fn store_unaligned(self, slice: &mut [$elem_ty]) {
+                $id::store_unaligned(self, slice)
+            }
+            #[inline]
+            unsafe fn store_aligned_unchecked(self, slice: &mut [$elem_ty]) {
+                $id::store_aligned_unchecked(self, slice)
+            }
+            #[inline]
+            unsafe fn store_unaligned_unchecked(
+                self, slice: &mut [$elem_ty]
+            ) {
+                $id::store_unaligned_unchecked(self, slice)
+            }
+            #[inline]
+            fn sum(self) -> $elem_ty {
+                $id::sum(self)
+            }
+            #[inline]
+            fn product(self) -> $elem_ty {
+                $id::product(self)
+            }
+            #[inline]
+            fn max_element(self) -> $elem_ty {
+                $id::max(self)
+            }
+            #[inline]
+            fn min_element(self) -> $elem_ty {
+                $id::min(self)
+            }
+            #[inline]
+            fn lanes_lt(self, other: Self) -> $bool_ty {
+                $id::lt(self, other)
+            }
+            #[inline]
+            fn lanes_le(self, other: Self) -> $bool_ty {
+                $id::le(self, other)
+            }
+            #[inline]
+            fn lanes_gt(self, other: Self) -> $bool_ty {
+                $id::gt(self, other)
+            }
+            #[inline]
+            fn lanes_ge(self, other: Self) -> $bool_ty {
+                $id::ge(self, other)
+            }
+        }
+    }
+}
+
+macro_rules! impl_simd_int {
+    ($id:ident, $elem_ty:ident) => {
+        impl ::simd::SimdInt for $id {
+            #[inline]
+            fn and(self) -> $elem_ty {
+                $id::and(self)
+            }
+            #[inline]
+            fn or(self) -> $elem_ty {
+                $id::or(self)
+            }
+            #[inline]
+            fn xor(self) -> $elem_ty {
+                $id::xor(self)
+            }
+        }
+    }
+}
+
+macro_rules! impl_simd_float {
+    ($id:ident, $bool_ty:ident) => {
+        impl ::simd::SimdFloat for $id {
+            #[inline]
+            fn abs(self) -> Self {
+                $id::abs(self)
+            }
+            #[inline]
+            fn sqrt(self) -> Self {
+                $id::sqrt(self)
+            }
+            #[inline]
+            fn recip_approx(self) -> Self {
+                $id::recip_approx(self)
+            }
+            #[inline]
+            fn rsqrt_approx(self) -> Self {
+                $id::rsqrt_approx(self)
+            }
+            #[inline]
+            fn is_nan(self) -> $bool_ty {
+                $id::is_nan(self)
+            }
+            #[inline]
+            fn is_infinite(self) -> $bool_ty {
+                $id::is_infinite(self)
+            }
+            #[inline]
+            fn is_finite(self) -> $bool_ty {
+                $id::is_finite(self)
+            }
+        }
+    }
+}
+
+macro_rules! impl_simd_bool {
+    ($id:ident) => {
+        impl ::simd::SimdBool for $id {
+            #[inline]
+            fn all(self) -> bool {
+                $id::all(self)
+            }
+            #[inline]
+            fn any(self) -> bool {
+                $id::any(self)
+            }
+            #[inline]
+            fn none(self) -> bool {
+                $id::none(self)
+            }
+            #[inline]
+            fn and(self) -> bool {
+                $id::and(self)
+            }
+            #[inline]
+            fn or(self) -> bool {
+                $id::or(self)
+            }
+            #[inline]
+            fn xor(self) -> bool {
+                $id::xor(self)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_simd {
+    ($id:ident, $bool_ty:ident, $true:expr, $false:expr) => {
+        #[test]
+        fn simd_trait() {
+            // `$bool_ty` is `$id` for boolean vectors:
+            use ::coresimd::simd::{$id, Simd};
+            use ::coresimd::simd::$bool_ty as Mask;
+
+            fn generic<V: Simd>(x: V::Element, y: V::Element) -> V {
+                V::splat(x).replace(V::LANES - 1, y)
+            }
+
+            assert_eq!(<$id as Simd>::LANES, $id::lanes());
+            let v: $id = generic($false, $true);
+            for i in 0..$id::lanes() - 1 {
+                assert_eq!(Simd::extract(v, i), $false);
+            }
+            assert_eq!(Simd::extract(v, $id::lanes() - 1), $true);
+
+            let m: Mask = Simd::lanes_eq(v, $id::splat($true));
+            assert_eq!(m, $id::eq(v, $id::splat($true)));
+            let m: Mask = Simd::lanes_ne(v, $id::splat($true));
+            assert_eq!(m, $id::ne(v, $id::splat($true)));
+        }
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_simd_num {
+    ($id:ident, $elem_ty:ident) => {
+        #[test]
+        fn simd_num_trait() {
+            use ::coresimd::simd::{$id, SimdNum};
+
+            fn sum_of_slice<V: SimdNum>(slice: &[V::Element]) -> V::Element {
+                let mut chunks = slice.chunks(V::LANES);
+                let mut acc = V::load_unaligned(chunks.next().unwrap());
+                for chunk in chunks {
+                    acc += V::load_unaligned(chunk);
+                }
+                acc.sum()
+            }
+
+            let data = [1 as $elem_ty; 3 * $id::lanes()];
+            assert_eq!(
+                sum_of_slice::<$id>(&data),
+                (3 * $id::lanes()) as $elem_ty
+            );
+
+            let a = $id::splat(0 as $elem_ty);
+            let b = $id::splat(1 as $elem_ty);
+            assert_eq!(SimdNum::lanes_lt(a, b), $id::lt(a, b));
+            assert_eq!(SimdNum::lanes_ge(a, b), $id::ge(a, b));
+            assert_eq!(SimdNum::max_element(b), $id::max(b));
+            assert_eq!(SimdNum::product(b), $id::product(b));
+        }
+    }
+}
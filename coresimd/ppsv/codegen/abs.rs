@@ -0,0 +1,201 @@
+//! Code generation for the lane-wise wrapping absolute value of signed
+//! integer vectors.
+//!
+//! Uses `pabsb`/`pabsw`/`pabsd` when `ssse3` (`vpabs{b,w,d}` when `avx2`)
+//! is enabled on `x86`/`x86_64`, and a lane-wise select otherwise.
+
+use coresimd::simd::*;
+use coresimd::simd_llvm::*;
+
+/// Lane-wise wrapping absolute value.
+pub trait WrappingAbs {
+    /// Returns the absolute value of each lane, where the absolute value
+    /// of the minimum value is the minimum value itself.
+    fn wrapping_abs(self) -> Self;
+}
+
+macro_rules! wrapping_abs {
+    ($id:ident) => {
+        impl WrappingAbs for $id {
+            #[inline]
+            fn wrapping_abs(self) -> Self {
+                let zero = $id::splat(0);
+                unsafe { simd_select(self.lt(zero), zero - self, self) }
+            }
+        }
+    };
+}
+
+macro_rules! wrapping_abs_route {
+    ($cfg:meta: $id:ident, $f:path) => {
+        #[cfg($cfg)]
+        impl WrappingAbs for $id {
+            #[inline]
+            fn wrapping_abs(self) -> Self {
+                unsafe { $f(self) }
+            }
+        }
+    };
+}
+
+macro_rules! wrapping_abs_fallback {
+    ($cfg:meta: $($id:ident),+) => {
+        $(
+            #[cfg($cfg)]
+            wrapping_abs!($id);
+        )+
+    };
+}
+
+wrapping_abs_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "ssse3"):
+    i8x16, x86::abs_i8x16
+);
+wrapping_abs_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "ssse3"):
+    i16x8, x86::abs_i16x8
+);
+wrapping_abs_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "ssse3"):
+    i32x4, x86::abs_i32x4
+);
+wrapping_abs_fallback!(
+    not(all(any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "ssse3")):
+    i8x16, i16x8, i32x4
+);
+
+wrapping_abs_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"):
+    i8x32, x86::abs_i8x32
+);
+wrapping_abs_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"):
+    i16x16, x86::abs_i16x16
+);
+wrapping_abs_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"):
+    i32x8, x86::abs_i32x8
+);
+wrapping_abs_fallback!(
+    not(all(any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx2")):
+    i8x32, i16x16, i32x8
+);
+
+wrapping_abs!(i8x2);
+wrapping_abs!(i8x4);
+wrapping_abs!(i8x8);
+wrapping_abs!(i16x2);
+wrapping_abs!(i16x4);
+wrapping_abs!(i32x2);
+wrapping_abs!(i64x2);
+wrapping_abs!(i128x1);
+wrapping_abs!(i64x4);
+wrapping_abs!(i128x2);
+wrapping_abs!(i8x64);
+wrapping_abs!(i16x32);
+wrapping_abs!(i32x16);
+wrapping_abs!(i64x8);
+wrapping_abs!(i128x4);
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod x86 {
+    //! Absolute values with `x86`/`x86_64` target intrinsics.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+    use coresimd::x86::*;
+
+    /// Wrapping absolute value with `pabsb`.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    #[cfg_attr(test, assert_instr(pabsb))]
+    pub unsafe fn abs_i8x16(x: i8x16) -> i8x16 {
+        i8x16::from_bits(_mm_abs_epi8(x.into_bits()))
+    }
+
+    /// Wrapping absolute value with `pabsw`.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    #[cfg_attr(test, assert_instr(pabsw))]
+    pub unsafe fn abs_i16x8(x: i16x8) -> i16x8 {
+        i16x8::from_bits(_mm_abs_epi16(x.into_bits()))
+    }
+
+    /// Wrapping absolute value with `pabsd`.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    #[cfg_attr(test, assert_instr(pabsd))]
+    pub unsafe fn abs_i32x4(x: i32x4) -> i32x4 {
+        i32x4::from_bits(_mm_abs_epi32(x.into_bits()))
+    }
+
+    /// Wrapping absolute value with `vpabsb`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    #[cfg_attr(test, assert_instr(vpabsb))]
+    pub unsafe fn abs_i8x32(x: i8x32) -> i8x32 {
+        i8x32::from_bits(_mm256_abs_epi8(x.into_bits()))
+    }
+
+    /// Wrapping absolute value with `vpabsw`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    #[cfg_attr(test, assert_instr(vpabsw))]
+    pub unsafe fn abs_i16x16(x: i16x16) -> i16x16 {
+        i16x16::from_bits(_mm256_abs_epi16(x.into_bits()))
+    }
+
+    /// Wrapping absolute value with `vpabsd`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    #[cfg_attr(test, assert_instr(vpabsd))]
+    pub unsafe fn abs_i32x8(x: i32x8) -> i32x8 {
+        i32x8::from_bits(_mm256_abs_epi32(x.into_bits()))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+
+        #[simd_test = "ssse3"]
+        unsafe fn abs_ssse3() {
+            let x = i8x16::new(
+                -128, -127, -1, 0, 1, 127, -5, 5, 0, 0, 0, 0, 0, 0, 0, 0,
+            );
+            let e = i8x16::new(
+                -128, 127, 1, 0, 1, 127, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0,
+            );
+            assert_eq!(super::abs_i8x16(x), e);
+            let x = i16x8::new(-32768, -32767, -1, 0, 1, 32767, -5, 5);
+            let e = i16x8::new(-32768, 32767, 1, 0, 1, 32767, 5, 5);
+            assert_eq!(super::abs_i16x8(x), e);
+            let x = i32x4::new(i32::min_value(), -1, 0, i32::max_value());
+            let e = i32x4::new(i32::min_value(), 1, 0, i32::max_value());
+            assert_eq!(super::abs_i32x4(x), e);
+        }
+
+        #[simd_test = "avx2"]
+        unsafe fn abs_avx2() {
+            let x = i8x32::splat(-128).replace(1, -3).replace(31, 3);
+            let e = i8x32::splat(-128).replace(1, 3).replace(31, 3);
+            assert_eq!(super::abs_i8x32(x), e);
+            let x = i16x16::splat(-32768).replace(1, -3).replace(15, 3);
+            let e = i16x16::splat(-32768).replace(1, 3).replace(15, 3);
+            assert_eq!(super::abs_i16x16(x), e);
+            let x = i32x8::splat(i32::min_value()).replace(1, -3);
+            let e = i32x8::splat(i32::min_value()).replace(1, 3);
+            assert_eq!(super::abs_i32x8(x), e);
+        }
+    }
+}
@@ -0,0 +1,333 @@
+//! Code generation for the `all` and `any` reductions of boolean vectors.
+//!
+//! The lanes of boolean vectors have either all bits cleared or all bits
+//! set. The reductions use:
+//!
+//! * vectors of at most 64 bits: a comparison of their bits, as an integer,
+//!   with `0` or `!0`,
+//! * `x86`/`x86_64`: `pmovmskb` when `sse2` is enabled, and for 256-bit
+//!   vectors `vpmovmskb` when `avx2` is enabled, or `vptest` when `avx` is
+//!   enabled,
+//! * `aarch64`: `uminv`/`umaxv` when `neon` is enabled,
+//! * the bitwise `and`/`or` reductions otherwise.
+//!
+//! Wider vectors are reduced to the supported ones by the lane-wise
+//! `and`/`or` of their halves.
+
+use coresimd::simd::*;
+#[cfg(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+              target_feature = "sse2"),
+          all(target_arch = "aarch64", target_feature = "neon")))]
+use coresimd::simd_llvm::*;
+#[cfg(not(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = "sse2"),
+              all(target_arch = "aarch64", target_feature = "neon"))))]
+use super::and::ReduceAnd;
+#[cfg(not(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = "sse2"),
+              all(target_arch = "aarch64", target_feature = "neon"))))]
+use super::or::ReduceOr;
+use mem;
+
+/// `all` and `any` reductions of boolean vectors.
+pub trait AllAny {
+    /// Are `all` vector lanes `true`?
+    fn all(self) -> bool;
+    /// Is `any` vector lane `true`?
+    fn any(self) -> bool;
+}
+
+macro_rules! all_any_bits {
+    ($id:ident, $int_ty:ident) => {
+        impl AllAny for $id {
+            #[inline]
+            fn all(self) -> bool {
+                unsafe { mem::transmute::<$id, $int_ty>(self) == !0 }
+            }
+            #[inline]
+            fn any(self) -> bool {
+                unsafe { mem::transmute::<$id, $int_ty>(self) != 0 }
+            }
+        }
+    };
+}
+
+all_any_bits!(b8x1, u8);
+all_any_bits!(b8x2, u16);
+all_any_bits!(b8x4, u32);
+all_any_bits!(b8x8, u64);
+
+macro_rules! all_any_fallback {
+    ($cfg:meta: $($id:ident),+) => {
+        $(
+            #[cfg($cfg)]
+            impl AllAny for $id {
+                #[inline]
+                fn all(self) -> bool {
+                    ReduceAnd::reduce_and(self) != 0
+                }
+                #[inline]
+                fn any(self) -> bool {
+                    ReduceOr::reduce_or(self) != 0
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! all_any_halves {
+    ($cfg:meta: $id:ident, $half:ident, $lanes:tt) => {
+        #[cfg($cfg)]
+        impl AllAny for $id {
+            #[inline]
+            fn all(self) -> bool {
+                let (lo, hi): ($half, $half) =
+                    unsafe { split_lanes!($half, $lanes, self) };
+                AllAny::all(lo & hi)
+            }
+            #[inline]
+            fn any(self) -> bool {
+                let (lo, hi): ($half, $half) =
+                    unsafe { split_lanes!($half, $lanes, self) };
+                AllAny::any(lo | hi)
+            }
+        }
+    };
+}
+
+macro_rules! all_any_route {
+    ($cfg:meta: $id:ident, $all:path, $any:path) => {
+        #[cfg($cfg)]
+        impl AllAny for $id {
+            #[inline]
+            fn all(self) -> bool {
+                unsafe { $all(self) }
+            }
+            #[inline]
+            fn any(self) -> bool {
+                unsafe { $any(self) }
+            }
+        }
+    };
+}
+
+all_any_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"):
+    b8x16, x86::all_b8x16, x86::any_b8x16
+);
+all_any_route!(
+    all(target_arch = "aarch64", target_feature = "neon"):
+    b8x16, aarch64::all_b8x16, aarch64::any_b8x16
+);
+all_any_fallback!(
+    not(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "sse2"),
+            all(target_arch = "aarch64", target_feature = "neon"))):
+    b8x16
+);
+
+all_any_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"):
+    b8x32, x86::all_b8x32, x86::any_b8x32
+);
+all_any_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx", not(target_feature = "avx2")):
+    b8x32, x86::all_b8x32_avx, x86::any_b8x32_avx
+);
+all_any_halves!(
+    any(all(any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2", not(target_feature = "avx")),
+        all(target_arch = "aarch64", target_feature = "neon")):
+    b8x32, b8x16, 32
+);
+all_any_fallback!(
+    not(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "sse2"),
+            all(target_arch = "aarch64", target_feature = "neon"))):
+    b8x32
+);
+
+all_any_halves!(
+    any(all(any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"),
+        all(target_arch = "aarch64", target_feature = "neon")):
+    b8x64, b8x32, 64
+);
+all_any_fallback!(
+    not(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "sse2"),
+            all(target_arch = "aarch64", target_feature = "neon"))):
+    b8x64
+);
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod x86 {
+    //! Boolean reductions with `x86`/`x86_64` target intrinsics.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+    use coresimd::x86::*;
+
+    /// Are all lanes of `x` `true`?
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    #[cfg_attr(test, assert_instr(pmovmskb))]
+    pub unsafe fn all_b8x16(x: b8x16) -> bool {
+        _mm_movemask_epi8(x.into_bits()) == 0xffff
+    }
+
+    /// Is any lane of `x` `true`?
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    #[cfg_attr(test, assert_instr(pmovmskb))]
+    pub unsafe fn any_b8x16(x: b8x16) -> bool {
+        _mm_movemask_epi8(x.into_bits()) != 0
+    }
+
+    /// Are all lanes of `x` `true`?
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    #[cfg_attr(test, assert_instr(vpmovmskb))]
+    pub unsafe fn all_b8x32(x: b8x32) -> bool {
+        _mm256_movemask_epi8(x.into_bits()) == -1
+    }
+
+    /// Is any lane of `x` `true`?
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    #[cfg_attr(test, assert_instr(vpmovmskb))]
+    pub unsafe fn any_b8x32(x: b8x32) -> bool {
+        _mm256_movemask_epi8(x.into_bits()) != 0
+    }
+
+    /// Are all lanes of `x` `true`?
+    #[inline]
+    #[target_feature(enable = "avx")]
+    #[cfg_attr(test, assert_instr(vptest))]
+    pub unsafe fn all_b8x32_avx(x: b8x32) -> bool {
+        _mm256_testc_si256(x.into_bits(), _mm256_set1_epi8(-1)) != 0
+    }
+
+    /// Is any lane of `x` `true`?
+    #[inline]
+    #[target_feature(enable = "avx")]
+    #[cfg_attr(test, assert_instr(vptest))]
+    pub unsafe fn any_b8x32_avx(x: b8x32) -> bool {
+        let x: __m256i = x.into_bits();
+        _mm256_testz_si256(x, x) == 0
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+
+        #[simd_test = "sse2"]
+        unsafe fn all_any_b8x16() {
+            let t = b8x16::splat(true);
+            let f = b8x16::splat(false);
+            assert!(super::all_b8x16(t) && super::any_b8x16(t));
+            assert!(!super::all_b8x16(f) && !super::any_b8x16(f));
+            assert!(!super::all_b8x16(t.replace(15, false)));
+            assert!(super::any_b8x16(f.replace(15, true)));
+        }
+
+        #[simd_test = "avx2"]
+        unsafe fn all_any_b8x32() {
+            let t = b8x32::splat(true);
+            let f = b8x32::splat(false);
+            assert!(super::all_b8x32(t) && super::any_b8x32(t));
+            assert!(!super::all_b8x32(f) && !super::any_b8x32(f));
+            assert!(!super::all_b8x32(t.replace(31, false)));
+            assert!(super::any_b8x32(f.replace(31, true)));
+        }
+
+        #[simd_test = "avx"]
+        unsafe fn all_any_b8x32_avx() {
+            let t = b8x32::splat(true);
+            let f = b8x32::splat(false);
+            assert!(super::all_b8x32_avx(t) && super::any_b8x32_avx(t));
+            assert!(!super::all_b8x32_avx(f) && !super::any_b8x32_avx(f));
+            assert!(!super::all_b8x32_avx(t.replace(31, false)));
+            assert!(super::any_b8x32_avx(f.replace(31, true)));
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub mod aarch64 {
+    //! Boolean reductions with AArch64 NEON intrinsics.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+    use coresimd::aarch64::*;
+
+    /// Are all lanes of `x` `true`?
+    #[inline]
+    #[target_feature(enable = "neon")]
+    #[cfg_attr(test, assert_instr(uminv))]
+    pub unsafe fn all_b8x16(x: b8x16) -> bool {
+        vminvq_u8(x.into_bits()) != 0
+    }
+
+    /// Is any lane of `x` `true`?
+    #[inline]
+    #[target_feature(enable = "neon")]
+    #[cfg_attr(test, assert_instr(umaxv))]
+    pub unsafe fn any_b8x16(x: b8x16) -> bool {
+        vmaxvq_u8(x.into_bits()) != 0
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+
+        #[simd_test = "neon"]
+        unsafe fn all_any_b8x16() {
+            let t = b8x16::splat(true);
+            let f = b8x16::splat(false);
+            assert!(super::all_b8x16(t) && super::any_b8x16(t));
+            assert!(!super::all_b8x16(f) && !super::any_b8x16(f));
+            assert!(!super::all_b8x16(t.replace(15, false)));
+            assert!(super::any_b8x16(f.replace(15, true)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AllAny;
+    use coresimd::simd::*;
+
+    // note: these are also tested in the portable vector API tests
+
+    #[test]
+    fn all_any_b8x64() {
+        let t = b8x64::splat(true);
+        let f = b8x64::splat(false);
+        assert!(AllAny::all(t) && AllAny::any(t));
+        assert!(!AllAny::all(f) && !AllAny::any(f));
+        for i in 0..64 {
+            assert!(!AllAny::all(t.replace(i, false)));
+            assert!(AllAny::any(f.replace(i, true)));
+        }
+    }
+
+    #[test]
+    fn all_any_b8x8() {
+        let t = b8x8::splat(true);
+        let f = b8x8::splat(false);
+        assert!(AllAny::all(t) && AllAny::any(t));
+        assert!(!AllAny::all(f) && !AllAny::any(f));
+        assert!(!AllAny::all(t.replace(7, false)));
+        assert!(AllAny::any(f.replace(0, true)));
+    }
+}
@@ -0,0 +1,162 @@
+//! Code generation for the lane-wise rounding average of integer vectors.
+//!
+//! The average is rounded up, and computed without overflow. It uses:
+//!
+//! * `x86`/`x86_64`: `pavgb`/`pavgw` for unsigned 8- and 16-bit lanes when
+//!   `sse2` (`vpavgb`/`vpavgw` when `avx2`) is enabled,
+//! * `arm`/`aarch64`: `vrhadd` (`urhadd`/`srhadd` on AArch64) for 8- and
+//!   16-bit lanes when `neon` is enabled,
+//! * `(a | b) - ((a ^ b) >> 1)` otherwise.
+
+use coresimd::simd::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "sse2"))]
+use mem;
+
+/// Lane-wise rounding average.
+pub trait RoundingAverage {
+    /// Computes the lane-wise average of `self` and `other`, rounded up.
+    fn rounding_average(self, other: Self) -> Self;
+}
+
+macro_rules! avg {
+    ($id:ident) => {
+        impl RoundingAverage for $id {
+            #[inline]
+            fn rounding_average(self, other: Self) -> Self {
+                // a + b == 2 * (a | b) - (a ^ b), and the shift rounds
+                // down, so that the result is rounded up:
+                (self | other) - ((self ^ other) >> 1_u32)
+            }
+        }
+    };
+}
+
+macro_rules! avg_x86 {
+    ($feature:tt, $id:ident, $intr:ident) => {
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = $feature))]
+        impl RoundingAverage for $id {
+            #[inline]
+            fn rounding_average(self, other: Self) -> Self {
+                use coresimd::x86::$intr;
+                unsafe {
+                    mem::transmute($intr(
+                        mem::transmute(self),
+                        mem::transmute(other),
+                    ))
+                }
+            }
+        }
+    };
+}
+
+avg_x86!("sse2", u8x16, _mm_avg_epu8);
+avg_x86!("sse2", u16x8, _mm_avg_epu16);
+avg_x86!("avx2", u8x32, _mm256_avg_epu8);
+avg_x86!("avx2", u16x16, _mm256_avg_epu16);
+
+macro_rules! avg_neon {
+    ($id:ident, $intr:ident) => {
+        #[cfg(all(any(target_arch = "arm", target_arch = "aarch64"),
+                  target_feature = "neon"))]
+        impl RoundingAverage for $id {
+            #[inline]
+            fn rounding_average(self, other: Self) -> Self {
+                use coresimd::arm::$intr;
+                unsafe { $intr(self, other) }
+            }
+        }
+    };
+}
+
+avg_neon!(i8x8, vrhadd_s8);
+avg_neon!(i8x16, vrhaddq_s8);
+avg_neon!(i16x4, vrhadd_s16);
+avg_neon!(i16x8, vrhaddq_s16);
+avg_neon!(u8x8, vrhadd_u8);
+avg_neon!(u8x16, vrhaddq_u8);
+avg_neon!(u16x4, vrhadd_u16);
+avg_neon!(u16x8, vrhaddq_u16);
+
+macro_rules! avg_fallback {
+    ($cfg:meta: $($id:ident),+) => {
+        $(
+            #[cfg($cfg)]
+            avg!($id);
+        )+
+    };
+}
+
+avg_fallback!(
+    not(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "sse2"),
+            all(any(target_arch = "arm", target_arch = "aarch64"),
+                target_feature = "neon"))):
+    u8x16, u16x8
+);
+avg_fallback!(
+    not(all(any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx2")):
+    u8x32, u16x16
+);
+avg_fallback!(
+    not(all(any(target_arch = "arm", target_arch = "aarch64"),
+            target_feature = "neon")):
+    i8x8, i8x16, i16x4, i16x8, u8x8, u16x4
+);
+
+avg!(i8x2);
+avg!(u8x2);
+avg!(i8x4);
+avg!(u8x4);
+avg!(i16x2);
+avg!(u16x2);
+avg!(i32x2);
+avg!(u32x2);
+avg!(i32x4);
+avg!(u32x4);
+avg!(i64x2);
+avg!(u64x2);
+avg!(i128x1);
+avg!(u128x1);
+avg!(i8x32);
+avg!(i16x16);
+avg!(i32x8);
+avg!(u32x8);
+avg!(i64x4);
+avg!(u64x4);
+avg!(i128x2);
+avg!(u128x2);
+avg!(i8x64);
+avg!(u8x64);
+avg!(i16x32);
+avg!(u16x32);
+avg!(i32x16);
+avg!(u32x16);
+avg!(i64x8);
+avg!(u64x8);
+avg!(i128x4);
+avg!(u128x4);
+
+#[cfg(test)]
+mod tests {
+    use super::RoundingAverage;
+    use coresimd::simd::*;
+
+    // note: these are also tested in the portable vector API tests
+
+    #[test]
+    fn rounding_average_u8x16() {
+        let a = u8x16::splat(255);
+        let b = u8x16::splat(254);
+        assert_eq!(a.rounding_average(b), a);
+    }
+
+    #[test]
+    fn rounding_average_i16x8() {
+        let a = i16x8::splat(-3);
+        let b = i16x8::splat(0);
+        assert_eq!(a.rounding_average(b), i16x8::splat(-1));
+    }
+}
@@ -0,0 +1,260 @@
+//! Code generation for the arithmetic of complex numbers stored as
+//! interleaved `(re, im)` lane pairs of floating-point vectors.
+//!
+//! The complex products are computed with `mul_addsub`, which subtracts in
+//! the even (real) lanes and adds in the odd (imaginary) lanes. It uses:
+//!
+//! * `x86`/`x86_64`: `vfmaddsub` when `fma` is enabled, and otherwise
+//!   `addsubps`/`addsubpd` when `sse3` is enabled, and
+//!   `vaddsubps`/`vaddsubpd` for 256-bit vectors when `avx` is enabled,
+//! * a shuffle of the lane-wise difference and sum otherwise.
+
+use coresimd::simd::*;
+use coresimd::simd_llvm::*;
+
+/// Alternating subtraction and addition of a product.
+pub trait MulAddSub {
+    /// Computes `self * b - c` in the even lanes and `self * b + c` in the
+    /// odd lanes.
+    fn mul_addsub(self, b: Self, c: Self) -> Self;
+}
+
+macro_rules! mul_addsub_fallback {
+    ($($id:ident: $lanes:tt),+) => {
+        mul_addsub_fallback!(all(): $($id: $lanes),+);
+    };
+    ($cfg:meta: $($id:ident: $lanes:tt),+) => {
+        $(
+            #[cfg($cfg)]
+            impl MulAddSub for $id {
+                #[inline]
+                fn mul_addsub(self, b: Self, c: Self) -> Self {
+                    let ab = self * b;
+                    unsafe {
+                        complex_lanes!($id, alternate, $lanes, ab - c, ab + c)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! mul_addsub_route {
+    ($cfg:meta: $id:ident, $f:path) => {
+        #[cfg($cfg)]
+        impl MulAddSub for $id {
+            #[inline]
+            fn mul_addsub(self, b: Self, c: Self) -> Self {
+                unsafe { $f(self, b, c) }
+            }
+        }
+    };
+}
+
+mul_addsub_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "fma"):
+    f32x4, x86::fmaddsub_f32x4
+);
+mul_addsub_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "fma"):
+    f64x2, x86::fmaddsub_f64x2
+);
+mul_addsub_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse3", not(target_feature = "fma")):
+    f32x4, x86::addsub_f32x4
+);
+mul_addsub_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse3", not(target_feature = "fma")):
+    f64x2, x86::addsub_f64x2
+);
+mul_addsub_fallback!(
+    not(all(any(target_arch = "x86", target_arch = "x86_64"),
+            any(target_feature = "sse3", target_feature = "fma"))):
+    f32x4: 4, f64x2: 2
+);
+
+mul_addsub_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "fma"):
+    f32x8, x86::fmaddsub_f32x8
+);
+mul_addsub_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "fma"):
+    f64x4, x86::fmaddsub_f64x4
+);
+mul_addsub_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx", not(target_feature = "fma")):
+    f32x8, x86::addsub_f32x8
+);
+mul_addsub_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx", not(target_feature = "fma")):
+    f64x4, x86::addsub_f64x4
+);
+mul_addsub_fallback!(
+    not(all(any(target_arch = "x86", target_arch = "x86_64"),
+            any(target_feature = "avx", target_feature = "fma"))):
+    f32x8: 8, f64x4: 4
+);
+
+mul_addsub_fallback!(f32x2: 2, f32x16: 16, f64x8: 8);
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod x86 {
+    //! Alternating subtraction and addition with `x86`/`x86_64` target
+    //! intrinsics.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+    use coresimd::x86::*;
+
+    #[allow(improper_ctypes)]
+    extern "C" {
+        #[link_name = "llvm.x86.fma.vfmaddsub.ps"]
+        fn vfmaddsubps(a: __m128, b: __m128, c: __m128) -> __m128;
+        #[link_name = "llvm.x86.fma.vfmaddsub.pd"]
+        fn vfmaddsubpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+        #[link_name = "llvm.x86.fma.vfmaddsub.ps.256"]
+        fn vfmaddsubps256(a: __m256, b: __m256, c: __m256) -> __m256;
+        #[link_name = "llvm.x86.fma.vfmaddsub.pd.256"]
+        fn vfmaddsubpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    }
+
+    /// Computes `a * b - c` in the even lanes and `a * b + c` in the odd
+    /// lanes with `addsubps`.
+    #[inline]
+    #[target_feature(enable = "sse3")]
+    #[cfg_attr(test, assert_instr(addsubps))]
+    pub unsafe fn addsub_f32x4(a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+        f32x4::from_bits(_mm_addsub_ps((a * b).into_bits(), c.into_bits()))
+    }
+
+    /// Computes `a * b - c` in the even lanes and `a * b + c` in the odd
+    /// lanes with `addsubpd`.
+    #[inline]
+    #[target_feature(enable = "sse3")]
+    #[cfg_attr(test, assert_instr(addsubpd))]
+    pub unsafe fn addsub_f64x2(a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+        f64x2::from_bits(_mm_addsub_pd((a * b).into_bits(), c.into_bits()))
+    }
+
+    /// Computes `a * b - c` in the even lanes and `a * b + c` in the odd
+    /// lanes with `vaddsubps`.
+    #[inline]
+    #[target_feature(enable = "avx")]
+    #[cfg_attr(test, assert_instr(vaddsubps))]
+    pub unsafe fn addsub_f32x8(a: f32x8, b: f32x8, c: f32x8) -> f32x8 {
+        f32x8::from_bits(_mm256_addsub_ps((a * b).into_bits(), c.into_bits()))
+    }
+
+    /// Computes `a * b - c` in the even lanes and `a * b + c` in the odd
+    /// lanes with `vaddsubpd`.
+    #[inline]
+    #[target_feature(enable = "avx")]
+    #[cfg_attr(test, assert_instr(vaddsubpd))]
+    pub unsafe fn addsub_f64x4(a: f64x4, b: f64x4, c: f64x4) -> f64x4 {
+        f64x4::from_bits(_mm256_addsub_pd((a * b).into_bits(), c.into_bits()))
+    }
+
+    /// Computes `a * b - c` in the even lanes and `a * b + c` in the odd
+    /// lanes, with a single rounding, with `vfmaddsubps`.
+    #[inline]
+    #[target_feature(enable = "fma")]
+    #[cfg_attr(test, assert_instr(vfmaddsub))]
+    pub unsafe fn fmaddsub_f32x4(a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+        f32x4::from_bits(vfmaddsubps(
+            a.into_bits(),
+            b.into_bits(),
+            c.into_bits(),
+        ))
+    }
+
+    /// Computes `a * b - c` in the even lanes and `a * b + c` in the odd
+    /// lanes, with a single rounding, with `vfmaddsubpd`.
+    #[inline]
+    #[target_feature(enable = "fma")]
+    #[cfg_attr(test, assert_instr(vfmaddsub))]
+    pub unsafe fn fmaddsub_f64x2(a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+        f64x2::from_bits(vfmaddsubpd(
+            a.into_bits(),
+            b.into_bits(),
+            c.into_bits(),
+        ))
+    }
+
+    /// Computes `a * b - c` in the even lanes and `a * b + c` in the odd
+    /// lanes, with a single rounding, with `vfmaddsubps`.
+    #[inline]
+    #[target_feature(enable = "fma")]
+    #[cfg_attr(test, assert_instr(vfmaddsub))]
+    pub unsafe fn fmaddsub_f32x8(a: f32x8, b: f32x8, c: f32x8) -> f32x8 {
+        f32x8::from_bits(vfmaddsubps256(
+            a.into_bits(),
+            b.into_bits(),
+            c.into_bits(),
+        ))
+    }
+
+    /// Computes `a * b - c` in the even lanes and `a * b + c` in the odd
+    /// lanes, with a single rounding, with `vfmaddsubpd`.
+    #[inline]
+    #[target_feature(enable = "fma")]
+    #[cfg_attr(test, assert_instr(vfmaddsub))]
+    pub unsafe fn fmaddsub_f64x4(a: f64x4, b: f64x4, c: f64x4) -> f64x4 {
+        f64x4::from_bits(vfmaddsubpd256(
+            a.into_bits(),
+            b.into_bits(),
+            c.into_bits(),
+        ))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+
+        macro_rules! check {
+            ($f:path, $id:ident) => {
+                let r = $f($id::splat(3.), $id::splat(2.), $id::splat(1.));
+                for i in 0..$id::lanes() {
+                    assert_eq!(r.extract(i), if i % 2 == 0 { 5. } else { 7. });
+                }
+            };
+        }
+
+        #[simd_test = "sse3"]
+        unsafe fn addsub_sse3() {
+            check!(super::addsub_f32x4, f32x4);
+            check!(super::addsub_f64x2, f64x2);
+        }
+
+        #[simd_test = "avx"]
+        unsafe fn addsub_avx() {
+            check!(super::addsub_f32x8, f32x8);
+            check!(super::addsub_f64x4, f64x4);
+        }
+
+        #[simd_test = "fma"]
+        unsafe fn fmaddsub() {
+            check!(super::fmaddsub_f32x4, f32x4);
+            check!(super::fmaddsub_f64x2, f64x2);
+            check!(super::fmaddsub_f32x8, f32x8);
+            check!(super::fmaddsub_f64x4, f64x4);
+
+            // The product is not rounded:
+            let e = 2f32.powi(-20);
+            let a = f32x4::splat(1. + e);
+            let c = f32x4::splat(1. + 2. * e);
+            let r = super::fmaddsub_f32x4(a, a, c);
+            assert_eq!(r.extract(0), e * e);
+        }
+    }
+}
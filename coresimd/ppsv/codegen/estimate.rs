@@ -0,0 +1,306 @@
+//! Code generation for the approximate reciprocal and reciprocal square root
+//! of floating-point vectors.
+//!
+//! The approximations are computed by the estimate instructions of the
+//! target, refined by Newton-Raphson steps:
+//!
+//! * `x86`/`x86_64`: `rcpps`/`rsqrtps` when `sse` (`vrcpps`/`vrsqrtps` for
+//!   256-bit vectors when `avx`) is enabled, for `f32` lanes, and for `f64`
+//!   lanes converted to `f32` when `sse2` (`avx`) is enabled. Their maximum
+//!   relative error is `1.5 * 2^-12`.
+//! * `arm`/`aarch64`: `vrecpe`/`vrsqrte` (`frecpe`/`frsqrte`) when `neon` is
+//!   enabled, for `f32` lanes. Their maximum relative error is `2^-8`.
+//!
+//! Otherwise, the reciprocal and the reciprocal square root are computed
+//! precisely, and the refinement steps are skipped.
+//!
+//! The lanes for which the estimates are not accurate, e.g., subnormal
+//! `f32` lanes or `f64` lanes out of the range of `f32`, are detected after
+//! the refinement and computed precisely.
+
+use coresimd::simd::*;
+#[cfg(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+              target_feature = "sse"),
+          all(any(target_arch = "arm", target_arch = "aarch64"),
+              target_feature = "neon")))]
+use coresimd::simd_llvm::*;
+
+/// Approximate reciprocal and reciprocal square root.
+pub trait Estimate {
+    /// Approximates `1 / self`, refined by `steps` Newton-Raphson steps.
+    fn recip_estimate(self, steps: u32) -> Self;
+    /// Approximates `1 / sqrt(self)`, refined by `steps` Newton-Raphson
+    /// steps.
+    fn rsqrt_estimate(self, steps: u32) -> Self;
+}
+
+/// Refines the approximation `$y` of the reciprocal (square root) of `$x`
+/// with `$steps` Newton-Raphson steps.
+macro_rules! refine {
+    (recip, $id:ident, $x:expr, $y:expr, $steps:expr) => {{
+        let (x, mut y): ($id, $id) = ($x, $y);
+        for _ in 0..$steps {
+            y = y * ($id::splat(2.) - x * y);
+        }
+        refine!(@checked $id, y, x * y, $id::splat(1.) / x)
+    }};
+    (rsqrt, $id:ident, $x:expr, $y:expr, $steps:expr) => {{
+        let (x, mut y): ($id, $id) = ($x, $y);
+        for _ in 0..$steps {
+            y = y * ($id::splat(1.5) - $id::splat(0.5) * x * y * y);
+        }
+        refine!(@checked $id, y, x * y * y, $id::splat(1.) / x.sqrt())
+    }};
+    // The estimates are not accurate for zero, infinite, and subnormal
+    // lanes, nor for `f64` lanes out of the range of the normal `f32`s:
+    // they are either exact special values, whose refinement produces
+    // `NaN`, or zero, infinite, or sign-flipped. Such lanes, where the
+    // product `$e` that should approximate one does not, are computed
+    // precisely.
+    (@checked $id:ident, $y:ident, $e:expr, $precise:expr) => {{
+        let e: $id = $e;
+        let ok = e.gt($id::splat(0.5)) & e.lt($id::splat(2.));
+        if ok.all() {
+            $y
+        } else {
+            unsafe { simd_select(ok, $y, $precise) }
+        }
+    }};
+}
+
+macro_rules! estimate_route {
+    ($cfg:meta: $id:ident, $recip:path, $rsqrt:path) => {
+        #[cfg($cfg)]
+        impl Estimate for $id {
+            #[inline]
+            fn recip_estimate(self, steps: u32) -> Self {
+                let y = unsafe { $recip(self) };
+                refine!(recip, $id, self, y, steps)
+            }
+            #[inline]
+            fn rsqrt_estimate(self, steps: u32) -> Self {
+                let y = unsafe { $rsqrt(self) };
+                refine!(rsqrt, $id, self, y, steps)
+            }
+        }
+    };
+}
+
+macro_rules! estimate_precise {
+    ($cfg:meta: $($id:ident),+) => {
+        $(
+            #[cfg($cfg)]
+            impl Estimate for $id {
+                #[inline]
+                fn recip_estimate(self, _steps: u32) -> Self {
+                    $id::splat(1.) / self
+                }
+                #[inline]
+                fn rsqrt_estimate(self, _steps: u32) -> Self {
+                    $id::splat(1.) / self.sqrt()
+                }
+            }
+        )+
+    };
+}
+
+estimate_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse"):
+    f32x2, x86::recip_f32x2, x86::rsqrt_f32x2
+);
+estimate_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse"):
+    f32x4, x86::recip_f32x4, x86::rsqrt_f32x4
+);
+estimate_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"):
+    f64x2, x86::recip_f64x2, x86::rsqrt_f64x2
+);
+estimate_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx"):
+    f32x8, x86::recip_f32x8, x86::rsqrt_f32x8
+);
+estimate_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx"):
+    f64x4, x86::recip_f64x4, x86::rsqrt_f64x4
+);
+estimate_route!(
+    all(any(target_arch = "arm", target_arch = "aarch64"),
+        target_feature = "neon"):
+    f32x2, ::coresimd::arm::vrecpe_f32, ::coresimd::arm::vrsqrte_f32
+);
+estimate_route!(
+    all(any(target_arch = "arm", target_arch = "aarch64"),
+        target_feature = "neon"):
+    f32x4, ::coresimd::arm::vrecpeq_f32, ::coresimd::arm::vrsqrteq_f32
+);
+
+estimate_precise!(
+    not(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "sse"),
+            all(any(target_arch = "arm", target_arch = "aarch64"),
+                target_feature = "neon"))):
+    f32x2, f32x4
+);
+estimate_precise!(
+    not(all(any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2")):
+    f64x2
+);
+estimate_precise!(
+    not(all(any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx")):
+    f32x8, f64x4
+);
+estimate_precise!(
+    all():
+    f32x16, f64x8
+);
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod x86 {
+    //! Estimates with `x86`/`x86_64` target intrinsics.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+    use coresimd::simd_llvm::*;
+    use coresimd::x86::*;
+
+    /// Estimates `1 / x` with `rcpps`.
+    #[inline]
+    #[target_feature(enable = "sse")]
+    #[cfg_attr(test, assert_instr(rcpps))]
+    pub unsafe fn recip_f32x4(x: f32x4) -> f32x4 {
+        f32x4::from_bits(_mm_rcp_ps(x.into_bits()))
+    }
+
+    /// Estimates `1 / sqrt(x)` with `rsqrtps`.
+    #[inline]
+    #[target_feature(enable = "sse")]
+    #[cfg_attr(test, assert_instr(rsqrtps))]
+    pub unsafe fn rsqrt_f32x4(x: f32x4) -> f32x4 {
+        f32x4::from_bits(_mm_rsqrt_ps(x.into_bits()))
+    }
+
+    /// Estimates `1 / x` with `rcpps`.
+    #[inline]
+    #[target_feature(enable = "sse")]
+    #[cfg_attr(test, assert_instr(rcpps))]
+    pub unsafe fn recip_f32x2(x: f32x2) -> f32x2 {
+        let r = recip_f32x4(simd_shuffle4(x, x, [0, 1, 0, 1]));
+        simd_shuffle2(r, r, [0, 1])
+    }
+
+    /// Estimates `1 / sqrt(x)` with `rsqrtps`.
+    #[inline]
+    #[target_feature(enable = "sse")]
+    #[cfg_attr(test, assert_instr(rsqrtps))]
+    pub unsafe fn rsqrt_f32x2(x: f32x2) -> f32x2 {
+        let r = rsqrt_f32x4(simd_shuffle4(x, x, [0, 1, 0, 1]));
+        simd_shuffle2(r, r, [0, 1])
+    }
+
+    /// Estimates `1 / x` with `rcpps`, in single precision.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    #[cfg_attr(test, assert_instr(rcpps))]
+    pub unsafe fn recip_f64x2(x: f64x2) -> f64x2 {
+        f64x2::from_bits(_mm_cvtps_pd(_mm_rcp_ps(_mm_cvtpd_ps(x.into_bits()))))
+    }
+
+    /// Estimates `1 / sqrt(x)` with `rsqrtps`, in single precision.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    #[cfg_attr(test, assert_instr(rsqrtps))]
+    pub unsafe fn rsqrt_f64x2(x: f64x2) -> f64x2 {
+        let x = _mm_cvtpd_ps(x.into_bits());
+        f64x2::from_bits(_mm_cvtps_pd(_mm_rsqrt_ps(x)))
+    }
+
+    /// Estimates `1 / x` with `vrcpps`.
+    #[inline]
+    #[target_feature(enable = "avx")]
+    #[cfg_attr(test, assert_instr(vrcpps))]
+    pub unsafe fn recip_f32x8(x: f32x8) -> f32x8 {
+        f32x8::from_bits(_mm256_rcp_ps(x.into_bits()))
+    }
+
+    /// Estimates `1 / sqrt(x)` with `vrsqrtps`.
+    #[inline]
+    #[target_feature(enable = "avx")]
+    #[cfg_attr(test, assert_instr(vrsqrtps))]
+    pub unsafe fn rsqrt_f32x8(x: f32x8) -> f32x8 {
+        f32x8::from_bits(_mm256_rsqrt_ps(x.into_bits()))
+    }
+
+    /// Estimates `1 / x` with `vrcpps`, in single precision.
+    #[inline]
+    #[target_feature(enable = "avx")]
+    #[cfg_attr(test, assert_instr(vrcpps))]
+    pub unsafe fn recip_f64x4(x: f64x4) -> f64x4 {
+        let x = _mm256_cvtpd_ps(x.into_bits());
+        f64x4::from_bits(_mm256_cvtps_pd(_mm_rcp_ps(x)))
+    }
+
+    /// Estimates `1 / sqrt(x)` with `vrsqrtps`, in single precision.
+    #[inline]
+    #[target_feature(enable = "avx")]
+    #[cfg_attr(test, assert_instr(vrsqrtps))]
+    pub unsafe fn rsqrt_f64x4(x: f64x4) -> f64x4 {
+        let x = _mm256_cvtpd_ps(x.into_bits());
+        f64x4::from_bits(_mm256_cvtps_pd(_mm_rsqrt_ps(x)))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+
+        #[simd_test = "sse2"]
+        unsafe fn estimate_sse() {
+            let x = f32x4::new(2., 0.25, 1., 16.);
+            let r = super::recip_f32x4(x) * x;
+            let s = super::rsqrt_f32x4(x);
+            let s = s * s * x;
+            for i in 0..4 {
+                assert!((r.extract(i) - 1.).abs() <= 1.5 / 4096.);
+                assert!((s.extract(i) - 1.).abs() <= 3.1 / 4096.);
+            }
+            let x = f64x2::new(4., 0.5);
+            let r = super::recip_f64x2(x) * x;
+            let s = super::rsqrt_f64x2(x);
+            let s = s * s * x;
+            for i in 0..2 {
+                assert!((r.extract(i) - 1.).abs() <= 1.5 / 4096.);
+                assert!((s.extract(i) - 1.).abs() <= 3.1 / 4096.);
+            }
+        }
+
+        #[simd_test = "avx"]
+        unsafe fn estimate_avx() {
+            let x = f32x8::new(2., 0.25, 1., 16., 3., 5., 7., 1e-3);
+            let r = super::recip_f32x8(x) * x;
+            let s = super::rsqrt_f32x8(x);
+            let s = s * s * x;
+            for i in 0..8 {
+                assert!((r.extract(i) - 1.).abs() <= 1.5 / 4096.);
+                assert!((s.extract(i) - 1.).abs() <= 3.1 / 4096.);
+            }
+            let x = f64x4::new(4., 0.5, 3., 1e3);
+            let r = super::recip_f64x4(x) * x;
+            let s = super::rsqrt_f64x4(x);
+            let s = s * s * x;
+            for i in 0..4 {
+                assert!((r.extract(i) - 1.).abs() <= 1.5 / 4096.);
+                assert!((s.extract(i) - 1.).abs() <= 3.1 / 4096.);
+            }
+        }
+    }
+}
@@ -0,0 +1,527 @@
+//! Code generation for conversions between half-precision (`f16`) and
+//! single-precision (`f32`) floating-point vectors.
+//!
+//! Half-precision lanes are stored as their `u16` bit patterns. The
+//! conversions use:
+//!
+//! * `x86`/`x86_64`: `vcvtph2ps`/`vcvtps2ph` when `f16c` is enabled,
+//! * `aarch64`: `fcvtl`/`fcvtn` when `neon` and `fp16` are enabled,
+//! * a bit-exact software implementation otherwise.
+//!
+//! All paths convert `f32 -> f16` rounding to nearest, ties to even, and
+//! quiet signaling NaNs.
+
+use coresimd::simd::*;
+use coresimd::simd_llvm::*;
+use mem;
+
+/// Converts the bit pattern of a half-precision float into a
+/// single-precision float. This conversion is exact.
+#[inline]
+pub fn f16_to_f32(h: u16) -> f32 {
+    let sign = ((h as u32) & 0x8000) << 16;
+    let exp = ((h >> 10) & 0x1f) as u32;
+    let man = (h & 0x3ff) as u32;
+    let bits = if exp == 0x1f {
+        if man == 0 {
+            // infinity
+            sign | 0x7f80_0000
+        } else {
+            // NaN: preserve the payload, quiet it
+            sign | 0x7fc0_0000 | (man << 13)
+        }
+    } else if exp == 0 {
+        if man == 0 {
+            // signed zero
+            sign
+        } else {
+            // subnormal half: normalize it
+            let mut e = 127 - 15 + 1;
+            let mut m = man;
+            while m & 0x400 == 0 {
+                m <<= 1;
+                e -= 1;
+            }
+            sign | (e << 23) | ((m & 0x3ff) << 13)
+        }
+    } else {
+        sign | ((exp + 127 - 15) << 23) | (man << 13)
+    };
+    unsafe { mem::transmute(bits) }
+}
+
+/// Converts a single-precision float into the bit pattern of the nearest
+/// half-precision float (ties to even).
+#[inline]
+pub fn f32_to_f16(x: f32) -> u16 {
+    let x: u32 = unsafe { mem::transmute(x) };
+    let sign = ((x >> 16) & 0x8000) as u16;
+    let exp = ((x >> 23) & 0xff) as i32;
+    let man = x & 0x7f_ffff;
+
+    if exp == 0xff {
+        // infinity or NaN: quiet NaNs and truncate their payload
+        let nan = if man == 0 { 0 } else { 0x200 | (man >> 13) as u16 };
+        return sign | 0x7c00 | nan;
+    }
+
+    let e = exp - 127 + 15;
+    if e >= 0x1f {
+        // overflow: round to infinity
+        return sign | 0x7c00;
+    }
+    if e <= 0 {
+        // the result is a subnormal half or zero
+        if e < -10 {
+            // smaller than half of the smallest subnormal half
+            return sign;
+        }
+        let m = man | 0x80_0000;
+        let shift = (14 - e) as u32;
+        let half = m >> shift;
+        let rem = m & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        let half = if rem > halfway || (rem == halfway && half & 1 == 1) {
+            // the carry might produce the smallest normal half
+            half + 1
+        } else {
+            half
+        };
+        return sign | half as u16;
+    }
+
+    let half = ((e as u32) << 10) | (man >> 13);
+    let rem = man & 0x1fff;
+    let half = if rem > 0x1000 || (rem == 0x1000 && half & 1 == 1) {
+        // the carry might overflow into the exponent, producing infinity
+        half + 1
+    } else {
+        half
+    };
+    sign | half as u16
+}
+
+/// Conversions between vectors of half-precision float bit patterns and
+/// single-precision float vectors.
+pub trait ConvertF16 {
+    /// Single-precision float vector with the same number of lanes.
+    type F32;
+    /// Converts the half-precision lanes into single-precision lanes.
+    fn f16_to_f32(self) -> Self::F32;
+    /// Converts single-precision lanes into half-precision lanes, rounding
+    /// to nearest (ties to even).
+    fn f16_from_f32(x: Self::F32) -> Self;
+}
+
+/// Software conversions, one lane at a time.
+macro_rules! soft_f16 {
+    ($id:ident, $f32_ty:ident) => {
+        #[inline]
+        fn f16_to_f32(self) -> $f32_ty {
+            let mut r = $f32_ty::splat(0.);
+            for i in 0..$id::lanes() {
+                unsafe {
+                    r = r.replace_unchecked(
+                        i,
+                        f16_to_f32(self.extract_unchecked(i)),
+                    );
+                }
+            }
+            r
+        }
+        #[inline]
+        fn f16_from_f32(x: $f32_ty) -> Self {
+            let mut r = $id::splat(0);
+            for i in 0..$id::lanes() {
+                unsafe {
+                    r = r.replace_unchecked(
+                        i,
+                        f32_to_f16(x.extract_unchecked(i)),
+                    );
+                }
+            }
+            r
+        }
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "f16c"))]
+impl ConvertF16 for u16x4 {
+    type F32 = f32x4;
+    #[inline]
+    fn f16_to_f32(self) -> f32x4 {
+        unsafe { x86::f16_to_f32_u16x4(self) }
+    }
+    #[inline]
+    fn f16_from_f32(x: f32x4) -> Self {
+        unsafe { x86::f32_to_f16_f32x4(x) }
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "f16c"))]
+impl ConvertF16 for u16x8 {
+    type F32 = f32x8;
+    #[inline]
+    fn f16_to_f32(self) -> f32x8 {
+        unsafe { x86::f16_to_f32_u16x8(self) }
+    }
+    #[inline]
+    fn f16_from_f32(x: f32x8) -> Self {
+        unsafe { x86::f32_to_f16_f32x8(x) }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon",
+          target_feature = "fp16"))]
+impl ConvertF16 for u16x4 {
+    type F32 = f32x4;
+    #[inline]
+    fn f16_to_f32(self) -> f32x4 {
+        unsafe { aarch64::f16_to_f32_u16x4(self) }
+    }
+    #[inline]
+    fn f16_from_f32(x: f32x4) -> Self {
+        unsafe { aarch64::f32_to_f16_f32x4(x) }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon",
+          target_feature = "fp16"))]
+impl ConvertF16 for u16x8 {
+    type F32 = f32x8;
+    #[inline]
+    fn f16_to_f32(self) -> f32x8 {
+        unsafe {
+            let lo: u16x4 = simd_shuffle4(self, self, [0, 1, 2, 3]);
+            let hi: u16x4 = simd_shuffle4(self, self, [4, 5, 6, 7]);
+            simd_shuffle8(
+                lo.f16_to_f32(),
+                hi.f16_to_f32(),
+                [0, 1, 2, 3, 4, 5, 6, 7],
+            )
+        }
+    }
+    #[inline]
+    fn f16_from_f32(x: f32x8) -> Self {
+        unsafe {
+            let lo: f32x4 = simd_shuffle4(x, x, [0, 1, 2, 3]);
+            let hi: f32x4 = simd_shuffle4(x, x, [4, 5, 6, 7]);
+            simd_shuffle8(
+                u16x4::f16_from_f32(lo),
+                u16x4::f16_from_f32(hi),
+                [0, 1, 2, 3, 4, 5, 6, 7],
+            )
+        }
+    }
+}
+
+#[cfg(not(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = "f16c"),
+              all(target_arch = "aarch64", target_feature = "neon",
+                  target_feature = "fp16"))))]
+impl ConvertF16 for u16x4 {
+    type F32 = f32x4;
+    soft_f16!(u16x4, f32x4);
+}
+
+#[cfg(not(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = "f16c"),
+              all(target_arch = "aarch64", target_feature = "neon",
+                  target_feature = "fp16"))))]
+impl ConvertF16 for u16x8 {
+    type F32 = f32x8;
+    soft_f16!(u16x8, f32x8);
+}
+
+/// 512-bit single-precision vectors are converted one half at a time.
+impl ConvertF16 for u16x16 {
+    type F32 = f32x16;
+    #[inline]
+    fn f16_to_f32(self) -> f32x16 {
+        unsafe {
+            let lo: u16x8 =
+                simd_shuffle8(self, self, [0, 1, 2, 3, 4, 5, 6, 7]);
+            let hi: u16x8 =
+                simd_shuffle8(self, self, [8, 9, 10, 11, 12, 13, 14, 15]);
+            simd_shuffle16(
+                lo.f16_to_f32(),
+                hi.f16_to_f32(),
+                [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+            )
+        }
+    }
+    #[inline]
+    fn f16_from_f32(x: f32x16) -> Self {
+        unsafe {
+            let lo: f32x8 = simd_shuffle8(x, x, [0, 1, 2, 3, 4, 5, 6, 7]);
+            let hi: f32x8 =
+                simd_shuffle8(x, x, [8, 9, 10, 11, 12, 13, 14, 15]);
+            simd_shuffle16(
+                u16x8::f16_from_f32(lo),
+                u16x8::f16_from_f32(hi),
+                [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+            )
+        }
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod x86 {
+    //! Conversions with the `f16c` target intrinsics.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+    use coresimd::simd_llvm::*;
+    use coresimd::x86::*;
+    use mem;
+
+    /// Converts the half-precision lanes of `x` with `vcvtph2ps`.
+    #[inline]
+    #[target_feature(enable = "f16c")]
+    #[cfg_attr(test, assert_instr(vcvtph2ps))]
+    pub unsafe fn f16_to_f32_u16x4(x: u16x4) -> f32x4 {
+        let x: u16x8 =
+            simd_shuffle8(x, u16x4::splat(0), [0, 1, 2, 3, 4, 4, 4, 4]);
+        mem::transmute(_mm_cvtph_ps(mem::transmute(x)))
+    }
+
+    /// Converts the lanes of `x` to half-precision with `vcvtps2ph`.
+    #[inline]
+    #[target_feature(enable = "f16c")]
+    #[cfg_attr(test, assert_instr(vcvtps2ph))]
+    pub unsafe fn f32_to_f16_f32x4(x: f32x4) -> u16x4 {
+        let r: u16x8 = mem::transmute(_mm_cvtps_ph(mem::transmute(x), 0));
+        simd_shuffle4(r, r, [0, 1, 2, 3])
+    }
+
+    /// Converts the half-precision lanes of `x` with `vcvtph2ps`.
+    #[inline]
+    #[target_feature(enable = "f16c")]
+    #[cfg_attr(test, assert_instr(vcvtph2ps))]
+    pub unsafe fn f16_to_f32_u16x8(x: u16x8) -> f32x8 {
+        mem::transmute(_mm256_cvtph_ps(mem::transmute(x)))
+    }
+
+    /// Converts the lanes of `x` to half-precision with `vcvtps2ph`.
+    #[inline]
+    #[target_feature(enable = "f16c")]
+    #[cfg_attr(test, assert_instr(vcvtps2ph))]
+    pub unsafe fn f32_to_f16_f32x8(x: f32x8) -> u16x8 {
+        mem::transmute(_mm256_cvtps_ph(mem::transmute(x), 0))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+        use super::super::{f16_to_f32, f32_to_f16};
+        use super::super::tests::{bits, F16_VALUES, F32_VALUES};
+
+        #[simd_test = "f16c"]
+        unsafe fn f16_to_f32_f16c() {
+            for c in F16_VALUES.chunks(8) {
+                let x = u16x8::new(
+                    c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7],
+                );
+                let lo = u16x4::new(c[0], c[1], c[2], c[3]);
+                let r = super::f16_to_f32_u16x8(x);
+                let r_lo = super::f16_to_f32_u16x4(lo);
+                for i in 0..8 {
+                    let e = bits(f16_to_f32(c[i]));
+                    assert_eq!(bits(r.extract(i)), e);
+                    if i < 4 {
+                        assert_eq!(bits(r_lo.extract(i)), e);
+                    }
+                }
+            }
+        }
+
+        #[simd_test = "f16c"]
+        unsafe fn f32_to_f16_f16c() {
+            for c in F32_VALUES.chunks(8) {
+                let x = f32x8::new(
+                    c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7],
+                );
+                let lo = f32x4::new(c[0], c[1], c[2], c[3]);
+                let r = super::f32_to_f16_f32x8(x);
+                let r_lo = super::f32_to_f16_f32x4(lo);
+                for i in 0..8 {
+                    let e = f32_to_f16(c[i]);
+                    assert_eq!(r.extract(i), e);
+                    if i < 4 {
+                        assert_eq!(r_lo.extract(i), e);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon",
+          target_feature = "fp16"))]
+pub mod aarch64 {
+    //! Conversions with the `fp16` NEON instructions.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+
+    #[allow(improper_ctypes)]
+    extern "C" {
+        #[link_name = "llvm.aarch64.neon.vcvthf2fp"]
+        fn vcvthf2fp(x: u16x4) -> f32x4;
+        #[link_name = "llvm.aarch64.neon.vcvtfp2hf"]
+        fn vcvtfp2hf(x: f32x4) -> u16x4;
+    }
+
+    /// Converts the half-precision lanes of `x` with `fcvtl`.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    #[cfg_attr(test, assert_instr(fcvtl))]
+    pub unsafe fn f16_to_f32_u16x4(x: u16x4) -> f32x4 {
+        vcvthf2fp(x)
+    }
+
+    /// Converts the lanes of `x` to half-precision with `fcvtn`.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    #[cfg_attr(test, assert_instr(fcvtn))]
+    pub unsafe fn f32_to_f16_f32x4(x: f32x4) -> u16x4 {
+        vcvtfp2hf(x)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+        use super::super::{f16_to_f32, f32_to_f16};
+        use super::super::tests::{bits, F16_VALUES, F32_VALUES};
+
+        #[simd_test = "neon"]
+        unsafe fn f16_to_f32_neon() {
+            for c in F16_VALUES.chunks(4) {
+                let r = super::f16_to_f32_u16x4(u16x4::new(
+                    c[0], c[1], c[2], c[3],
+                ));
+                for i in 0..4 {
+                    assert_eq!(bits(r.extract(i)), bits(f16_to_f32(c[i])));
+                }
+            }
+        }
+
+        #[simd_test = "neon"]
+        unsafe fn f32_to_f16_neon() {
+            for c in F32_VALUES.chunks(4) {
+                let r = super::f32_to_f16_f32x4(f32x4::new(
+                    c[0], c[1], c[2], c[3],
+                ));
+                for i in 0..4 {
+                    assert_eq!(r.extract(i), f32_to_f16(c[i]));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{f16_to_f32, f32_to_f16};
+    use std::mem;
+
+    pub fn bits(x: f32) -> u32 {
+        unsafe { mem::transmute(x) }
+    }
+
+    /// Half-precision bit patterns checked against the hardware
+    /// conversions: signed zeros, normals, the largest half, subnormals,
+    /// infinities and a quiet NaN.
+    pub const F16_VALUES: [u16; 16] = [
+        0x0000, 0x8000, 0x3c00, 0xc000, 0x7bff, 0xfbff, 0x0001, 0x83ff,
+        0x0400, 0x8400, 0x3555, 0xb666, 0x7c00, 0xfc00, 0x7e00, 0xfe00,
+    ];
+
+    /// Single-precision values checked against the hardware conversions:
+    /// exact conversions, ties to even, overflows to infinity, subnormal
+    /// results, underflows to zero, infinities and a NaN.
+    pub const F32_VALUES: [f32; 16] = [
+        0.,
+        -0.,
+        1.,
+        -2.,
+        65504.,
+        65520.,
+        1. + 1. / 2048.,
+        1. + 3. / 2048.,
+        -1e10,
+        1. / 16777216.,
+        1.5 / 16777216.,
+        -2.5 / 16777216.,
+        1. / 33554432.,
+        1. / 0.,
+        -1. / 0.,
+        0. / 0.,
+    ];
+
+    #[test]
+    fn f16_to_f32_special() {
+        assert_eq!(bits(f16_to_f32(0x0000)), bits(0.));
+        assert_eq!(bits(f16_to_f32(0x8000)), bits(-0.));
+        assert_eq!(f16_to_f32(0x3c00), 1.);
+        assert_eq!(f16_to_f32(0xc000), -2.);
+        assert_eq!(f16_to_f32(0x7bff), 65504.);
+        // smallest subnormal and smallest normal half
+        assert_eq!(f16_to_f32(0x0001), 1. / 16777216.);
+        assert_eq!(f16_to_f32(0x0400), 1. / 16384.);
+        assert_eq!(f16_to_f32(0x7c00), 1. / 0.);
+        assert_eq!(f16_to_f32(0xfc00), -1. / 0.);
+        assert!(f16_to_f32(0x7e00).is_nan());
+        // signaling NaNs are quieted
+        assert_eq!(bits(f16_to_f32(0x7d00)), 0x7fe0_0000);
+    }
+
+    #[test]
+    fn f32_to_f16_special() {
+        assert_eq!(f32_to_f16(0.), 0x0000);
+        assert_eq!(f32_to_f16(-0.), 0x8000);
+        assert_eq!(f32_to_f16(1.), 0x3c00);
+        assert_eq!(f32_to_f16(-2.), 0xc000);
+        assert_eq!(f32_to_f16(65504.), 0x7bff);
+        assert_eq!(f32_to_f16(1. / 16777216.), 0x0001);
+        assert_eq!(f32_to_f16(1. / 16384.), 0x0400);
+        assert_eq!(f32_to_f16(1. / 0.), 0x7c00);
+        assert_eq!(f32_to_f16(-1. / 0.), 0xfc00);
+        assert_eq!(f32_to_f16(0. / 0.) & 0x7e00, 0x7e00);
+        // overflow rounds to infinity
+        assert_eq!(f32_to_f16(65520.), 0x7c00);
+        assert_eq!(f32_to_f16(1e10), 0x7c00);
+        // underflow rounds to zero
+        assert_eq!(f32_to_f16(1. / 33554432.), 0x0000);
+        assert_eq!(f32_to_f16(-1e-10), 0x8000);
+    }
+
+    #[test]
+    fn f32_to_f16_ties_to_even() {
+        // 1 + 2^-11 is halfway between 1 and 1 + 2^-10: rounds to 1
+        assert_eq!(f32_to_f16(1. + 1. / 2048.), 0x3c00);
+        // 1 + 3 * 2^-11 is halfway between 1 + 2^-10 and 1 + 2^-9
+        assert_eq!(f32_to_f16(1. + 3. / 2048.), 0x3c02);
+        // slightly above the halfway point rounds up
+        assert_eq!(f32_to_f16(1. + 1. / 2048. + 1. / 65536.), 0x3c01);
+        // halfway between the two smallest subnormals rounds to even
+        assert_eq!(f32_to_f16(1.5 / 16777216.), 0x0002);
+        assert_eq!(f32_to_f16(2.5 / 16777216.), 0x0002);
+    }
+
+    #[test]
+    fn f16_round_trip() {
+        for h in 0..0x7c00_u16 {
+            assert_eq!(f32_to_f16(f16_to_f32(h)), h);
+            assert_eq!(f32_to_f16(f16_to_f32(h | 0x8000)), h | 0x8000);
+        }
+    }
+}
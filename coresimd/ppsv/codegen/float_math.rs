@@ -0,0 +1,77 @@
+//! Code generation for the lane-wise absolute value and square root of
+//! floating-point vectors.
+//!
+//! These lower to the `llvm.fabs` and `llvm.sqrt` intrinsics, which LLVM
+//! maps to the target instructions when available (e.g., `andps`/`sqrtps`
+//! on `x86`/`x86_64`, `vabs`/`fsqrt` on ARM and AArch64).
+
+use coresimd::simd::*;
+
+/// Lane-wise absolute value and square root.
+pub trait FloatMath {
+    /// Lane-wise absolute value.
+    fn abs(self) -> Self;
+    /// Lane-wise square root.
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! impl_float_math_llvm {
+    ($id:ident,
+     $fabs:ident: $fabs_name:expr,
+     $sqrt:ident: $sqrt_name:expr) => {
+        #[allow(improper_ctypes)]
+        extern "C" {
+            #[link_name = $fabs_name]
+            fn $fabs(x: $id) -> $id;
+            #[link_name = $sqrt_name]
+            fn $sqrt(x: $id) -> $id;
+        }
+
+        impl FloatMath for $id {
+            #[inline]
+            fn abs(self) -> Self {
+                unsafe { $fabs(self) }
+            }
+            #[inline]
+            fn sqrt(self) -> Self {
+                unsafe { $sqrt(self) }
+            }
+        }
+    };
+}
+
+impl_float_math_llvm!(
+    f32x2,
+    fabs_f32x2: "llvm.fabs.v2f32",
+    sqrt_f32x2: "llvm.sqrt.v2f32"
+);
+impl_float_math_llvm!(
+    f32x4,
+    fabs_f32x4: "llvm.fabs.v4f32",
+    sqrt_f32x4: "llvm.sqrt.v4f32"
+);
+impl_float_math_llvm!(
+    f32x8,
+    fabs_f32x8: "llvm.fabs.v8f32",
+    sqrt_f32x8: "llvm.sqrt.v8f32"
+);
+impl_float_math_llvm!(
+    f32x16,
+    fabs_f32x16: "llvm.fabs.v16f32",
+    sqrt_f32x16: "llvm.sqrt.v16f32"
+);
+impl_float_math_llvm!(
+    f64x2,
+    fabs_f64x2: "llvm.fabs.v2f64",
+    sqrt_f64x2: "llvm.sqrt.v2f64"
+);
+impl_float_math_llvm!(
+    f64x4,
+    fabs_f64x4: "llvm.fabs.v4f64",
+    sqrt_f64x4: "llvm.sqrt.v4f64"
+);
+impl_float_math_llvm!(
+    f64x8,
+    fabs_f64x8: "llvm.fabs.v8f64",
+    sqrt_f64x8: "llvm.sqrt.v8f64"
+);
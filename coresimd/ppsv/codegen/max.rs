@@ -76,6 +76,18 @@ extern "C" {
     fn reduce_max_i8x64(x: i8x64) -> i8;
     #[link_name = "llvm.experimental.vector.reduce.umax.u8.v64u8"]
     fn reduce_max_u8x64(x: u8x64) -> u8;
+    #[link_name = "llvm.experimental.vector.reduce.smax.i128.v1i128"]
+    fn reduce_max_i128x1(x: i128x1) -> i128;
+    #[link_name = "llvm.experimental.vector.reduce.umax.u128.v1u128"]
+    fn reduce_max_u128x1(x: u128x1) -> u128;
+    #[link_name = "llvm.experimental.vector.reduce.smax.i128.v2i128"]
+    fn reduce_max_i128x2(x: i128x2) -> i128;
+    #[link_name = "llvm.experimental.vector.reduce.umax.u128.v2u128"]
+    fn reduce_max_u128x2(x: u128x2) -> u128;
+    #[link_name = "llvm.experimental.vector.reduce.smax.i128.v4i128"]
+    fn reduce_max_i128x4(x: i128x4) -> i128;
+    #[link_name = "llvm.experimental.vector.reduce.umax.u128.v4u128"]
+    fn reduce_max_u128x4(x: u128x4) -> u128;
     #[link_name = "llvm.experimental.vector.reduce.fmax.f32.v2f32"]
     fn reduce_fmax_f32x2(x: f32x2) -> f32;
     #[link_name = "llvm.experimental.vector.reduce.fmax.f64.v2f64"]
@@ -102,74 +114,119 @@ pub trait ReduceMax {
 }
 
 macro_rules! red_max {
-    ($id:ident, $elem_ty:ident, $llvm_intr:ident) => {
+    ($id:ident: $lanes:tt, $elem_ty:ident, $llvm_intr:ident
+     $(, $route:ident: $route_fn:path)*) => {
         impl ReduceMax for $id {
             type Acc = $elem_ty;
-            #[cfg(not(target_arch = "aarch64"))]
-            #[inline]
-            fn reduce_max(self) -> Self::Acc {
-                unsafe { $llvm_intr(self) }
-            }
-            // FIXME: broken on AArch64
-            #[cfg(target_arch = "aarch64")]
-            #[allow(unused_imports)]
-            #[inline]
-            fn reduce_max(self) -> Self::Acc {
-                use num::Float;
-                use cmp::Ord;
-                let mut x = self.extract(0);
-                for i in 1..$id::lanes() {
-                    x = x.max(self.extract(i));
+            red_fn!(
+                reduce_max -> $elem_ty,
+                llvm: |x: $id| unsafe { $llvm_intr(x) },
+                tree: |x: $id| {
+                    // Ignores the `NaN` lanes, like the LLVM reduction:
+                    let max = |a: $id, b: $id| unsafe {
+                        ::coresimd::simd_llvm::simd_select(
+                            a.gt(b) | b.ne(b),
+                            a,
+                            b,
+                        )
+                    };
+                    tree_reduce!($id: $lanes, x, max).extract(0)
                 }
-                x
-            }
+                $(, $route: $route_fn)*
+            );
         }
     };
 }
-red_max!(i8x2, i8, reduce_max_i8x2);
-red_max!(u8x2, u8, reduce_max_u8x2);
-red_max!(i16x2, i16, reduce_max_i16x2);
-red_max!(u16x2, u16, reduce_max_u16x2);
-red_max!(i32x2, i32, reduce_max_i32x2);
-red_max!(u32x2, u32, reduce_max_u32x2);
-red_max!(i64x2, i64, reduce_max_i64x2);
-red_max!(u64x2, u64, reduce_max_u64x2);
-red_max!(i8x4, i8, reduce_max_i8x4);
-red_max!(u8x4, u8, reduce_max_u8x4);
-red_max!(i16x4, i16, reduce_max_i16x4);
-red_max!(u16x4, u16, reduce_max_u16x4);
-red_max!(i32x4, i32, reduce_max_i32x4);
-red_max!(u32x4, u32, reduce_max_u32x4);
-red_max!(i64x4, i64, reduce_max_i64x4);
-red_max!(u64x4, u64, reduce_max_u64x4);
-red_max!(i8x8, i8, reduce_max_i8x8);
-red_max!(u8x8, u8, reduce_max_u8x8);
-red_max!(i16x8, i16, reduce_max_i16x8);
-red_max!(u16x8, u16, reduce_max_u16x8);
-red_max!(i32x8, i32, reduce_max_i32x8);
-red_max!(u32x8, u32, reduce_max_u32x8);
-red_max!(i64x8, i64, reduce_max_i64x8);
-red_max!(u64x8, u64, reduce_max_u64x8);
-red_max!(i8x16, i8, reduce_max_i8x16);
-red_max!(u8x16, u8, reduce_max_u8x16);
-red_max!(i16x16, i16, reduce_max_i16x16);
-red_max!(u16x16, u16, reduce_max_u16x16);
-red_max!(i32x16, i32, reduce_max_i32x16);
-red_max!(u32x16, u32, reduce_max_u32x16);
-red_max!(i8x32, i8, reduce_max_i8x32);
-red_max!(u8x32, u8, reduce_max_u8x32);
-red_max!(i16x32, i16, reduce_max_i16x32);
-red_max!(u16x32, u16, reduce_max_u16x32);
-red_max!(i8x64, i8, reduce_max_i8x64);
-red_max!(u8x64, u8, reduce_max_u8x64);
+red_max!(i8x2: 2, i8, reduce_max_i8x2);
+red_max!(u8x2: 2, u8, reduce_max_u8x2);
+red_max!(i16x2: 2, i16, reduce_max_i16x2);
+red_max!(u16x2: 2, u16, reduce_max_u16x2);
+red_max!(
+    i32x2: 2,
+    i32,
+    reduce_max_i32x2,
+    neon: super::tree::neon::reduce_max_i32x2
+);
+red_max!(
+    u32x2: 2,
+    u32,
+    reduce_max_u32x2,
+    neon: super::tree::neon::reduce_max_u32x2
+);
+red_max!(i64x2: 2, i64, reduce_max_i64x2);
+red_max!(u64x2: 2, u64, reduce_max_u64x2);
+red_max!(i8x4: 4, i8, reduce_max_i8x4);
+red_max!(u8x4: 4, u8, reduce_max_u8x4);
+red_max!(
+    i16x4: 4,
+    i16,
+    reduce_max_i16x4,
+    neon: super::tree::neon::reduce_max_i16x4
+);
+red_max!(
+    u16x4: 4,
+    u16,
+    reduce_max_u16x4,
+    neon: super::tree::neon::reduce_max_u16x4
+);
+red_max!(i32x4: 4, i32, reduce_max_i32x4);
+red_max!(u32x4: 4, u32, reduce_max_u32x4);
+red_max!(i64x4: 4, i64, reduce_max_i64x4);
+red_max!(u64x4: 4, u64, reduce_max_u64x4);
+red_max!(
+    i8x8: 8,
+    i8,
+    reduce_max_i8x8,
+    neon: super::tree::neon::reduce_max_i8x8
+);
+red_max!(
+    u8x8: 8,
+    u8,
+    reduce_max_u8x8,
+    neon: super::tree::neon::reduce_max_u8x8
+);
+red_max!(
+    i16x8: 8,
+    i16,
+    reduce_max_i16x8,
+    sse41: super::tree::x86::reduce_max_i16x8
+);
+red_max!(
+    u16x8: 8,
+    u16,
+    reduce_max_u16x8,
+    sse41: super::tree::x86::reduce_max_u16x8
+);
+red_max!(i32x8: 8, i32, reduce_max_i32x8);
+red_max!(u32x8: 8, u32, reduce_max_u32x8);
+red_max!(i64x8: 8, i64, reduce_max_i64x8);
+red_max!(u64x8: 8, u64, reduce_max_u64x8);
+red_max!(i8x16: 16, i8, reduce_max_i8x16);
+red_max!(u8x16: 16, u8, reduce_max_u8x16);
+red_max!(i16x16: 16, i16, reduce_max_i16x16);
+red_max!(u16x16: 16, u16, reduce_max_u16x16);
+red_max!(i32x16: 16, i32, reduce_max_i32x16);
+red_max!(u32x16: 16, u32, reduce_max_u32x16);
+red_max!(i8x32: 32, i8, reduce_max_i8x32);
+red_max!(u8x32: 32, u8, reduce_max_u8x32);
+red_max!(i16x32: 32, i16, reduce_max_i16x32);
+red_max!(u16x32: 32, u16, reduce_max_u16x32);
+red_max!(i8x64: 64, i8, reduce_max_i8x64);
+red_max!(u8x64: 64, u8, reduce_max_u8x64);
+red_max!(i128x1: 1, i128, reduce_max_i128x1);
+red_max!(u128x1: 1, u128, reduce_max_u128x1);
+red_max!(i128x2: 2, i128, reduce_max_i128x2);
+red_max!(u128x2: 2, u128, reduce_max_u128x2);
+red_max!(i128x4: 4, i128, reduce_max_i128x4);
+red_max!(u128x4: 4, u128, reduce_max_u128x4);
 
-red_max!(f32x2, f32, reduce_fmax_f32x2);
-red_max!(f64x2, f64, reduce_fmax_f64x2);
-red_max!(f32x4, f32, reduce_fmax_f32x4);
-red_max!(f64x4, f64, reduce_fmax_f64x4);
-red_max!(f32x8, f32, reduce_fmax_f32x8);
-red_max!(f64x8, f64, reduce_fmax_f64x8);
-red_max!(f32x16, f32, reduce_fmax_f32x16);
+red_max!(f32x2: 2, f32, reduce_fmax_f32x2);
+red_max!(f64x2: 2, f64, reduce_fmax_f64x2);
+red_max!(f32x4: 4, f32, reduce_fmax_f32x4);
+red_max!(f64x4: 4, f64, reduce_fmax_f64x4);
+red_max!(f32x8: 8, f32, reduce_fmax_f32x8);
+red_max!(f64x8: 8, f64, reduce_fmax_f64x8);
+red_max!(f32x16: 16, f32, reduce_fmax_f32x16);
 
 #[cfg(test)]
 mod tests {
@@ -1,9 +1,22 @@
 //! Code Generation
 
+#[macro_use]
+pub mod tree;
 pub mod sum;
+pub mod sum_widening;
 pub mod product;
 pub mod and;
 pub mod or;
 pub mod xor;
 pub mod min;
 pub mod max;
+pub mod all_any;
+pub mod f16;
+pub mod avg;
+pub mod abs;
+pub mod signum;
+pub mod estimate;
+pub mod float_math;
+pub mod complex;
+pub mod mul_high;
+pub mod slide;
@@ -0,0 +1,210 @@
+//! Code generation for the high half of the widening multiplication of
+//! unsigned integer vectors.
+//!
+//! `x86`/`x86_64` has no vector instructions for the high half of the
+//! product of 32-bit lanes, but widening multiplications of the even lanes.
+//! The high halves use:
+//!
+//! * `x86`/`x86_64`: `pmulhuw` for `u16x8` and `pmuludq` for `u32x4` when
+//!   `sse2` is enabled, and `vpmulhuw` for `u16x16` and `vpmuludq` for
+//!   `u32x8` when `avx2` is enabled,
+//! * the multiplication of the lanes widened to twice their width
+//!   otherwise.
+
+use convert::From;
+use coresimd::simd::*;
+
+/// High half of the widening multiplication.
+pub trait MulHigh {
+    /// Computes the high half of the lane-wise product of `self` and
+    /// `other`, that is, `(self * other) >> bits`, where `bits` is the
+    /// width of the lanes, as if computed in lanes twice as wide.
+    fn mul_high(self, other: Self) -> Self;
+}
+
+macro_rules! mul_high_widening {
+    ($($id:ident: $wide_ty:ident, $bits:expr),+) => {
+        mul_high_widening!(all(): $($id: $wide_ty, $bits),+);
+    };
+    ($cfg:meta: $($id:ident: $wide_ty:ident, $bits:expr),+) => {
+        $(
+            #[cfg($cfg)]
+            impl MulHigh for $id {
+                #[inline]
+                fn mul_high(self, other: Self) -> Self {
+                    let p = $wide_ty::from(self) * $wide_ty::from(other);
+                    $id::from(p >> $bits)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! mul_high_route {
+    ($cfg:meta: $id:ident, $f:path) => {
+        #[cfg($cfg)]
+        impl MulHigh for $id {
+            #[inline]
+            fn mul_high(self, other: Self) -> Self {
+                unsafe { $f(self, other) }
+            }
+        }
+    };
+}
+
+mul_high_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"):
+    u16x8, x86::mul_high_u16x8
+);
+mul_high_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"):
+    u32x4, x86::mul_high_u32x4
+);
+mul_high_widening!(
+    not(all(any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2")):
+    u16x8: u32x8, 16, u32x4: u64x4, 32
+);
+
+mul_high_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"):
+    u16x16, x86::mul_high_u16x16
+);
+mul_high_route!(
+    all(any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"):
+    u32x8, x86::mul_high_u32x8
+);
+mul_high_widening!(
+    not(all(any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx2")):
+    u16x16: u32x16, 16, u32x8: u64x8, 32
+);
+
+mul_high_widening!(u16x4: u32x4, 16, u32x2: u64x2, 32);
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod x86 {
+    //! High halves of widening multiplications with `x86`/`x86_64` target
+    //! intrinsics.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+    use coresimd::simd_llvm::*;
+    use coresimd::x86::*;
+
+    /// High half of the lane-wise product of `a` and `b` with `pmulhuw`.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    #[cfg_attr(test, assert_instr(pmulhuw))]
+    pub unsafe fn mul_high_u16x8(a: u16x8, b: u16x8) -> u16x8 {
+        u16x8::from_bits(_mm_mulhi_epu16(a.into_bits(), b.into_bits()))
+    }
+
+    /// High half of the lane-wise product of `a` and `b` with `vpmulhuw`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    #[cfg_attr(test, assert_instr(vpmulhuw))]
+    pub unsafe fn mul_high_u16x16(a: u16x16, b: u16x16) -> u16x16 {
+        u16x16::from_bits(_mm256_mulhi_epu16(a.into_bits(), b.into_bits()))
+    }
+
+    /// High half of the lane-wise product of `a` and `b` with `pmuludq`.
+    ///
+    /// `pmuludq` multiplies the even lanes into 64-bit products, so the odd
+    /// lanes are moved to the even ones and multiplied separately.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    #[cfg_attr(test, assert_instr(pmuludq))]
+    pub unsafe fn mul_high_u32x4(a: u32x4, b: u32x4) -> u32x4 {
+        let a_odd: u32x4 = simd_shuffle4(a, a, [1, 1, 3, 3]);
+        let b_odd: u32x4 = simd_shuffle4(b, b, [1, 1, 3, 3]);
+        let even = u32x4::from_bits(_mm_mul_epu32(
+            a.into_bits(),
+            b.into_bits(),
+        ));
+        let odd = u32x4::from_bits(_mm_mul_epu32(
+            a_odd.into_bits(),
+            b_odd.into_bits(),
+        ));
+        // The high halves are the odd lanes of the products:
+        simd_shuffle4(even, odd, [1, 5, 3, 7])
+    }
+
+    /// High half of the lane-wise product of `a` and `b` with `vpmuludq`.
+    ///
+    /// `vpmuludq` multiplies the even lanes into 64-bit products, so the
+    /// odd lanes are moved to the even ones and multiplied separately.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    #[cfg_attr(test, assert_instr(vpmuludq))]
+    pub unsafe fn mul_high_u32x8(a: u32x8, b: u32x8) -> u32x8 {
+        let a_odd: u32x8 = simd_shuffle8(a, a, [1, 1, 3, 3, 5, 5, 7, 7]);
+        let b_odd: u32x8 = simd_shuffle8(b, b, [1, 1, 3, 3, 5, 5, 7, 7]);
+        let even = u32x8::from_bits(_mm256_mul_epu32(
+            a.into_bits(),
+            b.into_bits(),
+        ));
+        let odd = u32x8::from_bits(_mm256_mul_epu32(
+            a_odd.into_bits(),
+            b_odd.into_bits(),
+        ));
+        // The high halves are the odd lanes of the products:
+        simd_shuffle8(even, odd, [1, 9, 3, 11, 5, 13, 7, 15])
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+
+        #[simd_test = "sse2"]
+        unsafe fn mul_high_sse2() {
+            let a = u16x8::new(0xffff, 0x8000, 3, 0x1234, 0, 1, 2, 0xfffe);
+            let b = u16x8::new(0xffff, 2, 5, 0x4321, 7, 0xffff, 0x8000, 3);
+            let r = super::mul_high_u16x8(a, b);
+            for i in 0..8 {
+                let e = (a.extract(i) as u32 * b.extract(i) as u32) >> 16;
+                assert_eq!(r.extract(i), e as u16);
+            }
+            let a = u32x4::new(0xffff_ffff, 0x8000_0000, 3, 0x1234_5678);
+            let b = u32x4::new(0xffff_ffff, 2, 5, 0x8765_4321);
+            let r = super::mul_high_u32x4(a, b);
+            for i in 0..4 {
+                let e = (a.extract(i) as u64 * b.extract(i) as u64) >> 32;
+                assert_eq!(r.extract(i), e as u32);
+            }
+        }
+
+        #[simd_test = "avx2"]
+        unsafe fn mul_high_avx2() {
+            let mut a = u16x16::splat(0);
+            let mut b = u16x16::splat(0);
+            for i in 0..16 {
+                a = a.replace(i, 0xffff - (i as u16) * 0x1111);
+                b = b.replace(i, (i as u16 + 1) * 0x0f0f);
+            }
+            let r = super::mul_high_u16x16(a, b);
+            for i in 0..16 {
+                let e = (a.extract(i) as u32 * b.extract(i) as u32) >> 16;
+                assert_eq!(r.extract(i), e as u16);
+            }
+            let mut a = u32x8::splat(0);
+            let mut b = u32x8::splat(0);
+            for i in 0..8 {
+                a = a.replace(i, 0xffff_ffff - (i as u32) * 0x1111_1111);
+                b = b.replace(i, (i as u32 + 1) * 0x0f0f_0f0f);
+            }
+            let r = super::mul_high_u32x8(a, b);
+            for i in 0..8 {
+                let e = (a.extract(i) as u64 * b.extract(i) as u64) >> 32;
+                assert_eq!(r.extract(i), e as u32);
+            }
+        }
+    }
+}
@@ -76,6 +76,18 @@ extern "C" {
     fn reduce_mul_i8x64(x: i8x64) -> i8;
     #[link_name = "llvm.experimental.vector.reduce.mul.u8.v64u8"]
     fn reduce_mul_u8x64(x: u8x64) -> u8;
+    #[link_name = "llvm.experimental.vector.reduce.mul.i128.v1i128"]
+    fn reduce_mul_i128x1(x: i128x1) -> i128;
+    #[link_name = "llvm.experimental.vector.reduce.mul.u128.v1u128"]
+    fn reduce_mul_u128x1(x: u128x1) -> u128;
+    #[link_name = "llvm.experimental.vector.reduce.mul.i128.v2i128"]
+    fn reduce_mul_i128x2(x: i128x2) -> i128;
+    #[link_name = "llvm.experimental.vector.reduce.mul.u128.v2u128"]
+    fn reduce_mul_u128x2(x: u128x2) -> u128;
+    #[link_name = "llvm.experimental.vector.reduce.mul.i128.v4i128"]
+    fn reduce_mul_i128x4(x: i128x4) -> i128;
+    #[link_name = "llvm.experimental.vector.reduce.mul.u128.v4u128"]
+    fn reduce_mul_u128x4(x: u128x4) -> u128;
     #[link_name = "llvm.experimental.vector.reduce.fmul.f32.v2f32"]
     fn reduce_fmul_f32x2(acc: f32, x: f32x2) -> f32;
     #[link_name = "llvm.experimental.vector.reduce.fmul.f64.v2f64"]
@@ -101,63 +113,64 @@ pub trait ReduceMul {
 }
 
 macro_rules! red_mul {
-    ($id:ident, $elem_ty:ident, $llvm_intr:ident) => {
+    ($id:ident: $lanes:tt, $elem_ty:ident, $llvm_intr:ident
+     $(, $route:ident: $route_fn:path)*) => {
         impl ReduceMul for $id {
             type Acc = $elem_ty;
-            #[cfg(not(target_arch = "aarch64"))]
-            #[inline]
-            fn reduce_mul(self) -> Self::Acc {
-                unsafe { $llvm_intr(self) }
-            }
-            // FIXME: broken in AArch64
-            #[cfg(target_arch = "aarch64")]
-            #[inline]
-            fn reduce_mul(self) -> Self::Acc {
-                let mut x = self.extract(0);
-                for i in 1..$id::lanes() {
-                    x *= self.extract(i);
+            red_fn!(
+                reduce_mul -> $elem_ty,
+                llvm: |x: $id| unsafe { $llvm_intr(x) },
+                tree: |x: $id| {
+                    let op = |a: $id, b: $id| a * b;
+                    tree_reduce!($id: $lanes, x, op).extract(0)
                 }
-                x
-            }
+                $(, $route: $route_fn)*
+            );
         }
     };
 }
-red_mul!(i8x2, i8, reduce_mul_i8x2);
-red_mul!(u8x2, u8, reduce_mul_u8x2);
-red_mul!(i16x2, i16, reduce_mul_i16x2);
-red_mul!(u16x2, u16, reduce_mul_u16x2);
-red_mul!(i32x2, i32, reduce_mul_i32x2);
-red_mul!(u32x2, u32, reduce_mul_u32x2);
-red_mul!(i64x2, i64, reduce_mul_i64x2);
-red_mul!(u64x2, u64, reduce_mul_u64x2);
-red_mul!(i8x4, i8, reduce_mul_i8x4);
-red_mul!(u8x4, u8, reduce_mul_u8x4);
-red_mul!(i16x4, i16, reduce_mul_i16x4);
-red_mul!(u16x4, u16, reduce_mul_u16x4);
-red_mul!(i32x4, i32, reduce_mul_i32x4);
-red_mul!(u32x4, u32, reduce_mul_u32x4);
-red_mul!(i64x4, i64, reduce_mul_i64x4);
-red_mul!(u64x4, u64, reduce_mul_u64x4);
-red_mul!(i8x8, i8, reduce_mul_i8x8);
-red_mul!(u8x8, u8, reduce_mul_u8x8);
-red_mul!(i16x8, i16, reduce_mul_i16x8);
-red_mul!(u16x8, u16, reduce_mul_u16x8);
-red_mul!(i32x8, i32, reduce_mul_i32x8);
-red_mul!(u32x8, u32, reduce_mul_u32x8);
-red_mul!(i64x8, i64, reduce_mul_i64x8);
-red_mul!(u64x8, u64, reduce_mul_u64x8);
-red_mul!(i8x16, i8, reduce_mul_i8x16);
-red_mul!(u8x16, u8, reduce_mul_u8x16);
-red_mul!(i16x16, i16, reduce_mul_i16x16);
-red_mul!(u16x16, u16, reduce_mul_u16x16);
-red_mul!(i32x16, i32, reduce_mul_i32x16);
-red_mul!(u32x16, u32, reduce_mul_u32x16);
-red_mul!(i8x32, i8, reduce_mul_i8x32);
-red_mul!(u8x32, u8, reduce_mul_u8x32);
-red_mul!(i16x32, i16, reduce_mul_i16x32);
-red_mul!(u16x32, u16, reduce_mul_u16x32);
-red_mul!(i8x64, i8, reduce_mul_i8x64);
-red_mul!(u8x64, u8, reduce_mul_u8x64);
+red_mul!(i8x2: 2, i8, reduce_mul_i8x2);
+red_mul!(u8x2: 2, u8, reduce_mul_u8x2);
+red_mul!(i16x2: 2, i16, reduce_mul_i16x2);
+red_mul!(u16x2: 2, u16, reduce_mul_u16x2);
+red_mul!(i32x2: 2, i32, reduce_mul_i32x2);
+red_mul!(u32x2: 2, u32, reduce_mul_u32x2);
+red_mul!(i64x2: 2, i64, reduce_mul_i64x2);
+red_mul!(u64x2: 2, u64, reduce_mul_u64x2);
+red_mul!(i8x4: 4, i8, reduce_mul_i8x4);
+red_mul!(u8x4: 4, u8, reduce_mul_u8x4);
+red_mul!(i16x4: 4, i16, reduce_mul_i16x4);
+red_mul!(u16x4: 4, u16, reduce_mul_u16x4);
+red_mul!(i32x4: 4, i32, reduce_mul_i32x4);
+red_mul!(u32x4: 4, u32, reduce_mul_u32x4);
+red_mul!(i64x4: 4, i64, reduce_mul_i64x4);
+red_mul!(u64x4: 4, u64, reduce_mul_u64x4);
+red_mul!(i8x8: 8, i8, reduce_mul_i8x8);
+red_mul!(u8x8: 8, u8, reduce_mul_u8x8);
+red_mul!(i16x8: 8, i16, reduce_mul_i16x8);
+red_mul!(u16x8: 8, u16, reduce_mul_u16x8);
+red_mul!(i32x8: 8, i32, reduce_mul_i32x8);
+red_mul!(u32x8: 8, u32, reduce_mul_u32x8);
+red_mul!(i64x8: 8, i64, reduce_mul_i64x8);
+red_mul!(u64x8: 8, u64, reduce_mul_u64x8);
+red_mul!(i8x16: 16, i8, reduce_mul_i8x16);
+red_mul!(u8x16: 16, u8, reduce_mul_u8x16);
+red_mul!(i16x16: 16, i16, reduce_mul_i16x16);
+red_mul!(u16x16: 16, u16, reduce_mul_u16x16);
+red_mul!(i32x16: 16, i32, reduce_mul_i32x16);
+red_mul!(u32x16: 16, u32, reduce_mul_u32x16);
+red_mul!(i8x32: 32, i8, reduce_mul_i8x32);
+red_mul!(u8x32: 32, u8, reduce_mul_u8x32);
+red_mul!(i16x32: 32, i16, reduce_mul_i16x32);
+red_mul!(u16x32: 32, u16, reduce_mul_u16x32);
+red_mul!(i8x64: 64, i8, reduce_mul_i8x64);
+red_mul!(u8x64: 64, u8, reduce_mul_u8x64);
+red_mul!(i128x1: 1, i128, reduce_mul_i128x1);
+red_mul!(u128x1: 1, u128, reduce_mul_u128x1);
+red_mul!(i128x2: 2, i128, reduce_mul_i128x2);
+red_mul!(u128x2: 2, u128, reduce_mul_u128x2);
+red_mul!(i128x4: 4, i128, reduce_mul_i128x4);
+red_mul!(u128x4: 4, u128, reduce_mul_u128x4);
 
 macro_rules! red_fmul {
     ($id:ident, $elem_ty:ident, $llvm_intr:ident) => {
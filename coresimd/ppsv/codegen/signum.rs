@@ -0,0 +1,113 @@
+//! Code generation for the lane-wise sign of signed integer vectors.
+//!
+//! Uses `psignb`/`psignw`/`psignd` of a vector of ones when `ssse3`
+//! (`vpsign{b,w,d}` when `avx2`) is enabled on `x86`/`x86_64`, and two
+//! lane-wise selects otherwise.
+
+use coresimd::simd::*;
+use coresimd::simd_llvm::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "ssse3"))]
+use mem;
+
+/// Lane-wise sign.
+pub trait Signum {
+    /// Returns `-1`, `0`, or `1` in each lane if the lane is negative,
+    /// zero, or positive, respectively.
+    fn signum(self) -> Self;
+}
+
+macro_rules! signum {
+    ($id:ident) => {
+        impl Signum for $id {
+            #[inline]
+            fn signum(self) -> Self {
+                let zero = $id::splat(0);
+                unsafe {
+                    let r = simd_select(self.gt(zero), $id::splat(1), zero);
+                    simd_select(self.lt(zero), $id::splat(-1), r)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! signum_x86 {
+    ($feature:tt, $id:ident, $intr:ident) => {
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = $feature))]
+        impl Signum for $id {
+            #[inline]
+            fn signum(self) -> Self {
+                use coresimd::x86::$intr;
+                // `psign` negates, zeroes, or keeps the lanes of the first
+                // argument if the lanes of the second one are negative,
+                // zero, or positive:
+                unsafe {
+                    mem::transmute($intr(
+                        mem::transmute($id::splat(1)),
+                        mem::transmute(self),
+                    ))
+                }
+            }
+        }
+    };
+}
+
+signum_x86!("ssse3", i8x16, _mm_sign_epi8);
+signum_x86!("ssse3", i16x8, _mm_sign_epi16);
+signum_x86!("ssse3", i32x4, _mm_sign_epi32);
+signum_x86!("avx2", i8x32, _mm256_sign_epi8);
+signum_x86!("avx2", i16x16, _mm256_sign_epi16);
+signum_x86!("avx2", i32x8, _mm256_sign_epi32);
+
+macro_rules! signum_fallback {
+    ($cfg:meta: $($id:ident),+) => {
+        $(
+            #[cfg($cfg)]
+            signum!($id);
+        )+
+    };
+}
+
+signum_fallback!(
+    not(all(any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "ssse3")):
+    i8x16, i16x8, i32x4
+);
+signum_fallback!(
+    not(all(any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "avx2")):
+    i8x32, i16x16, i32x8
+);
+
+signum!(i8x2);
+signum!(i8x4);
+signum!(i8x8);
+signum!(i16x2);
+signum!(i16x4);
+signum!(i32x2);
+signum!(i64x2);
+signum!(i128x1);
+signum!(i64x4);
+signum!(i128x2);
+signum!(i8x64);
+signum!(i16x32);
+signum!(i32x16);
+signum!(i64x8);
+signum!(i128x4);
+
+#[cfg(test)]
+mod tests {
+    use super::Signum;
+    use coresimd::simd::*;
+
+    // note: these are also tested in the portable vector API tests
+
+    #[test]
+    fn signum_i16x8() {
+        let v = i16x8::new(-32768, -2, -1, 0, 1, 2, 32767, 0);
+        let e = i16x8::new(-1, -1, -1, 0, 1, 1, 1, 0);
+        assert_eq!(v.signum(), e);
+    }
+}
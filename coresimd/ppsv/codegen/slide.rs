@@ -0,0 +1,140 @@
+//! Code generation checks for the lane rotations and `slide`.
+//!
+//! These are implemented by the portable shuffles of `api::slide`, which
+//! fold into a single shuffle when the lane count is a constant. These
+//! helpers call them with literal counts, and check that LLVM lowers this
+//! shuffle to `palignr` (`_mm_alignr_epi8`) on `x86`/`x86_64` with `ssse3`,
+//! and to `vext` on ARM and AArch64 with `neon`.
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod x86 {
+    //! `slide` and the lane rotations with `palignr`.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+
+    /// Lanes `3..19` of the concatenation of `a` and `b`.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    #[cfg_attr(test, assert_instr(palignr))]
+    pub unsafe fn slide_3_i8x16(a: i8x16, b: i8x16) -> i8x16 {
+        a.slide(b, 3)
+    }
+
+    /// Lanes `1..9` of the concatenation of `a` and `b`.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    #[cfg_attr(test, assert_instr(palignr))]
+    pub unsafe fn slide_1_u16x8(a: u16x8, b: u16x8) -> u16x8 {
+        a.slide(b, 1)
+    }
+
+    /// Rotates the lanes of `x` down by 5 lanes.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    #[cfg_attr(test, assert_instr(palignr))]
+    pub unsafe fn rotate_lanes_left_5_u8x16(x: u8x16) -> u8x16 {
+        x.rotate_lanes_left(5)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+        use coresimd::x86::*;
+
+        #[simd_test = "ssse3"]
+        unsafe fn slide_ssse3() {
+            let a = i8x16::new(
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            );
+            let b = a + i8x16::splat(16);
+            let e = i8x16::from_bits(_mm_alignr_epi8(
+                b.into_bits(),
+                a.into_bits(),
+                3,
+            ));
+            assert_eq!(super::slide_3_i8x16(a, b), e);
+
+            let a = u16x8::new(0, 1, 2, 3, 4, 5, 6, 7);
+            let b = a + u16x8::splat(8);
+            let e = u16x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+            assert_eq!(super::slide_1_u16x8(a, b), e);
+
+            let x = u8x16::from_bits(a);
+            let e = u8x16::from_bits(_mm_alignr_epi8(
+                x.into_bits(),
+                x.into_bits(),
+                5,
+            ));
+            assert_eq!(super::rotate_lanes_left_5_u8x16(x), e);
+        }
+    }
+}
+
+#[cfg(all(any(target_arch = "arm", target_arch = "aarch64"),
+          target_feature = "neon"))]
+pub mod neon {
+    //! `slide` and the lane rotations with `vext`.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+
+    /// Lanes `3..11` of the concatenation of `a` and `b`.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    #[cfg_attr(all(test, target_arch = "arm"), assert_instr(vext))]
+    #[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(ext))]
+    pub unsafe fn slide_3_u8x8(a: u8x8, b: u8x8) -> u8x8 {
+        a.slide(b, 3)
+    }
+
+    /// Lanes `5..21` of the concatenation of `a` and `b`.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    #[cfg_attr(all(test, target_arch = "arm"), assert_instr(vext))]
+    #[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(ext))]
+    pub unsafe fn slide_5_i8x16(a: i8x16, b: i8x16) -> i8x16 {
+        a.slide(b, 5)
+    }
+
+    /// Rotates the lanes of `x` down by 1 lane.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    #[cfg_attr(all(test, target_arch = "arm"), assert_instr(vext))]
+    #[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(ext))]
+    pub unsafe fn rotate_lanes_left_1_f32x4(x: f32x4) -> f32x4 {
+        x.rotate_lanes_left(1)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+
+        #[simd_test = "neon"]
+        unsafe fn slide_neon() {
+            let a = u8x8::new(0, 1, 2, 3, 4, 5, 6, 7);
+            let b = a + u8x8::splat(8);
+            let e = u8x8::new(3, 4, 5, 6, 7, 8, 9, 10);
+            assert_eq!(super::slide_3_u8x8(a, b), e);
+
+            let a = i8x16::new(
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            );
+            let b = a + i8x16::splat(16);
+            let e = i8x16::new(
+                5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+            );
+            assert_eq!(super::slide_5_i8x16(a, b), e);
+
+            let x = f32x4::new(1., 2., 3., 4.);
+            let e = f32x4::new(2., 3., 4., 1.);
+            assert_eq!(super::rotate_lanes_left_1_f32x4(x), e);
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! Code generation for the widening horizontal sum of integer vectors.
+//!
+//! The lanes are added into a wider accumulator, so that the sum cannot
+//! overflow. The reductions use:
+//!
+//! * `x86`/`x86_64`: `psadbw` for 8-bit lanes when `sse2` (`vpsadbw` when
+//!   `avx2`) is enabled,
+//! * `aarch64`: `saddlv`/`uaddlv` for 8- and 16-bit lanes when `neon` is
+//!   enabled,
+//! * a loop over the lanes otherwise, which LLVM vectorizes.
+//!
+//! Vectors without a dedicated implementation are reduced one half at a time.
+
+use coresimd::simd::*;
+use coresimd::simd_llvm::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "sse2"))]
+use mem;
+
+/// Reduction: horizontal sum of the vector elements into a wider scalar.
+pub trait ReduceAddWidening {
+    /// Result type of the reduction.
+    type Acc;
+    /// Computes the horizontal sum of the vector elements.
+    fn reduce_add_widening(self) -> Self::Acc;
+}
+
+macro_rules! red_add_widening {
+    ($id:ident, $acc:ident) => {
+        impl ReduceAddWidening for $id {
+            type Acc = $acc;
+            #[inline]
+            fn reduce_add_widening(self) -> $acc {
+                let mut acc: $acc = 0;
+                for i in 0..$id::lanes() {
+                    acc += unsafe { self.extract_unchecked(i) } as $acc;
+                }
+                acc
+            }
+        }
+    };
+}
+
+macro_rules! red_add_widening_halves {
+    ($id:ident, $acc:ident, $half:ident, $shuffle:ident,
+     [$($lo:expr),+], [$($hi:expr),+]) => {
+        impl ReduceAddWidening for $id {
+            type Acc = $acc;
+            #[inline]
+            fn reduce_add_widening(self) -> $acc {
+                unsafe {
+                    let lo: $half = $shuffle(self, self, [$($lo),+]);
+                    let hi: $half = $shuffle(self, self, [$($hi),+]);
+                    lo.reduce_add_widening() + hi.reduce_add_widening()
+                }
+            }
+        }
+    };
+}
+
+red_add_widening!(i8x2, i32);
+red_add_widening!(u8x2, u32);
+red_add_widening!(i8x4, i32);
+red_add_widening!(u8x4, u32);
+red_add_widening!(i16x2, i64);
+red_add_widening!(u16x2, u64);
+red_add_widening!(i32x2, i64);
+red_add_widening!(u32x2, u64);
+red_add_widening!(i32x4, i64);
+red_add_widening!(u32x4, u64);
+red_add_widening!(i32x8, i64);
+red_add_widening!(u32x8, u64);
+red_add_widening!(i32x16, i64);
+red_add_widening!(u32x16, u64);
+
+// 8-bit lanes on x86: `psadbw` computes the sums of the absolute
+// differences of each group of eight bytes, which against zero are the sums
+// of the bytes. Signed bytes are biased into the unsigned range first.
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "sse2"))]
+impl ReduceAddWidening for u8x16 {
+    type Acc = u32;
+    #[inline]
+    fn reduce_add_widening(self) -> u32 {
+        use coresimd::x86::_mm_sad_epu8;
+        unsafe {
+            let r: u64x2 = mem::transmute(_mm_sad_epu8(
+                mem::transmute(self),
+                mem::transmute(u8x16::splat(0)),
+            ));
+            (r.extract_unchecked(0) + r.extract_unchecked(1)) as u32
+        }
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "sse2"))]
+impl ReduceAddWidening for u8x8 {
+    type Acc = u32;
+    #[inline]
+    fn reduce_add_widening(self) -> u32 {
+        unsafe {
+            let x: u8x16 = simd_shuffle16(
+                self,
+                u8x8::splat(0),
+                [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+            );
+            x.reduce_add_widening()
+        }
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "sse2"))]
+impl ReduceAddWidening for i8x16 {
+    type Acc = i32;
+    #[inline]
+    fn reduce_add_widening(self) -> i32 {
+        let x: u8x16 = unsafe { mem::transmute(self ^ i8x16::splat(-128)) };
+        x.reduce_add_widening() as i32 - 128 * 16
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "sse2"))]
+impl ReduceAddWidening for i8x8 {
+    type Acc = i32;
+    #[inline]
+    fn reduce_add_widening(self) -> i32 {
+        let x: u8x8 = unsafe { mem::transmute(self ^ i8x8::splat(-128)) };
+        x.reduce_add_widening() as i32 - 128 * 8
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "avx2"))]
+impl ReduceAddWidening for u8x32 {
+    type Acc = u32;
+    #[inline]
+    fn reduce_add_widening(self) -> u32 {
+        use coresimd::x86::_mm256_sad_epu8;
+        unsafe {
+            let r: u64x4 = mem::transmute(_mm256_sad_epu8(
+                mem::transmute(self),
+                mem::transmute(u8x32::splat(0)),
+            ));
+            (r.extract_unchecked(0)
+                + r.extract_unchecked(1)
+                + r.extract_unchecked(2)
+                + r.extract_unchecked(3)) as u32
+        }
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "avx2"))]
+impl ReduceAddWidening for i8x32 {
+    type Acc = i32;
+    #[inline]
+    fn reduce_add_widening(self) -> i32 {
+        let x: u8x32 = unsafe { mem::transmute(self ^ i8x32::splat(-128)) };
+        x.reduce_add_widening() as i32 - 128 * 32
+    }
+}
+
+// 8- and 16-bit lanes on AArch64: `saddlv`/`uaddlv` sum the lanes into a
+// scalar twice as wide as the lanes.
+
+macro_rules! red_add_widening_neon {
+    ($id:ident, $acc:ident, $intr:ident) => {
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        impl ReduceAddWidening for $id {
+            type Acc = $acc;
+            #[inline]
+            fn reduce_add_widening(self) -> $acc {
+                use coresimd::aarch64::$intr;
+                unsafe { $intr(self) as $acc }
+            }
+        }
+    };
+}
+
+red_add_widening_neon!(i8x8, i32, vaddlv_s8);
+red_add_widening_neon!(u8x8, u32, vaddlv_u8);
+red_add_widening_neon!(i8x16, i32, vaddlvq_s8);
+red_add_widening_neon!(u8x16, u32, vaddlvq_u8);
+red_add_widening_neon!(i16x4, i64, vaddlv_s16);
+red_add_widening_neon!(u16x4, u64, vaddlv_u16);
+red_add_widening_neon!(i16x8, i64, vaddlvq_s16);
+red_add_widening_neon!(u16x8, u64, vaddlvq_u16);
+
+macro_rules! red_add_widening_fallback {
+    ($cfg:meta: $($id:ident, $acc:ident);+) => {
+        $(
+            #[cfg($cfg)]
+            red_add_widening!($id, $acc);
+        )+
+    };
+}
+
+red_add_widening_fallback!(
+    not(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "sse2"),
+            all(target_arch = "aarch64", target_feature = "neon"))):
+    i8x8, i32; u8x8, u32; i8x16, i32; u8x16, u32
+);
+red_add_widening_fallback!(
+    not(all(target_arch = "aarch64", target_feature = "neon")):
+    i16x4, i64; u16x4, u64; i16x8, i64; u16x8, u64
+);
+
+#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"),
+              target_feature = "avx2")))]
+red_add_widening_halves!(
+    i8x32, i32, i8x16, simd_shuffle16,
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]
+);
+#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"),
+              target_feature = "avx2")))]
+red_add_widening_halves!(
+    u8x32, u32, u8x16, simd_shuffle16,
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]
+);
+red_add_widening_halves!(
+    i8x64, i32, i8x32, simd_shuffle32,
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+     16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31],
+    [32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+     48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]
+);
+red_add_widening_halves!(
+    u8x64, u32, u8x32, simd_shuffle32,
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+     16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31],
+    [32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+     48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63]
+);
+red_add_widening_halves!(
+    i16x16, i64, i16x8, simd_shuffle8,
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [8, 9, 10, 11, 12, 13, 14, 15]
+);
+red_add_widening_halves!(
+    u16x16, u64, u16x8, simd_shuffle8,
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [8, 9, 10, 11, 12, 13, 14, 15]
+);
+red_add_widening_halves!(
+    i16x32, i64, i16x16, simd_shuffle16,
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]
+);
+red_add_widening_halves!(
+    u16x32, u64, u16x16, simd_shuffle16,
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::ReduceAddWidening;
+    use coresimd::simd::*;
+
+    // note: these are also tested in the portable vector API tests
+
+    #[test]
+    fn reduce_add_widening_u8x16() {
+        let v = u8x16::splat(255);
+        assert_eq!(v.reduce_add_widening(), 255 * 16);
+    }
+
+    #[test]
+    fn reduce_add_widening_i8x32() {
+        let v = i8x32::splat(-128);
+        assert_eq!(v.reduce_add_widening(), -128 * 32);
+        let v = i8x32::splat(127);
+        assert_eq!(v.reduce_add_widening(), 127 * 32);
+    }
+
+    #[test]
+    fn reduce_add_widening_u16x32() {
+        let v = u16x32::splat(65535);
+        assert_eq!(v.reduce_add_widening(), 65535 * 32);
+    }
+}
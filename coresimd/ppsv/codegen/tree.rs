@@ -0,0 +1,483 @@
+//! Fallback code generation for the horizontal reductions.
+//!
+//! LLVM lowers its `llvm.experimental.vector.reduce.*` intrinsics poorly on
+//! some targets: it crashes or emits scalar loops on ARM, AArch64, PowerPC,
+//! and on `x86` without `sse2`. On these targets, the reductions are
+//! computed instead by a tree of `log2(lanes)` lane-wise operations, each
+//! combining the vector with itself rotated by half of the remaining lanes.
+//!
+//! The `sum` and `product` of floating-point vectors do not use the tree:
+//! they add (multiply) the lanes strictly from left to right on every
+//! target, that is, `((x0 + x1) + x2) + x3` for a `f32x4`.
+//!
+//! Some reductions have a better lowering with target intrinsics, which is
+//! used on every target supporting them:
+//!
+//! * `x86`/`x86_64`: `phaddw`/`phaddd` for the sum of 16- and 32-bit lanes of
+//!   128-bit vectors when `ssse3` is enabled, and `phminposuw` for the
+//!   minimum and maximum of 16-bit lanes of 128-bit vectors when `sse4.1` is
+//!   enabled,
+//! * `arm`/`aarch64`: the pairwise `vpadd`, `vpmin` and `vpmax` for the sum,
+//!   minimum and maximum of the integer lanes of 64-bit vectors when `neon`
+//!   is enabled.
+
+/// Reduces the vector `$x` of type `$id` with `$lanes` lanes with the
+/// lane-wise operation `$op`.
+///
+/// Returns a vector whose lane `0` contains the reduction.
+macro_rules! tree_reduce {
+    ($id:ident: 1, $x:expr, $op:expr) => {{
+        $x
+    }};
+    ($id:ident: 2, $x:expr, $op:expr) => {{
+        use coresimd::simd_llvm::*;
+        let op = $op;
+        let x: $id = $x;
+        unsafe { op(x, slide_lanes_by!($id, 2, 1, x, x)) }
+    }};
+    ($id:ident: 4, $x:expr, $op:expr) => {{
+        use coresimd::simd_llvm::*;
+        let op = $op;
+        let x: $id = $x;
+        let x = unsafe { op(x, slide_lanes_by!($id, 4, 2, x, x)) };
+        tree_reduce!(@half $id: 4, x, op)
+    }};
+    ($id:ident: 8, $x:expr, $op:expr) => {{
+        use coresimd::simd_llvm::*;
+        let op = $op;
+        let x: $id = $x;
+        let x = unsafe { op(x, slide_lanes_by!($id, 8, 4, x, x)) };
+        tree_reduce!(@half $id: 8, x, op)
+    }};
+    ($id:ident: 16, $x:expr, $op:expr) => {{
+        use coresimd::simd_llvm::*;
+        let op = $op;
+        let x: $id = $x;
+        let x = unsafe { op(x, slide_lanes_by!($id, 16, 8, x, x)) };
+        tree_reduce!(@half $id: 16, x, op)
+    }};
+    ($id:ident: 32, $x:expr, $op:expr) => {{
+        use coresimd::simd_llvm::*;
+        let op = $op;
+        let x: $id = $x;
+        let x = unsafe { op(x, slide_lanes_by!($id, 32, 16, x, x)) };
+        tree_reduce!(@half $id: 32, x, op)
+    }};
+    ($id:ident: 64, $x:expr, $op:expr) => {{
+        use coresimd::simd_llvm::*;
+        let op = $op;
+        let x: $id = $x;
+        let x = unsafe { op(x, slide_lanes_by!($id, 64, 32, x, x)) };
+        tree_reduce!(@half $id: 64, x, op)
+    }};
+    // The remaining steps, once the `$lanes / 2` upper lanes of `$x` are
+    // a copy of its lower lanes.
+    (@half $id:ident: 4, $x:expr, $op:ident) => {{
+        unsafe { $op($x, slide_lanes_by!($id, 4, 1, $x, $x)) }
+    }};
+    (@half $id:ident: 8, $x:expr, $op:ident) => {{
+        let x = unsafe { $op($x, slide_lanes_by!($id, 8, 2, $x, $x)) };
+        unsafe { $op(x, slide_lanes_by!($id, 8, 1, x, x)) }
+    }};
+    (@half $id:ident: 16, $x:expr, $op:ident) => {{
+        let x = unsafe { $op($x, slide_lanes_by!($id, 16, 4, $x, $x)) };
+        tree_reduce!(@half $id: 8 of 16, x, $op)
+    }};
+    (@half $id:ident: 32, $x:expr, $op:ident) => {{
+        let x = unsafe { $op($x, slide_lanes_by!($id, 32, 8, $x, $x)) };
+        tree_reduce!(@half $id: 16 of 32, x, $op)
+    }};
+    (@half $id:ident: 64, $x:expr, $op:ident) => {{
+        let x = unsafe { $op($x, slide_lanes_by!($id, 64, 16, $x, $x)) };
+        tree_reduce!(@half $id: 32 of 64, x, $op)
+    }};
+    (@half $id:ident: 8 of 16, $x:expr, $op:ident) => {{
+        let x = unsafe { $op($x, slide_lanes_by!($id, 16, 2, $x, $x)) };
+        unsafe { $op(x, slide_lanes_by!($id, 16, 1, x, x)) }
+    }};
+    (@half $id:ident: 16 of 32, $x:expr, $op:ident) => {{
+        let x = unsafe { $op($x, slide_lanes_by!($id, 32, 4, $x, $x)) };
+        let x = unsafe { $op(x, slide_lanes_by!($id, 32, 2, x, x)) };
+        unsafe { $op(x, slide_lanes_by!($id, 32, 1, x, x)) }
+    }};
+    (@half $id:ident: 32 of 64, $x:expr, $op:ident) => {{
+        let x = unsafe { $op($x, slide_lanes_by!($id, 64, 8, $x, $x)) };
+        let x = unsafe { $op(x, slide_lanes_by!($id, 64, 4, x, x)) };
+        let x = unsafe { $op(x, slide_lanes_by!($id, 64, 2, x, x)) };
+        unsafe { $op(x, slide_lanes_by!($id, 64, 1, x, x)) }
+    }};
+}
+
+/// Defines the method `$name` of a reduction trait.
+///
+/// It computes the reduction with the target intrinsics `$route` when they
+/// are available, and otherwise with `$llvm` on the targets on which LLVM
+/// lowers it well, and with `$tree` on the others.
+macro_rules! red_fn {
+    ($name:ident -> $acc:ty, llvm: $llvm:expr, tree: $tree:expr) => {
+        red_fn!(@impl any(), $name -> $acc, $llvm, $tree, $tree);
+    };
+    ($name:ident -> $acc:ty, llvm: $llvm:expr, tree: $tree:expr,
+     ssse3: $route:path) => {
+        red_fn!(
+            @impl all(any(target_arch = "x86", target_arch = "x86_64"),
+                      target_feature = "ssse3"),
+            $name -> $acc, $llvm, $tree,
+            |x: Self| unsafe { $route(x) }
+        );
+    };
+    ($name:ident -> $acc:ty, llvm: $llvm:expr, tree: $tree:expr,
+     sse41: $route:path) => {
+        red_fn!(
+            @impl all(any(target_arch = "x86", target_arch = "x86_64"),
+                      target_feature = "sse4.1"),
+            $name -> $acc, $llvm, $tree,
+            |x: Self| unsafe { $route(x) }
+        );
+    };
+    ($name:ident -> $acc:ty, llvm: $llvm:expr, tree: $tree:expr,
+     neon: $route:path) => {
+        red_fn!(
+            @impl all(any(target_arch = "arm", target_arch = "aarch64"),
+                      target_feature = "neon"),
+            $name -> $acc, $llvm, $tree,
+            |x: Self| unsafe { $route(x) }
+        );
+    };
+    (@impl $route_cfg:meta, $name:ident -> $acc:ty,
+     $llvm:expr, $tree:expr, $route:expr) => {
+        #[cfg($route_cfg)]
+        #[inline]
+        fn $name(self) -> $acc {
+            ($route)(self)
+        }
+        #[cfg(all(not($route_cfg),
+                  not(any(target_arch = "arm",
+                          target_arch = "aarch64",
+                          target_arch = "powerpc",
+                          target_arch = "powerpc64",
+                          all(target_arch = "x86",
+                              not(target_feature = "sse2"))))))]
+        #[inline]
+        fn $name(self) -> $acc {
+            ($llvm)(self)
+        }
+        #[cfg(all(not($route_cfg),
+                  any(target_arch = "arm",
+                      target_arch = "aarch64",
+                      target_arch = "powerpc",
+                      target_arch = "powerpc64",
+                      all(target_arch = "x86",
+                          not(target_feature = "sse2")))))]
+        #[inline]
+        fn $name(self) -> $acc {
+            ($tree)(self)
+        }
+    };
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod x86 {
+    //! Reductions with `x86`/`x86_64` target intrinsics.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+    use coresimd::x86::*;
+
+    /// Sum of the lanes of `x` with `phaddw`.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    #[cfg_attr(test, assert_instr(phaddw))]
+    pub unsafe fn reduce_add_i16x8(x: i16x8) -> i16 {
+        let x: __m128i = x.into_bits();
+        let x = _mm_hadd_epi16(x, x);
+        let x = _mm_hadd_epi16(x, x);
+        let x = _mm_hadd_epi16(x, x);
+        i16x8::from_bits(x).extract(0)
+    }
+
+    /// Sum of the lanes of `x` with `phaddw`.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    #[cfg_attr(test, assert_instr(phaddw))]
+    pub unsafe fn reduce_add_u16x8(x: u16x8) -> u16 {
+        reduce_add_i16x8(x.into_bits()) as u16
+    }
+
+    /// Sum of the lanes of `x` with `phaddd`.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    #[cfg_attr(test, assert_instr(phaddd))]
+    pub unsafe fn reduce_add_i32x4(x: i32x4) -> i32 {
+        let x: __m128i = x.into_bits();
+        let x = _mm_hadd_epi32(x, x);
+        let x = _mm_hadd_epi32(x, x);
+        i32x4::from_bits(x).extract(0)
+    }
+
+    /// Sum of the lanes of `x` with `phaddd`.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    #[cfg_attr(test, assert_instr(phaddd))]
+    pub unsafe fn reduce_add_u32x4(x: u32x4) -> u32 {
+        reduce_add_i32x4(x.into_bits()) as u32
+    }
+
+    /// Minimum of the lanes of `x` with `phminposuw`.
+    #[inline]
+    #[target_feature(enable = "sse4.1")]
+    #[cfg_attr(test, assert_instr(phminposuw))]
+    pub unsafe fn reduce_min_u16x8(x: u16x8) -> u16 {
+        u16x8::from_bits(_mm_minpos_epu16(x.into_bits())).extract(0)
+    }
+
+    /// Maximum of the lanes of `x` with `phminposuw`.
+    #[inline]
+    #[target_feature(enable = "sse4.1")]
+    #[cfg_attr(test, assert_instr(phminposuw))]
+    pub unsafe fn reduce_max_u16x8(x: u16x8) -> u16 {
+        !reduce_min_u16x8(!x)
+    }
+
+    /// Minimum of the lanes of `x` with `phminposuw`.
+    #[inline]
+    #[target_feature(enable = "sse4.1")]
+    #[cfg_attr(test, assert_instr(phminposuw))]
+    pub unsafe fn reduce_min_i16x8(x: i16x8) -> i16 {
+        // Flipping the sign bit maps the order of `i16` to that of `u16`:
+        let x = u16x8::from_bits(x) ^ u16x8::splat(0x8000);
+        (reduce_min_u16x8(x) ^ 0x8000) as i16
+    }
+
+    /// Maximum of the lanes of `x` with `phminposuw`.
+    #[inline]
+    #[target_feature(enable = "sse4.1")]
+    #[cfg_attr(test, assert_instr(phminposuw))]
+    pub unsafe fn reduce_max_i16x8(x: i16x8) -> i16 {
+        // Flipping all bits but the sign bit maps the order of `i16` to the
+        // reverse order of `u16`:
+        let x = u16x8::from_bits(x) ^ u16x8::splat(0x7fff);
+        (reduce_min_u16x8(x) ^ 0x7fff) as i16
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+
+        #[simd_test = "ssse3"]
+        unsafe fn reduce_add_ssse3() {
+            let x = i16x8::new(1, -2, 3, 4, 5, 6, 7, i16::max_value());
+            assert_eq!(super::reduce_add_i16x8(x), i16::min_value() + 23);
+            let x = u16x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+            assert_eq!(super::reduce_add_u16x8(x), 36);
+            let x = i32x4::new(1, -2, 3, 4);
+            assert_eq!(super::reduce_add_i32x4(x), 6);
+            let x = u32x4::new(1, 2, 3, u32::max_value());
+            assert_eq!(super::reduce_add_u32x4(x), 5);
+        }
+
+        #[simd_test = "sse4.1"]
+        unsafe fn reduce_minmax_sse41() {
+            let x = u16x8::new(7, 2, 3, 65535, 5, 6, 1, 8);
+            assert_eq!(super::reduce_min_u16x8(x), 1);
+            assert_eq!(super::reduce_max_u16x8(x), 65535);
+            let x = i16x8::new(7, -2, 3, 4, i16::min_value(), 6, 1, 8);
+            assert_eq!(super::reduce_min_i16x8(x), i16::min_value());
+            assert_eq!(super::reduce_max_i16x8(x), 8);
+            let x = i16x8::new(-7, -2, -3, -4, -5, -6, -1, -8);
+            assert_eq!(super::reduce_min_i16x8(x), -8);
+            assert_eq!(super::reduce_max_i16x8(x), -1);
+        }
+    }
+}
+
+#[cfg(all(any(target_arch = "arm", target_arch = "aarch64"),
+          target_feature = "neon"))]
+pub mod neon {
+    //! Reductions with the pairwise NEON intrinsics.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+    use coresimd::arm::*;
+
+    /// Reduces `$id` by applying the pairwise `$intr` to `x` and itself
+    /// `log2(lanes)` times.
+    macro_rules! pairwise {
+        ($name:ident, $id:ident, $elem_ty:ident, $intr:ident,
+         $arm_instr:ident, $aarch64_instr:ident) => {
+            #[inline]
+            #[target_feature(enable = "neon")]
+            #[cfg_attr(all(test, target_arch = "arm"),
+                       assert_instr($arm_instr))]
+            #[cfg_attr(all(test, target_arch = "aarch64"),
+                       assert_instr($aarch64_instr))]
+            pub unsafe fn $name(x: $id) -> $elem_ty {
+                let mut x = x;
+                let mut n = $id::lanes();
+                while n > 1 {
+                    x = $intr(x, x);
+                    n /= 2;
+                }
+                x.extract(0)
+            }
+        };
+    }
+
+    pairwise!(reduce_add_i8x8, i8x8, i8, vpadd_s8, vpadd, addp);
+    pairwise!(reduce_add_u8x8, u8x8, u8, vpadd_u8, vpadd, addp);
+    pairwise!(reduce_add_i16x4, i16x4, i16, vpadd_s16, vpadd, addp);
+    pairwise!(reduce_add_u16x4, u16x4, u16, vpadd_u16, vpadd, addp);
+    pairwise!(reduce_add_i32x2, i32x2, i32, vpadd_s32, vpadd, addp);
+    pairwise!(reduce_add_u32x2, u32x2, u32, vpadd_u32, vpadd, addp);
+
+    pairwise!(reduce_min_i8x8, i8x8, i8, vpmin_s8, vpmin, sminp);
+    pairwise!(reduce_min_u8x8, u8x8, u8, vpmin_u8, vpmin, uminp);
+    pairwise!(reduce_min_i16x4, i16x4, i16, vpmin_s16, vpmin, sminp);
+    pairwise!(reduce_min_u16x4, u16x4, u16, vpmin_u16, vpmin, uminp);
+    pairwise!(reduce_min_i32x2, i32x2, i32, vpmin_s32, vpmin, sminp);
+    pairwise!(reduce_min_u32x2, u32x2, u32, vpmin_u32, vpmin, uminp);
+
+    pairwise!(reduce_max_i8x8, i8x8, i8, vpmax_s8, vpmax, smaxp);
+    pairwise!(reduce_max_u8x8, u8x8, u8, vpmax_u8, vpmax, umaxp);
+    pairwise!(reduce_max_i16x4, i16x4, i16, vpmax_s16, vpmax, smaxp);
+    pairwise!(reduce_max_u16x4, u16x4, u16, vpmax_u16, vpmax, umaxp);
+    pairwise!(reduce_max_i32x2, i32x2, i32, vpmax_s32, vpmax, smaxp);
+    pairwise!(reduce_max_u32x2, u32x2, u32, vpmax_u32, vpmax, umaxp);
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+
+        #[simd_test = "neon"]
+        unsafe fn reduce_pairwise_neon() {
+            let x = i8x8::new(1, -2, 3, 4, 5, 6, 7, 8);
+            assert_eq!(super::reduce_add_i8x8(x), 32);
+            assert_eq!(super::reduce_min_i8x8(x), -2);
+            assert_eq!(super::reduce_max_i8x8(x), 8);
+            let x = u16x4::new(1, 65535, 3, 4);
+            assert_eq!(super::reduce_add_u16x4(x), 7);
+            assert_eq!(super::reduce_min_u16x4(x), 1);
+            assert_eq!(super::reduce_max_u16x4(x), 65535);
+            let x = i32x2::new(-1, 2);
+            assert_eq!(super::reduce_add_i32x2(x), 1);
+            assert_eq!(super::reduce_min_i32x2(x), -1);
+            assert_eq!(super::reduce_max_i32x2(x), 2);
+        }
+    }
+}
+
+#[cfg(any(target_arch = "arm",
+          target_arch = "aarch64",
+          target_arch = "powerpc",
+          target_arch = "powerpc64"))]
+pub mod fallback {
+    //! Reductions of the portable vectors on the targets on which `red_fn!`
+    //! computes them with `tree_reduce!`.
+    //!
+    //! On `x86` without `sse2` the tree is lowered to scalar code, so there
+    //! is no instruction to check there.
+
+    #[cfg(test)]
+    use stdsimd_test::assert_instr;
+
+    use coresimd::simd::*;
+    use super::super::max::ReduceMax;
+    use super::super::product::ReduceMul;
+    use super::super::sum::ReduceAdd;
+
+    /// Sum of the lanes of `x`.
+    #[inline]
+    #[cfg(any(target_arch = "arm",
+              target_arch = "powerpc",
+              target_arch = "powerpc64"))]
+    #[cfg_attr(target_arch = "arm", target_feature(enable = "neon"))]
+    #[cfg_attr(not(target_arch = "arm"), target_feature(enable = "altivec"))]
+    #[cfg_attr(all(test, target_arch = "arm"), assert_instr(vadd))]
+    #[cfg_attr(all(test, not(target_arch = "arm")), assert_instr(vadduwm))]
+    pub unsafe fn reduce_add_i32x4(x: i32x4) -> i32 {
+        x.reduce_add()
+    }
+
+    /// Product of the lanes of `x`.
+    #[inline]
+    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+    #[target_feature(enable = "neon")]
+    #[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmul))]
+    #[cfg_attr(all(test, target_arch = "aarch64"), assert_instr(mul))]
+    pub unsafe fn reduce_mul_i32x4(x: i32x4) -> i32 {
+        x.reduce_mul()
+    }
+
+    /// Maximum of the lanes of `x`.
+    #[inline]
+    #[cfg(any(target_arch = "arm",
+              target_arch = "powerpc",
+              target_arch = "powerpc64"))]
+    #[cfg_attr(target_arch = "arm", target_feature(enable = "neon"))]
+    #[cfg_attr(not(target_arch = "arm"), target_feature(enable = "altivec"))]
+    #[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmax))]
+    #[cfg_attr(all(test, not(target_arch = "arm")), assert_instr(vmaxsw))]
+    pub unsafe fn reduce_max_i32x4(x: i32x4) -> i32 {
+        x.reduce_max()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use stdsimd_test::simd_test;
+        use coresimd::simd::*;
+
+        #[cfg(target_arch = "arm")]
+        #[simd_test = "neon"]
+        unsafe fn reduce_tree_arm() {
+            let x = i32x4::new(1, -2, 3, 4);
+            assert_eq!(super::reduce_add_i32x4(x), 6);
+            assert_eq!(super::reduce_mul_i32x4(x), -24);
+            assert_eq!(super::reduce_max_i32x4(x), 4);
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        #[simd_test = "neon"]
+        unsafe fn reduce_tree_aarch64() {
+            let x = i32x4::new(1, -2, 3, 4);
+            assert_eq!(super::reduce_mul_i32x4(x), -24);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use coresimd::simd::*;
+
+    // note: the reductions are tested in the portable vector API tests on
+    // every target, which only use the tree reductions on some of them.
+
+    #[test]
+    fn tree_reduce() {
+        let x = i32x4::new(1, 2, 3, 4);
+        let r = tree_reduce!(i32x4: 4, x, |a: i32x4, b: i32x4| a + b);
+        assert_eq!(r.extract(0), 10);
+
+        let mut x = u8x64::splat(0);
+        for i in 0..64 {
+            x = x.replace(i, (i as u8) ^ 0x5a);
+        }
+        let r = tree_reduce!(u8x64: 64, x, |a: u8x64, b: u8x64| {
+            a.lanewise_max(b)
+        });
+        assert_eq!(r.extract(0), 0x7f);
+
+        let x = f32x2::new(1., 2.);
+        let r = tree_reduce!(f32x2: 2, x, |a: f32x2, b: f32x2| a * b);
+        assert_eq!(r.extract(0), 2.);
+
+        let x = i128x1::new(7);
+        let r = tree_reduce!(i128x1: 1, x, |a: i128x1, b: i128x1| a ^ b);
+        assert_eq!(r.extract(0), 7);
+    }
+}
@@ -0,0 +1,50 @@
+//! Division of unsigned integer vectors by runtime-invariant divisors.
+//!
+//! Most targets have no vector integer division instructions, so the
+//! division of integer vectors is scalarized. A `Divisor` precomputes a
+//! multiplier and two shifts for a divisor `d`, which turn the division of
+//! the lanes by `d` into the high half of a widening multiplication, a
+//! subtraction, an addition, and two shifts (Granlund and Montgomery,
+//! "Division by Invariant Integers using Multiplication", 1994, as in the
+//! branch-free algorithm of `libdivide`).
+
+use coresimd::simd::{u16x16, u16x4, u16x8, u32x2, u32x4, u32x8};
+use mem;
+use ops;
+
+/// A divisor of the lanes of the unsigned integer vectors `T`, with a
+/// precomputed multiplier and shifts.
+///
+/// ```
+/// #![feature(stdsimd)]
+/// # #![cfg_attr(not(dox), no_std)]
+/// # #[cfg(not(dox))]
+/// # extern crate std as real_std;
+/// # #[cfg(not(dox))]
+/// # extern crate stdsimd as std;
+///
+/// use std::simd::{u32x8, Divisor};
+///
+/// # fn main() {
+/// let d = Divisor::<u32x8>::new(10);
+/// let x = u32x8::new(0, 9, 10, 99, 100, 12345, 1 << 31, !0);
+/// assert_eq!(x / d, u32x8::new(0, 0, 1, 9, 10, 1234, 214748364, 429496729));
+/// assert_eq!(x % d, u32x8::new(0, 9, 0, 9, 0, 5, 8, 5));
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Divisor<T> {
+    /// The divisor in all lanes.
+    divisor: T,
+    /// The multiplier in all lanes.
+    mul: T,
+    shift1: u32,
+    shift2: u32,
+}
+
+impl_divisor!(u16x4: u16, u32, u16x4_divisor_tests);
+impl_divisor!(u16x8: u16, u32, u16x8_divisor_tests);
+impl_divisor!(u16x16: u16, u32, u16x16_divisor_tests);
+impl_divisor!(u32x2: u32, u64, u32x2_divisor_tests);
+impl_divisor!(u32x4: u32, u64, u32x4_divisor_tests);
+impl_divisor!(u32x8: u32, u64, u32x8_divisor_tests);
@@ -0,0 +1,134 @@
+//! Helpers for differential testing of lane-wise vector operations against
+//! scalar reference implementations.
+//!
+//! These are available with the `quickcheck` feature, which also implements
+//! `quickcheck::Arbitrary` for the portable vector types, for example:
+//!
+//! ```ignore
+//! fn prop_add(a: f32x4, b: f32x4) -> bool {
+//!     check_binary_lanewise(a, b, |a, b| a + b, |a, b| a + b)
+//! }
+//! quickcheck(prop_add as fn(f32x4, f32x4) -> bool);
+//! ```
+
+use cmp::PartialEq;
+use ops::Fn;
+
+use super::Simd;
+
+/// Returns `true` if the lanes are equal, or if both are `NaN`.
+#[inline]
+#[cfg_attr(feature = "cargo-clippy", allow(eq_op))]
+fn lane_eq<T: PartialEq>(a: T, b: T) -> bool {
+    a == b || (a != a && b != b)
+}
+
+/// Checks the lane-wise vector operation `vector_op` against the scalar
+/// operation `scalar_op`.
+///
+/// Returns `true` if, for every lane `i`, the lane `i` of `vector_op(a)` is
+/// equal to `scalar_op(a.extract(i))`, where `NaN` lanes are considered
+/// equal to each other.
+pub fn check_unary_lanewise<V, F, S>(a: V, vector_op: F, scalar_op: S) -> bool
+where
+    V: Simd,
+    V::Element: PartialEq,
+    F: Fn(V) -> V,
+    S: Fn(V::Element) -> V::Element,
+{
+    let r = vector_op(a);
+    for i in 0..V::LANES {
+        if !lane_eq(r.extract(i), scalar_op(a.extract(i))) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Checks the lane-wise vector operation `vector_op` against the scalar
+/// operation `scalar_op`.
+///
+/// Returns `true` if, for every lane `i`, the lane `i` of `vector_op(a, b)`
+/// is equal to `scalar_op(a.extract(i), b.extract(i))`, where `NaN` lanes
+/// are considered equal to each other.
+pub fn check_binary_lanewise<V, F, S>(
+    a: V, b: V, vector_op: F, scalar_op: S
+) -> bool
+where
+    V: Simd,
+    V::Element: PartialEq,
+    F: Fn(V, V) -> V,
+    S: Fn(V::Element, V::Element) -> V::Element,
+{
+    let r = vector_op(a, b);
+    for i in 0..V::LANES {
+        let e = scalar_op(a.extract(i), b.extract(i));
+        if !lane_eq(r.extract(i), e) {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use coresimd::simd::*;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn arithmetic_ops() {
+        fn add(a: i32x4, b: i32x4) -> bool {
+            let s = |a: i32, b: i32| a.wrapping_add(b);
+            check_binary_lanewise(a, b, |a, b| a + b, s)
+        }
+        fn mul(a: u8x16, b: u8x16) -> bool {
+            let s = |a: u8, b: u8| a.wrapping_mul(b);
+            check_binary_lanewise(a, b, |a, b| a * b, s)
+        }
+        fn sub(a: f32x8, b: f32x8) -> bool {
+            check_binary_lanewise(a, b, |a, b| a - b, |a: f32, b| a - b)
+        }
+        fn div(a: f64x2, b: f64x2) -> bool {
+            check_binary_lanewise(a, b, |a, b| a / b, |a: f64, b| a / b)
+        }
+        fn neg(a: i64x2) -> bool {
+            check_unary_lanewise(a, |a| -a, |a: i64| a.wrapping_neg())
+        }
+        quickcheck(add as fn(i32x4, i32x4) -> bool);
+        quickcheck(mul as fn(u8x16, u8x16) -> bool);
+        quickcheck(sub as fn(f32x8, f32x8) -> bool);
+        quickcheck(div as fn(f64x2, f64x2) -> bool);
+        quickcheck(neg as fn(i64x2) -> bool);
+    }
+
+    #[test]
+    fn arbitrary_specials() {
+        use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
+        static NAN: AtomicBool = ATOMIC_BOOL_INIT;
+        static NEG_ZERO: AtomicBool = ATOMIC_BOOL_INIT;
+        static MIN: AtomicBool = ATOMIC_BOOL_INIT;
+
+        fn specials(f: f32x4, i: i16x8) -> bool {
+            for j in 0..4 {
+                let x = f.extract(j);
+                if x.is_nan() {
+                    NAN.store(true, Ordering::Relaxed);
+                }
+                if x == 0. && x.is_sign_negative() {
+                    NEG_ZERO.store(true, Ordering::Relaxed);
+                }
+            }
+            for j in 0..8 {
+                if i.extract(j) == i16::min_value() {
+                    MIN.store(true, Ordering::Relaxed);
+                }
+            }
+            true
+        }
+        quickcheck(specials as fn(f32x4, i16x8) -> bool);
+        assert!(NAN.load(Ordering::Relaxed));
+        assert!(NEG_ZERO.load(Ordering::Relaxed));
+        assert!(MIN.load(Ordering::Relaxed));
+    }
+}
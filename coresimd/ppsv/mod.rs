@@ -13,7 +13,15 @@
 //! This module is structured as follows:
 //!
 //! * `api`: defines the API of the portable packed vector types.
+//! * `divisor`: defines the precomputed divisors of unsigned integer
+//!   vectors.
 //! * `v{width}`: defines the portable vector types for a particular `width`.
+//! * `vptr`: defines the portable vectors of pointers.
+//! * `traits`: defines traits abstracting over the portable vector types.
+//! * `native`: aliases to the portable vector types of the widest width
+//!   natively supported by the target.
+//! * `lanewise` (with the `quickcheck` feature): helpers to check lane-wise
+//!   vector operations against scalar reference implementations.
 //!
 //! The portable packed vector types are named using the following schema:
 //! `{t}{l_w}x{l_n}`:
@@ -29,24 +37,46 @@
 //! For example, `f32x4` is a vector type containing four 32-bit wide
 //! floating-point numbers. The total width of this type is 32 bit times 4
 //! lanes, that is, 128 bits, and is thus defined in the `v128` module.
+//!
+//! Vectors with 128-bit wide integer lanes (e.g. `u128x2`) are also provided,
+//! and their boolean vectors use 8-bit wide lanes (e.g. `b8x2`). The
+//! single-lane boolean vector `b8x1` is defined in the `v8` module.
 
 #[macro_use]
 mod api;
 mod codegen;
+mod divisor;
 
+mod v8;
 mod v16;
 mod v32;
 mod v64;
 mod v128;
 mod v256;
 mod v512;
+mod vptr;
+
+mod traits;
+
+#[cfg(feature = "quickcheck")]
+mod lanewise;
+
+pub mod native;
 
+pub use self::divisor::*;
+pub use self::v8::*;
 pub use self::v16::*;
 pub use self::v32::*;
 pub use self::v64::*;
 pub use self::v128::*;
 pub use self::v256::*;
 pub use self::v512::*;
+pub use self::vptr::*;
+
+pub use self::traits::*;
+
+#[cfg(feature = "quickcheck")]
+pub use self::lanewise::*;
 
 use marker;
 
@@ -80,3 +110,12 @@ impl<T> FromBits<T> for T {
         t
     }
 }
+
+/// An iterator over the lanes of a vector.
+///
+/// This `struct` is created by the `into_iter` method of the vector types.
+#[derive(Clone, Debug)]
+pub struct IntoIter<T> {
+    vec: T,
+    index: usize,
+}
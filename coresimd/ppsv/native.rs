@@ -0,0 +1,126 @@
+//! Portable vector types of the widest width natively supported by the
+//! target.
+//!
+//! The aliases in this module resolve, at compile-time, to the portable
+//! vector type that fills the widest vector register the enabled target
+//! features operate on efficiently:
+//!
+//! * 512-bit vectors: `avx512f` for 32- and 64-bit lanes, `avx512bw` for 8-
+//!   and 16-bit lanes,
+//! * 256-bit vectors: `avx` for floating-point lanes, `avx2` for integer
+//!   lanes,
+//! * 128-bit vectors: otherwise (e.g. `sse2`, `neon`, `simd128`, or targets
+//!   without SIMD support).
+//!
+//! This allows writing code that is agnostic of the vector width, using
+//! `LANES` to compute the number of elements processed per iteration:
+//!
+//! ```ignore
+//! use coresimd::simd::native::f32xN;
+//!
+//! fn sum(x: &[f32]) -> f32 {
+//!     assert_eq!(x.len() % f32xN::LANES, 0);
+//!     let mut acc = f32xN::splat(0.);
+//!     for chunk in x.chunks(f32xN::LANES) {
+//!         acc += f32xN::load_unaligned(chunk);
+//!     }
+//!     acc.sum()
+//! }
+//! ```
+#![allow(non_camel_case_types)]
+
+use super::*;
+
+/// Defines the alias `$id` as the widest of `$v512`, `$v256`, and `$v128`
+/// enabled by the target features `$f512` and `$f256`.
+macro_rules! native_ty {
+    ($id:ident: $v512:ident if $f512:tt,
+     $v256:ident if $f256:tt, $v128:ident) => {
+        /// Portable vector type of the widest width natively supported by
+        /// the target.
+        #[cfg(target_feature = $f512)]
+        pub type $id = $v512;
+        /// Portable vector type of the widest width natively supported by
+        /// the target.
+        #[cfg(all(not(target_feature = $f512), target_feature = $f256))]
+        pub type $id = $v256;
+        /// Portable vector type of the widest width natively supported by
+        /// the target.
+        #[cfg(not(any(target_feature = $f512, target_feature = $f256)))]
+        pub type $id = $v128;
+    }
+}
+
+native_ty!(f32xN: f32x16 if "avx512f", f32x8 if "avx", f32x4);
+native_ty!(f64xN: f64x8 if "avx512f", f64x4 if "avx", f64x2);
+
+native_ty!(i8xN: i8x64 if "avx512bw", i8x32 if "avx2", i8x16);
+native_ty!(u8xN: u8x64 if "avx512bw", u8x32 if "avx2", u8x16);
+native_ty!(i16xN: i16x32 if "avx512bw", i16x16 if "avx2", i16x8);
+native_ty!(u16xN: u16x32 if "avx512bw", u16x16 if "avx2", u16x8);
+native_ty!(i32xN: i32x16 if "avx512f", i32x8 if "avx2", i32x4);
+native_ty!(u32xN: u32x16 if "avx512f", u32x8 if "avx2", u32x4);
+native_ty!(i64xN: i64x8 if "avx512f", i64x4 if "avx2", i64x2);
+native_ty!(u64xN: u64x8 if "avx512f", u64x4 if "avx2", u64x2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem;
+
+    macro_rules! test_native_ty {
+        ($($id:ident: $elem_ty:ident),+) => {
+            $(
+                assert_eq!($id::LANES, $id::lanes());
+                assert_eq!(
+                    $id::LANES * mem::size_of::<$elem_ty>(),
+                    mem::size_of::<$id>()
+                );
+                let v = $id::splat(1 as $elem_ty);
+                assert_eq!(v.sum(), $id::LANES as $elem_ty);
+            )+
+        }
+    }
+
+    #[test]
+    fn native_lanes() {
+        test_native_ty!(
+            f32xN: f32, f64xN: f64,
+            i8xN: i8, u8xN: u8, i16xN: i16, u16xN: u16,
+            i32xN: i32, u32xN: u32, i64xN: i64, u64xN: u64
+        );
+    }
+
+    #[test]
+    fn native_width() {
+        let float_width = if cfg!(target_feature = "avx512f") {
+            64
+        } else if cfg!(target_feature = "avx") {
+            32
+        } else {
+            16
+        };
+        assert_eq!(mem::size_of::<f32xN>(), float_width);
+        assert_eq!(mem::size_of::<f64xN>(), float_width);
+
+        let int_width = if cfg!(target_feature = "avx512f") {
+            64
+        } else if cfg!(target_feature = "avx2") {
+            32
+        } else {
+            16
+        };
+        assert_eq!(mem::size_of::<i32xN>(), int_width);
+        assert_eq!(mem::size_of::<u64xN>(), int_width);
+
+        let byte_width = if cfg!(target_feature = "avx512bw") {
+            64
+        } else if cfg!(target_feature = "avx2") {
+            32
+        } else {
+            16
+        };
+        assert_eq!(mem::size_of::<u8xN>(), byte_width);
+        assert_eq!(mem::size_of::<i16xN>(), byte_width);
+    }
+}
@@ -0,0 +1,262 @@
+//! Traits abstracting over the portable packed vector types.
+//!
+//! These traits allow writing code that is generic over, for example,
+//! `f32x4`, `f32x8`, and `f32x16`. They are sealed: they are implemented for
+//! all portable vector types of this crate, and cannot be implemented
+//! outside of it.
+//!
+//! * `Simd`: implemented by all vector types,
+//! * `SimdNum`: implemented by integer and floating-point vectors,
+//! * `SimdInt`: implemented by integer vectors,
+//! * `SimdFloat`: implemented by floating-point vectors,
+//! * `SimdBool`: implemented by boolean vectors.
+//!
+//! The lane-wise comparisons are named `lanes_eq`, `lanes_lt`, ..., and the
+//! reductions `max_element`/`min_element`, so that they do not collide with
+//! the methods of `PartialEq`, `PartialOrd`, and `Ord` in generic code.
+
+use default;
+use fmt;
+use iter;
+use marker;
+use ops;
+
+pub(crate) mod sealed {
+    /// Prevents the portable vector traits from being implemented outside
+    /// of this crate.
+    pub trait Sealed {}
+}
+
+/// A portable packed SIMD vector type.
+pub trait Simd:
+    sealed::Sealed
+    + marker::Copy
+    + fmt::Debug
+    + default::Default
+{
+    /// Type of the vector lanes.
+    type Element: marker::Copy;
+    /// Boolean vector type produced by lane-wise comparisons.
+    type Mask: SimdBool;
+    /// Number of vector lanes.
+    const LANES: usize;
+
+    /// Constructs a new instance with each element initialized to `value`.
+    fn splat(value: Self::Element) -> Self;
+
+    /// Extracts the value at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index >= Self::LANES`.
+    fn extract(self, index: usize) -> Self::Element;
+
+    /// Extracts the value at `index`.
+    ///
+    /// If `index >= Self::LANES` the behavior is undefined.
+    unsafe fn extract_unchecked(self, index: usize) -> Self::Element;
+
+    /// Returns a new vector where the value at `index` is replaced by
+    /// `new_value`.
+    ///
+    /// # Panics
+    ///
+    /// If `index >= Self::LANES`.
+    fn replace(self, index: usize, new_value: Self::Element) -> Self;
+
+    /// Returns a new vector where the value at `index` is replaced by
+    /// `new_value`.
+    ///
+    /// If `index >= Self::LANES` the behavior is undefined.
+    unsafe fn replace_unchecked(
+        self, index: usize, new_value: Self::Element
+    ) -> Self;
+
+    /// Lane-wise equality comparison.
+    fn lanes_eq(self, other: Self) -> Self::Mask;
+
+    /// Lane-wise inequality comparison.
+    fn lanes_ne(self, other: Self) -> Self::Mask;
+}
+
+/// A portable packed SIMD vector type with integer or floating-point lanes.
+pub trait SimdNum:
+    Simd
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+    + ops::Rem<Output = Self>
+    + ops::AddAssign
+    + ops::SubAssign
+    + ops::MulAssign
+    + ops::DivAssign
+    + ops::RemAssign
+    + iter::Sum
+    + iter::Product
+{
+    /// Instantiates a new vector with the values of the `slice`.
+    ///
+    /// # Panics
+    ///
+    /// If `slice.len() < Self::LANES` or `&slice[0]` is not aligned
+    /// to an `align_of::<Self>()` boundary.
+    fn load_aligned(slice: &[Self::Element]) -> Self;
+
+    /// Instantiates a new vector with the values of the `slice`.
+    ///
+    /// # Panics
+    ///
+    /// If `slice.len() < Self::LANES`.
+    fn load_unaligned(slice: &[Self::Element]) -> Self;
+
+    /// Instantiates a new vector with the values of the `slice`.
+    ///
+    /// # Precondition
+    ///
+    /// If `slice.len() < Self::LANES` or `&slice[0]` is not aligned
+    /// to an `align_of::<Self>()` boundary, the behavior is undefined.
+    unsafe fn load_aligned_unchecked(slice: &[Self::Element]) -> Self;
+
+    /// Instantiates a new vector with the values of the `slice`.
+    ///
+    /// # Precondition
+    ///
+    /// If `slice.len() < Self::LANES` the behavior is undefined.
+    unsafe fn load_unaligned_unchecked(slice: &[Self::Element]) -> Self;
+
+    /// Writes the values of the vector to the `slice`.
+    ///
+    /// # Panics
+    ///
+    /// If `slice.len() < Self::LANES` or `&slice[0]` is not
+    /// aligned to an `align_of::<Self>()` boundary.
+    fn store_aligned(self, slice: &mut [Self::Element]);
+
+    /// Writes the values of the vector to the `slice`.
+    ///
+    /// # Panics
+    ///
+    /// If `slice.len() < Self::LANES`.
+    fn store_unaligned(self, slice: &mut [Self::Element]);
+
+    /// Writes the values of the vector to the `slice`.
+    ///
+    /// # Precondition
+    ///
+    /// If `slice.len() < Self::LANES` or `&slice[0]` is not
+    /// aligned to an `align_of::<Self>()` boundary, the behavior is
+    /// undefined.
+    unsafe fn store_aligned_unchecked(self, slice: &mut [Self::Element]);
+
+    /// Writes the values of the vector to the `slice`.
+    ///
+    /// # Precondition
+    ///
+    /// If `slice.len() < Self::LANES` the behavior is undefined.
+    unsafe fn store_unaligned_unchecked(self, slice: &mut [Self::Element]);
+
+    /// Lane-wise addition of the vector elements.
+    fn sum(self) -> Self::Element;
+
+    /// Lane-wise multiplication of the vector elements.
+    fn product(self) -> Self::Element;
+
+    /// Largest vector value.
+    fn max_element(self) -> Self::Element;
+
+    /// Smallest vector value.
+    fn min_element(self) -> Self::Element;
+
+    /// Lane-wise less-than comparison.
+    fn lanes_lt(self, other: Self) -> Self::Mask;
+
+    /// Lane-wise less-than-or-equals comparison.
+    fn lanes_le(self, other: Self) -> Self::Mask;
+
+    /// Lane-wise greater-than comparison.
+    fn lanes_gt(self, other: Self) -> Self::Mask;
+
+    /// Lane-wise greater-than-or-equals comparison.
+    fn lanes_ge(self, other: Self) -> Self::Mask;
+}
+
+/// A portable packed SIMD vector type with integer lanes.
+pub trait SimdInt:
+    SimdNum
+    + ops::Not<Output = Self>
+    + ops::BitAnd<Output = Self>
+    + ops::BitOr<Output = Self>
+    + ops::BitXor<Output = Self>
+    + ops::BitAndAssign
+    + ops::BitOrAssign
+    + ops::BitXorAssign
+    + ops::Shl<u32, Output = Self>
+    + ops::Shr<u32, Output = Self>
+    + ops::ShlAssign<u32>
+    + ops::ShrAssign<u32>
+{
+    /// Lane-wise bitwise `and` of the vector elements.
+    fn and(self) -> Self::Element;
+
+    /// Lane-wise bitwise `or` of the vector elements.
+    fn or(self) -> Self::Element;
+
+    /// Lane-wise bitwise `xor` of the vector elements.
+    fn xor(self) -> Self::Element;
+}
+
+/// A portable packed SIMD vector type with floating-point lanes.
+pub trait SimdFloat: SimdNum + ops::Neg<Output = Self> {
+    /// Lane-wise absolute value.
+    fn abs(self) -> Self;
+
+    /// Lane-wise square root.
+    fn sqrt(self) -> Self;
+
+    /// Lane-wise approximation of the reciprocal `1 / self`.
+    fn recip_approx(self) -> Self;
+
+    /// Lane-wise approximation of the reciprocal square root
+    /// `1 / self.sqrt()`.
+    fn rsqrt_approx(self) -> Self;
+
+    /// Lane-wise test for `NaN`.
+    fn is_nan(self) -> Self::Mask;
+
+    /// Lane-wise test for positive or negative infinity.
+    fn is_infinite(self) -> Self::Mask;
+
+    /// Lane-wise test for neither infinite nor `NaN`.
+    fn is_finite(self) -> Self::Mask;
+}
+
+/// A portable packed SIMD vector type with boolean lanes.
+pub trait SimdBool:
+    Simd<Element = bool>
+    + ops::Not<Output = Self>
+    + ops::BitAnd<Output = Self>
+    + ops::BitOr<Output = Self>
+    + ops::BitXor<Output = Self>
+    + ops::BitAndAssign
+    + ops::BitOrAssign
+    + ops::BitXorAssign
+{
+    /// Are `all` vector lanes `true`?
+    fn all(self) -> bool;
+
+    /// Is `any` vector lanes `true`?
+    fn any(self) -> bool;
+
+    /// Are `all` vector lanes `false`?
+    fn none(self) -> bool;
+
+    /// Lane-wise bitwise `and` of the vector elements.
+    fn and(self) -> bool;
+
+    /// Lane-wise bitwise `or` of the vector elements.
+    fn or(self) -> bool;
+
+    /// Lane-wise bitwise `xor` of the vector elements.
+    fn xor(self) -> bool;
+}
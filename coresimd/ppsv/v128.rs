@@ -2,7 +2,10 @@
 
 simd_api_imports!();
 
-use coresimd::simd::{b8x2, b8x4, b8x8};
+use coresimd::simd::{
+    b8x1, b8x2, b8x4, b8x8, f32x2, f32x8, i16x4, i32x2, i8x8, u16x4, u32x2,
+    u32x8, u8x8,
+};
 
 simd_i_ty! {
     i8x16: 16, i8, b8x16, i8x16_tests |
@@ -53,6 +56,20 @@ simd_u_ty! {
     /// A 128-bit vector with 4 `u32` lanes.
 }
 
+simd_i_ty! {
+    i128x1: 1, i128, b8x1, i128x1_tests |
+    i128 |
+    x0 |
+    /// A 128-bit vector with 1 `i128` lane.
+}
+
+simd_u_ty! {
+    u128x1: 1, u128, b8x1, u128x1_tests |
+    u128 |
+    x0 |
+    /// A 128-bit vector with 1 `u128` lane.
+}
+
 simd_f_ty! {
     f32x4: 4, f32, b8x4, f32x4_tests |
     f32, f32, f32, f32 |
@@ -81,6 +98,101 @@ simd_f_ty! {
     /// A 128-bit vector with 2 `f64` lanes.
 }
 
+simd_f16_ty! {
+    f16x8: 8, f32x8, u16x8, f16x8_tests |
+    u16, u16, u16, u16, u16, u16, u16, u16 |
+    /// A 128-bit vector with 8 half-precision floating-point lanes.
+}
+
+simd_bf16_ty! {
+    bf16x8: 8, f32x8, u16x8, u32x8, f32x4, bf16x8_tests |
+    u16, u16, u16, u16, u16, u16, u16, u16 |
+    /// A 128-bit vector with 8 `bfloat16` floating-point lanes.
+}
+
+impl_float_class!(f32x4: f32, i32x4, b8x4, f32x4_class_tests);
+impl_float_class!(f64x2: f64, i64x2, b8x2, f64x2_class_tests);
+impl_minmax!(f32x4, i32x4);
+impl_minmax!(f64x2, i64x2);
+
+impl_sum_widening!(i8x16: i8, i32, i8x16_sum_widening_tests);
+impl_sum_widening!(u8x16: u8, u32, u8x16_sum_widening_tests);
+impl_sum_widening!(i16x8: i16, i64, i16x8_sum_widening_tests);
+impl_sum_widening!(u16x8: u16, u64, u16x8_sum_widening_tests);
+impl_sum_widening!(i32x4: i32, i64, i32x4_sum_widening_tests);
+impl_sum_widening!(u32x4: u32, u64, u32x4_sum_widening_tests);
+impl_sum_pairs_widening!(i8x16: i8, i16x8, i8x16_sum_pairs_tests);
+impl_sum_pairs_widening!(u8x16: u8, u16x8, u8x16_sum_pairs_tests);
+impl_sum_pairs_widening!(i16x8: i16, i32x4, i16x8_sum_pairs_tests);
+impl_sum_pairs_widening!(u16x8: u16, u32x4, u16x8_sum_pairs_tests);
+impl_sum_pairs_widening!(i32x4: i32, i64x2, i32x4_sum_pairs_tests);
+impl_sum_pairs_widening!(u32x4: u32, u64x2, u32x4_sum_pairs_tests);
+
+impl_sort_lanes!(i8x16: 16, i8x8, i8, i8x16_sort_tests);
+impl_sort_lanes!(u8x16: 16, u8x8, u8, u8x16_sort_tests);
+impl_sort_lanes!(i16x8: 8, i16x4, i16, i16x8_sort_tests);
+impl_sort_lanes!(u16x8: 8, u16x4, u16, u16x8_sort_tests);
+impl_sort_lanes!(i32x4: 4, i32x2, i32, i32x4_sort_tests);
+impl_sort_lanes!(u32x4: 4, u32x2, u32, u32x4_sort_tests);
+impl_sort_lanes!(f32x4: 4, f32x2, f32, f32x4_sort_tests);
+impl_sort_lanes!(i64x2: 2, i64, i64x2_sort_tests);
+impl_sort_lanes!(u64x2: 2, u64, u64x2_sort_tests);
+impl_sort_lanes!(f64x2: 2, f64, f64x2_sort_tests);
+impl_sort_lanes!(i128x1: 1, i128, i128x1_sort_tests);
+impl_sort_lanes!(u128x1: 1, u128, u128x1_sort_tests);
+
+impl_int_scan!(i8x16: 16, i8, i8x16_scan_tests);
+impl_int_scan!(u8x16: 16, u8, u8x16_scan_tests);
+impl_int_scan!(i16x8: 8, i16, i16x8_scan_tests);
+impl_int_scan!(u16x8: 8, u16, u16x8_scan_tests);
+impl_int_scan!(i32x4: 4, i32, i32x4_scan_tests);
+impl_int_scan!(u32x4: 4, u32, u32x4_scan_tests);
+impl_float_scan!(f32x4: 4, f32, f32x4_scan_tests);
+impl_int_scan!(i64x2: 2, i64, i64x2_scan_tests);
+impl_int_scan!(u64x2: 2, u64, u64x2_scan_tests);
+impl_float_scan!(f64x2: 2, f64, f64x2_scan_tests);
+impl_int_scan!(i128x1: 1, i128, i128x1_scan_tests);
+impl_int_scan!(u128x1: 1, u128, u128x1_scan_tests);
+
+impl_slide!(i8x16: 16, i8, i8x16_slide_tests);
+impl_slide!(u8x16: 16, u8, u8x16_slide_tests);
+impl_slide!(i16x8: 8, i16, i16x8_slide_tests);
+impl_slide!(u16x8: 8, u16, u16x8_slide_tests);
+impl_slide!(i32x4: 4, i32, i32x4_slide_tests);
+impl_slide!(u32x4: 4, u32, u32x4_slide_tests);
+impl_slide!(f32x4: 4, f32, f32x4_slide_tests);
+impl_slide!(i64x2: 2, i64, i64x2_slide_tests);
+impl_slide!(u64x2: 2, u64, u64x2_slide_tests);
+impl_slide!(f64x2: 2, f64, f64x2_slide_tests);
+impl_slide!(i128x1: 1, i128, i128x1_slide_tests);
+impl_slide!(u128x1: 1, u128, u128x1_slide_tests);
+impl_slide!(b8x16: 16, bool, b8x16_slide_tests);
+
+impl_signed_int_math!(i8x16: i8, u8x16, i8x16_int_math_tests);
+impl_unsigned_int_math!(u8x16: u8, u8x16_int_math_tests);
+impl_signed_int_math!(i16x8: i16, u16x8, i16x8_int_math_tests);
+impl_unsigned_int_math!(u16x8: u16, u16x8_int_math_tests);
+impl_signed_int_math!(i32x4: i32, u32x4, i32x4_int_math_tests);
+impl_unsigned_int_math!(u32x4: u32, u32x4_int_math_tests);
+impl_signed_int_math!(i64x2: i64, u64x2, i64x2_int_math_tests);
+impl_unsigned_int_math!(u64x2: u64, u64x2_int_math_tests);
+impl_signed_int_math!(i128x1: i128, u128x1, i128x1_int_math_tests);
+impl_unsigned_int_math!(u128x1: u128, u128x1_int_math_tests);
+
+impl_endian!(i8x16: 1, i8, u8x16, 16, i8x16_endian_tests);
+impl_endian!(u8x16: 1, u8, u8x16, 16, u8x16_endian_tests);
+impl_endian!(i16x8: 2, i16, u8x16, 16, i16x8_endian_tests);
+impl_endian!(u16x8: 2, u16, u8x16, 16, u16x8_endian_tests);
+impl_endian!(i32x4: 4, i32, u8x16, 16, i32x4_endian_tests);
+impl_endian!(u32x4: 4, u32, u8x16, 16, u32x4_endian_tests);
+impl_endian!(i64x2: 8, i64, u8x16, 16, i64x2_endian_tests);
+impl_endian!(u64x2: 8, u64, u8x16, 16, u64x2_endian_tests);
+impl_endian!(i128x1: 16, i128, u8x16, 16, i128x1_endian_tests);
+impl_endian!(u128x1: 16, u128, u8x16, 16, u128x1_endian_tests);
+
+impl_complex!(f32x4: 4, f32, f32x4_complex_tests);
+impl_complex!(f64x2: 2, f64, f64x2_complex_tests);
+
 impl_from_bits!(
     u64x2: u64,
     u64x2_from_bits | i64x2,
@@ -92,7 +204,9 @@ impl_from_bits!(
     i16x8,
     u8x16,
     i8x16,
-    b8x16
+    b8x16,
+    i128x1,
+    u128x1
 );
 impl_from_bits!(
     i64x2: i64,
@@ -105,7 +219,9 @@ impl_from_bits!(
     i16x8,
     u8x16,
     i8x16,
-    b8x16
+    b8x16,
+    i128x1,
+    u128x1
 );
 impl_from_bits!(
     f64x2: f64,
@@ -118,7 +234,9 @@ impl_from_bits!(
     i16x8,
     u8x16,
     i8x16,
-    b8x16
+    b8x16,
+    i128x1,
+    u128x1
 );
 impl_from_bits!(
     u32x4: u32,
@@ -131,7 +249,9 @@ impl_from_bits!(
     i16x8,
     u8x16,
     i8x16,
-    b8x16
+    b8x16,
+    i128x1,
+    u128x1
 );
 impl_from_bits!(
     i32x4: i32,
@@ -144,7 +264,9 @@ impl_from_bits!(
     i16x8,
     u8x16,
     i8x16,
-    b8x16
+    b8x16,
+    i128x1,
+    u128x1
 );
 impl_from_bits!(
     f32x4: f32,
@@ -157,7 +279,9 @@ impl_from_bits!(
     i16x8,
     u8x16,
     i8x16,
-    b8x16
+    b8x16,
+    i128x1,
+    u128x1
 );
 impl_from_bits!(
     u16x8: u16,
@@ -170,7 +294,11 @@ impl_from_bits!(
     i16x8,
     u8x16,
     i8x16,
-    b8x16
+    b8x16,
+    f16x8,
+    bf16x8,
+    i128x1,
+    u128x1
 );
 impl_from_bits!(
     i16x8: i16,
@@ -183,7 +311,11 @@ impl_from_bits!(
     u16x8,
     u8x16,
     i8x16,
-    b8x16
+    b8x16,
+    f16x8,
+    bf16x8,
+    i128x1,
+    u128x1
 );
 impl_from_bits!(
     u8x16: u8,
@@ -196,7 +328,9 @@ impl_from_bits!(
     u16x8,
     i16x8,
     i8x16,
-    b8x16
+    b8x16,
+    i128x1,
+    u128x1
 );
 impl_from_bits!(
     i8x16: i8,
@@ -209,9 +343,44 @@ impl_from_bits!(
     u16x8,
     i16x8,
     u8x16,
-    b8x16
+    b8x16,
+    i128x1,
+    u128x1
+);
+impl_from_bits!(
+    i128x1: i128,
+    i128x1_from_bits | u64x2,
+    i64x2,
+    f64x2,
+    u32x4,
+    i32x4,
+    f32x4,
+    u16x8,
+    i16x8,
+    u8x16,
+    i8x16,
+    b8x16,
+    u128x1
+);
+impl_from_bits!(
+    u128x1: u128,
+    u128x1_from_bits | u64x2,
+    i64x2,
+    f64x2,
+    u32x4,
+    i32x4,
+    f32x4,
+    u16x8,
+    i16x8,
+    u8x16,
+    i8x16,
+    b8x16,
+    i128x1
 );
 
+impl_from_bits_!(f16x8: u16x8, i16x8);
+impl_from_bits_!(bf16x8: u16x8, i16x8);
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use coresimd::x86::__m128;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -239,6 +408,14 @@ impl_from_bits_!(i16x8: __m128, __m128i, __m128d);
 impl_from_bits_!(u8x16: __m128, __m128i, __m128d);
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 impl_from_bits_!(i8x16: __m128, __m128i, __m128d);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_from_bits_!(i128x1: __m128, __m128i, __m128d);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_from_bits_!(u128x1: __m128, __m128i, __m128d);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_from_bits_!(f16x8: __m128, __m128i, __m128d);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_from_bits_!(bf16x8: __m128, __m128i, __m128d);
 
 impl_from!(
     f64x2: f64,
@@ -250,7 +427,9 @@ impl_from!(
     u16x2,
     i16x2,
     u8x2,
-    i8x2
+    i8x2,
+    i128x2,
+    u128x2
 );
 impl_from!(
     f32x4: f32,
@@ -262,7 +441,9 @@ impl_from!(
     u16x4,
     i16x4,
     u8x4,
-    i8x4
+    i8x4,
+    i128x4,
+    u128x4
 );
 impl_from!(
     u64x2: u64,
@@ -274,7 +455,9 @@ impl_from!(
     i16x2,
     u16x2,
     i8x2,
-    u8x2
+    u8x2,
+    i128x2,
+    u128x2
 );
 impl_from!(
     i64x2: i64,
@@ -286,7 +469,9 @@ impl_from!(
     i16x2,
     u16x2,
     i8x2,
-    u8x2
+    u8x2,
+    i128x2,
+    u128x2
 );
 impl_from!(
     u32x4: u32,
@@ -298,7 +483,9 @@ impl_from!(
     u16x4,
     i16x4,
     u8x4,
-    i8x4
+    i8x4,
+    i128x4,
+    u128x4
 );
 impl_from!(
     i32x4: i32,
@@ -310,7 +497,9 @@ impl_from!(
     u16x4,
     i16x4,
     u8x4,
-    i8x4
+    i8x4,
+    i128x4,
+    u128x4
 );
 impl_from!(
     i16x8: i16,
@@ -336,3 +525,6 @@ impl_from!(
     u8x8,
     i8x8
 );
+
+impl_from!(i128x1: i128, i128x1_from | u128x1);
+impl_from!(u128x1: u128, u128x1_from | i128x1);
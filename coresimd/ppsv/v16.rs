@@ -23,6 +23,25 @@ simd_b_ty! {
     /// A 16-bit wide vector with 2 `bool` lanes.
 }
 
+impl_sum_widening!(i8x2: i8, i32, i8x2_sum_widening_tests);
+impl_sum_widening!(u8x2: u8, u32, u8x2_sum_widening_tests);
+
+impl_sort_lanes!(i8x2: 2, i8, i8x2_sort_tests);
+impl_sort_lanes!(u8x2: 2, u8, u8x2_sort_tests);
+
+impl_int_scan!(i8x2: 2, i8, i8x2_scan_tests);
+impl_int_scan!(u8x2: 2, u8, u8x2_scan_tests);
+
+impl_slide!(i8x2: 2, i8, i8x2_slide_tests);
+impl_slide!(u8x2: 2, u8, u8x2_slide_tests);
+impl_slide!(b8x2: 2, bool, b8x2_slide_tests);
+
+impl_signed_int_math!(i8x2: i8, u8x2, i8x2_int_math_tests);
+impl_unsigned_int_math!(u8x2: u8, u8x2_int_math_tests);
+
+impl_endian!(i8x2: 1, i8, u8x2, 2, i8x2_endian_tests);
+impl_endian!(u8x2: 1, u8, u8x2, 2, u8x2_endian_tests);
+
 impl_from_bits!(i8x2: i8, i8x2_from_bits | u8x2, b8x2);
 impl_from_bits!(u8x2: u8, u8x2_from_bits | i8x2, b8x2);
 
@@ -35,7 +54,9 @@ impl_from!(
     u32x2,
     i32x2,
     u16x2,
-    u8x2
+    u8x2,
+    i128x2,
+    u128x2
 );
 impl_from!(
     u8x2: u8,
@@ -46,5 +67,7 @@ impl_from!(
     u32x2,
     i32x2,
     u16x2,
-    i8x2
+    i8x2,
+    i128x2,
+    u128x2
 );
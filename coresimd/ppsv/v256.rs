@@ -2,7 +2,10 @@
 
 simd_api_imports!();
 
-use coresimd::simd::{b8x16, b8x4, b8x8};
+use coresimd::simd::{
+    b8x16, b8x2, b8x4, b8x8, f32x16, f32x4, f64x2, i16x8, i32x4, i64x2, i8x16,
+    u16x8, u32x16, u32x4, u64x2, u8x16,
+};
 
 simd_i_ty! {
     i8x32: 32, i8, b8x32, i8x32_tests |
@@ -69,6 +72,20 @@ simd_u_ty! {
     /// A 256-bit vector with 8 `u32` lanes.
 }
 
+simd_i_ty! {
+    i128x2: 2, i128, b8x2, i128x2_tests |
+    i128, i128 |
+    x0, x1 |
+    /// A 256-bit vector with 2 `i128` lanes.
+}
+
+simd_u_ty! {
+    u128x2: 2, u128, b8x2, u128x2_tests |
+    u128, u128 |
+    x0, x1 |
+    /// A 256-bit vector with 2 `u128` lanes.
+}
+
 simd_f_ty! {
     f32x8: 8, f32, b8x8, f32x8_tests |
     f32, f32, f32, f32, f32, f32, f32, f32 |
@@ -97,6 +114,103 @@ simd_f_ty! {
     /// A 256-bit vector with 4 `f64` lanes.
 }
 
+simd_f16_ty! {
+    f16x16: 16, f32x16, u16x16, f16x16_tests |
+    u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16,
+    u16, u16 |
+    /// A 256-bit vector with 16 half-precision floating-point lanes.
+}
+
+simd_bf16_ty! {
+    bf16x16: 16, f32x16, u16x16, u32x16, f32x8, bf16x16_tests |
+    u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16,
+    u16, u16 |
+    /// A 256-bit vector with 16 `bfloat16` floating-point lanes.
+}
+
+impl_float_class!(f32x8: f32, i32x8, b8x8, f32x8_class_tests);
+impl_float_class!(f64x4: f64, i64x4, b8x4, f64x4_class_tests);
+impl_minmax!(f32x8, i32x8);
+impl_minmax!(f64x4, i64x4);
+
+impl_sum_widening!(i8x32: i8, i32, i8x32_sum_widening_tests);
+impl_sum_widening!(u8x32: u8, u32, u8x32_sum_widening_tests);
+impl_sum_widening!(i16x16: i16, i64, i16x16_sum_widening_tests);
+impl_sum_widening!(u16x16: u16, u64, u16x16_sum_widening_tests);
+impl_sum_widening!(i32x8: i32, i64, i32x8_sum_widening_tests);
+impl_sum_widening!(u32x8: u32, u64, u32x8_sum_widening_tests);
+impl_sum_pairs_widening!(i8x32: i8, i16x16, i8x32_sum_pairs_tests);
+impl_sum_pairs_widening!(u8x32: u8, u16x16, u8x32_sum_pairs_tests);
+impl_sum_pairs_widening!(i16x16: i16, i32x8, i16x16_sum_pairs_tests);
+impl_sum_pairs_widening!(u16x16: u16, u32x8, u16x16_sum_pairs_tests);
+impl_sum_pairs_widening!(i32x8: i32, i64x4, i32x8_sum_pairs_tests);
+impl_sum_pairs_widening!(u32x8: u32, u64x4, u32x8_sum_pairs_tests);
+
+impl_sort_lanes!(i8x32: 32, i8x16, i8, i8x32_sort_tests);
+impl_sort_lanes!(u8x32: 32, u8x16, u8, u8x32_sort_tests);
+impl_sort_lanes!(i16x16: 16, i16x8, i16, i16x16_sort_tests);
+impl_sort_lanes!(u16x16: 16, u16x8, u16, u16x16_sort_tests);
+impl_sort_lanes!(i32x8: 8, i32x4, i32, i32x8_sort_tests);
+impl_sort_lanes!(u32x8: 8, u32x4, u32, u32x8_sort_tests);
+impl_sort_lanes!(f32x8: 8, f32x4, f32, f32x8_sort_tests);
+impl_sort_lanes!(i64x4: 4, i64x2, i64, i64x4_sort_tests);
+impl_sort_lanes!(u64x4: 4, u64x2, u64, u64x4_sort_tests);
+impl_sort_lanes!(f64x4: 4, f64x2, f64, f64x4_sort_tests);
+impl_sort_lanes!(i128x2: 2, i128, i128x2_sort_tests);
+impl_sort_lanes!(u128x2: 2, u128, u128x2_sort_tests);
+
+impl_int_scan!(i8x32: 32, i8, i8x32_scan_tests);
+impl_int_scan!(u8x32: 32, u8, u8x32_scan_tests);
+impl_int_scan!(i16x16: 16, i16, i16x16_scan_tests);
+impl_int_scan!(u16x16: 16, u16, u16x16_scan_tests);
+impl_int_scan!(i32x8: 8, i32, i32x8_scan_tests);
+impl_int_scan!(u32x8: 8, u32, u32x8_scan_tests);
+impl_float_scan!(f32x8: 8, f32, f32x8_scan_tests);
+impl_int_scan!(i64x4: 4, i64, i64x4_scan_tests);
+impl_int_scan!(u64x4: 4, u64, u64x4_scan_tests);
+impl_float_scan!(f64x4: 4, f64, f64x4_scan_tests);
+impl_int_scan!(i128x2: 2, i128, i128x2_scan_tests);
+impl_int_scan!(u128x2: 2, u128, u128x2_scan_tests);
+
+impl_slide!(i8x32: 32, i8, i8x32_slide_tests);
+impl_slide!(u8x32: 32, u8, u8x32_slide_tests);
+impl_slide!(i16x16: 16, i16, i16x16_slide_tests);
+impl_slide!(u16x16: 16, u16, u16x16_slide_tests);
+impl_slide!(i32x8: 8, i32, i32x8_slide_tests);
+impl_slide!(u32x8: 8, u32, u32x8_slide_tests);
+impl_slide!(f32x8: 8, f32, f32x8_slide_tests);
+impl_slide!(i64x4: 4, i64, i64x4_slide_tests);
+impl_slide!(u64x4: 4, u64, u64x4_slide_tests);
+impl_slide!(f64x4: 4, f64, f64x4_slide_tests);
+impl_slide!(i128x2: 2, i128, i128x2_slide_tests);
+impl_slide!(u128x2: 2, u128, u128x2_slide_tests);
+impl_slide!(b8x32: 32, bool, b8x32_slide_tests);
+
+impl_signed_int_math!(i8x32: i8, u8x32, i8x32_int_math_tests);
+impl_unsigned_int_math!(u8x32: u8, u8x32_int_math_tests);
+impl_signed_int_math!(i16x16: i16, u16x16, i16x16_int_math_tests);
+impl_unsigned_int_math!(u16x16: u16, u16x16_int_math_tests);
+impl_signed_int_math!(i32x8: i32, u32x8, i32x8_int_math_tests);
+impl_unsigned_int_math!(u32x8: u32, u32x8_int_math_tests);
+impl_signed_int_math!(i64x4: i64, u64x4, i64x4_int_math_tests);
+impl_unsigned_int_math!(u64x4: u64, u64x4_int_math_tests);
+impl_signed_int_math!(i128x2: i128, u128x2, i128x2_int_math_tests);
+impl_unsigned_int_math!(u128x2: u128, u128x2_int_math_tests);
+
+impl_endian!(i8x32: 1, i8, u8x32, 32, i8x32_endian_tests);
+impl_endian!(u8x32: 1, u8, u8x32, 32, u8x32_endian_tests);
+impl_endian!(i16x16: 2, i16, u8x32, 32, i16x16_endian_tests);
+impl_endian!(u16x16: 2, u16, u8x32, 32, u16x16_endian_tests);
+impl_endian!(i32x8: 4, i32, u8x32, 32, i32x8_endian_tests);
+impl_endian!(u32x8: 4, u32, u8x32, 32, u32x8_endian_tests);
+impl_endian!(i64x4: 8, i64, u8x32, 32, i64x4_endian_tests);
+impl_endian!(u64x4: 8, u64, u8x32, 32, u64x4_endian_tests);
+impl_endian!(i128x2: 16, i128, u8x32, 32, i128x2_endian_tests);
+impl_endian!(u128x2: 16, u128, u8x32, 32, u128x2_endian_tests);
+
+impl_complex!(f32x8: 8, f32, f32x8_complex_tests);
+impl_complex!(f64x4: 4, f64, f64x4_complex_tests);
+
 impl_from_bits!(
     i8x32: i8,
     i8x32_from_bits | u64x4,
@@ -108,7 +222,9 @@ impl_from_bits!(
     u16x16,
     i16x16,
     u8x32,
-    b8x32
+    b8x32,
+    i128x2,
+    u128x2
 );
 impl_from_bits!(
     u8x32: u8,
@@ -121,7 +237,9 @@ impl_from_bits!(
     u16x16,
     i16x16,
     i8x32,
-    b8x32
+    b8x32,
+    i128x2,
+    u128x2
 );
 impl_from_bits!(
     i16x16: i16,
@@ -134,7 +252,11 @@ impl_from_bits!(
     u16x16,
     u8x32,
     i8x32,
-    b8x32
+    b8x32,
+    f16x16,
+    bf16x16,
+    i128x2,
+    u128x2
 );
 impl_from_bits!(
     u16x16: u16,
@@ -147,7 +269,11 @@ impl_from_bits!(
     i16x16,
     u8x32,
     i8x32,
-    b8x32
+    b8x32,
+    f16x16,
+    bf16x16,
+    i128x2,
+    u128x2
 );
 impl_from_bits!(
     i32x8: i32,
@@ -160,7 +286,9 @@ impl_from_bits!(
     i16x16,
     u8x32,
     i8x32,
-    b8x32
+    b8x32,
+    i128x2,
+    u128x2
 );
 impl_from_bits!(
     u32x8: u32,
@@ -173,7 +301,9 @@ impl_from_bits!(
     i16x16,
     u8x32,
     i8x32,
-    b8x32
+    b8x32,
+    i128x2,
+    u128x2
 );
 impl_from_bits!(
     f32x8: f32,
@@ -186,7 +316,9 @@ impl_from_bits!(
     i16x16,
     u8x32,
     i8x32,
-    b8x32
+    b8x32,
+    i128x2,
+    u128x2
 );
 impl_from_bits!(
     i64x4: i64,
@@ -199,7 +331,9 @@ impl_from_bits!(
     i16x16,
     u8x32,
     i8x32,
-    b8x32
+    b8x32,
+    i128x2,
+    u128x2
 );
 impl_from_bits!(
     u64x4: u64,
@@ -212,7 +346,9 @@ impl_from_bits!(
     i16x16,
     u8x32,
     i8x32,
-    b8x32
+    b8x32,
+    i128x2,
+    u128x2
 );
 impl_from_bits!(
     f64x4: f64,
@@ -225,8 +361,43 @@ impl_from_bits!(
     i16x16,
     u8x32,
     i8x32,
-    b8x32
+    b8x32,
+    i128x2,
+    u128x2
+);
+impl_from_bits!(
+    i128x2: i128,
+    i128x2_from_bits | i8x32,
+    u64x4,
+    i64x4,
+    f64x4,
+    u32x8,
+    i32x8,
+    f32x8,
+    u16x16,
+    i16x16,
+    u8x32,
+    b8x32,
+    u128x2
 );
+impl_from_bits!(
+    u128x2: u128,
+    u128x2_from_bits | i8x32,
+    u64x4,
+    i64x4,
+    f64x4,
+    u32x8,
+    i32x8,
+    f32x8,
+    u16x16,
+    i16x16,
+    u8x32,
+    b8x32,
+    i128x2
+);
+
+impl_from_bits_!(f16x16: u16x16, i16x16);
+impl_from_bits_!(bf16x16: u16x16, i16x16);
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use coresimd::x86::__m256;
@@ -255,6 +426,14 @@ impl_from_bits_!(i16x16: __m256, __m256i, __m256d);
 impl_from_bits_!(u8x32: __m256, __m256i, __m256d);
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 impl_from_bits_!(i8x32: __m256, __m256i, __m256d);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_from_bits_!(i128x2: __m256, __m256i, __m256d);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_from_bits_!(u128x2: __m256, __m256i, __m256d);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_from_bits_!(f16x16: __m256, __m256i, __m256d);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_from_bits_!(bf16x16: __m256, __m256i, __m256d);
 
 impl_from!(
     f64x4: f64,
@@ -266,7 +445,9 @@ impl_from!(
     u16x4,
     i16x4,
     u8x4,
-    i8x4
+    i8x4,
+    i128x4,
+    u128x4
 );
 impl_from!(
     i64x4: i64,
@@ -278,7 +459,9 @@ impl_from!(
     u16x4,
     i16x4,
     u8x4,
-    i8x4
+    i8x4,
+    i128x4,
+    u128x4
 );
 impl_from!(
     u64x4: u64,
@@ -290,7 +473,9 @@ impl_from!(
     u16x4,
     i16x4,
     u8x4,
-    i8x4
+    i8x4,
+    i128x4,
+    u128x4
 );
 impl_from!(
     f32x8: f32,
@@ -348,3 +533,32 @@ impl_from!(
 );
 impl_from!(i8x32: i8, i8x32_from | u16x32, i16x32, u8x32);
 impl_from!(u8x32: u8, u8x32_from | u16x32, i16x32, i8x32);
+
+impl_from!(
+    i128x2: i128,
+    i128x2_from | i8x2,
+    u8x2,
+    i16x2,
+    u16x2,
+    f32x2,
+    u32x2,
+    i32x2,
+    f64x2,
+    u64x2,
+    i64x2,
+    u128x2
+);
+impl_from!(
+    u128x2: u128,
+    u128x2_from | i8x2,
+    u8x2,
+    i16x2,
+    u16x2,
+    f32x2,
+    u32x2,
+    i32x2,
+    f64x2,
+    u64x2,
+    i64x2,
+    i128x2
+);
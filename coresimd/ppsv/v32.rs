@@ -1,7 +1,7 @@
 //! 32-bit wide portable packed vector types.
 
 simd_api_imports!();
-use coresimd::simd::b8x2;
+use coresimd::simd::{b8x2, i8x2, u8x2};
 
 simd_i_ty! {
     i16x2: 2, i16, b8x2, i16x2_tests |
@@ -38,6 +38,39 @@ simd_b_ty! {
     /// A 32-bit wide vector with 4 `bool` lanes.
 }
 
+impl_sum_widening!(i8x4: i8, i32, i8x4_sum_widening_tests);
+impl_sum_widening!(u8x4: u8, u32, u8x4_sum_widening_tests);
+impl_sum_widening!(i16x2: i16, i64, i16x2_sum_widening_tests);
+impl_sum_widening!(u16x2: u16, u64, u16x2_sum_widening_tests);
+impl_sum_pairs_widening!(i8x4: i8, i16x2, i8x4_sum_pairs_tests);
+impl_sum_pairs_widening!(u8x4: u8, u16x2, u8x4_sum_pairs_tests);
+
+impl_sort_lanes!(i8x4: 4, i8x2, i8, i8x4_sort_tests);
+impl_sort_lanes!(u8x4: 4, u8x2, u8, u8x4_sort_tests);
+impl_sort_lanes!(i16x2: 2, i16, i16x2_sort_tests);
+impl_sort_lanes!(u16x2: 2, u16, u16x2_sort_tests);
+
+impl_int_scan!(i8x4: 4, i8, i8x4_scan_tests);
+impl_int_scan!(u8x4: 4, u8, u8x4_scan_tests);
+impl_int_scan!(i16x2: 2, i16, i16x2_scan_tests);
+impl_int_scan!(u16x2: 2, u16, u16x2_scan_tests);
+
+impl_slide!(i8x4: 4, i8, i8x4_slide_tests);
+impl_slide!(u8x4: 4, u8, u8x4_slide_tests);
+impl_slide!(i16x2: 2, i16, i16x2_slide_tests);
+impl_slide!(u16x2: 2, u16, u16x2_slide_tests);
+impl_slide!(b8x4: 4, bool, b8x4_slide_tests);
+
+impl_signed_int_math!(i8x4: i8, u8x4, i8x4_int_math_tests);
+impl_unsigned_int_math!(u8x4: u8, u8x4_int_math_tests);
+impl_signed_int_math!(i16x2: i16, u16x2, i16x2_int_math_tests);
+impl_unsigned_int_math!(u16x2: u16, u16x2_int_math_tests);
+
+impl_endian!(i8x4: 1, i8, u8x4, 4, i8x4_endian_tests);
+impl_endian!(u8x4: 1, u8, u8x4, 4, u8x4_endian_tests);
+impl_endian!(i16x2: 2, i16, u8x4, 4, i16x2_endian_tests);
+impl_endian!(u16x2: 2, u16, u8x4, 4, u16x2_endian_tests);
+
 impl_from_bits!(i16x2: i16, i16x2_from_bits | u16x2, i8x4, u8x4, b8x4);
 impl_from_bits!(u16x2: u16, u16x2_from_bits | i16x2, i8x4, u8x4, b8x4);
 impl_from_bits!(i8x4: i8, i8x2_from_bits | i16x2, u16x2, u8x4, b8x4);
@@ -53,7 +86,9 @@ impl_from!(
     i32x2,
     u16x2,
     u8x2,
-    i8x2
+    i8x2,
+    i128x2,
+    u128x2
 );
 
 impl_from!(
@@ -66,7 +101,9 @@ impl_from!(
     i32x2,
     i16x2,
     u8x2,
-    i8x2
+    i8x2,
+    i128x2,
+    u128x2
 );
 
 impl_from!(
@@ -79,7 +116,9 @@ impl_from!(
     f32x4,
     u16x4,
     i16x4,
-    u8x4
+    u8x4,
+    i128x4,
+    u128x4
 );
 
 impl_from!(
@@ -92,5 +131,7 @@ impl_from!(
     f32x4,
     u16x4,
     i16x4,
-    i8x4
+    i8x4,
+    i128x4,
+    u128x4
 );
@@ -2,7 +2,10 @@
 
 simd_api_imports!();
 
-use coresimd::simd::{b8x16, b8x32, b8x8};
+use coresimd::simd::{
+    b8x16, b8x32, b8x4, b8x8, f32x8, f64x4, i128x2, i16x16, i32x8, i64x4,
+    i8x32, u128x2, u16x16, u32x8, u64x4, u8x32,
+};
 
 simd_i_ty! {
     i8x64: 64, i8, b8x64, i8x64_tests |
@@ -98,6 +101,20 @@ simd_u_ty! {
     /// A 512-bit vector with 16 `u32` lanes.
 }
 
+simd_i_ty! {
+    i128x4: 4, i128, b8x4, i128x4_tests |
+    i128, i128, i128, i128 |
+    x0, x1, x2, x3 |
+    /// A 512-bit vector with 4 `i128` lanes.
+}
+
+simd_u_ty! {
+    u128x4: 4, u128, b8x4, u128x4_tests |
+    u128, u128, u128, u128 |
+    x0, x1, x2, x3 |
+    /// A 512-bit vector with 4 `u128` lanes.
+}
+
 simd_f_ty! {
     f32x16: 16, f32, b8x16, f32x16_tests |
     f32, f32, f32, f32, f32, f32, f32, f32,
@@ -128,6 +145,89 @@ simd_f_ty! {
     /// A 512-bit vector with 8 `f64` lanes.
 }
 
+impl_float_class!(f32x16: f32, i32x16, b8x16, f32x16_class_tests);
+impl_float_class!(f64x8: f64, i64x8, b8x8, f64x8_class_tests);
+impl_minmax!(f32x16, i32x16);
+impl_minmax!(f64x8, i64x8);
+
+impl_sum_widening!(i8x64: i8, i32, i8x64_sum_widening_tests);
+impl_sum_widening!(u8x64: u8, u32, u8x64_sum_widening_tests);
+impl_sum_widening!(i16x32: i16, i64, i16x32_sum_widening_tests);
+impl_sum_widening!(u16x32: u16, u64, u16x32_sum_widening_tests);
+impl_sum_widening!(i32x16: i32, i64, i32x16_sum_widening_tests);
+impl_sum_widening!(u32x16: u32, u64, u32x16_sum_widening_tests);
+impl_sum_pairs_widening!(i8x64: i8, i16x32, i8x64_sum_pairs_tests);
+impl_sum_pairs_widening!(u8x64: u8, u16x32, u8x64_sum_pairs_tests);
+impl_sum_pairs_widening!(i16x32: i16, i32x16, i16x32_sum_pairs_tests);
+impl_sum_pairs_widening!(u16x32: u16, u32x16, u16x32_sum_pairs_tests);
+impl_sum_pairs_widening!(i32x16: i32, i64x8, i32x16_sum_pairs_tests);
+impl_sum_pairs_widening!(u32x16: u32, u64x8, u32x16_sum_pairs_tests);
+
+impl_sort_lanes!(i8x64: 64, i8x32, i8, i8x64_sort_tests);
+impl_sort_lanes!(u8x64: 64, u8x32, u8, u8x64_sort_tests);
+impl_sort_lanes!(i16x32: 32, i16x16, i16, i16x32_sort_tests);
+impl_sort_lanes!(u16x32: 32, u16x16, u16, u16x32_sort_tests);
+impl_sort_lanes!(i32x16: 16, i32x8, i32, i32x16_sort_tests);
+impl_sort_lanes!(u32x16: 16, u32x8, u32, u32x16_sort_tests);
+impl_sort_lanes!(f32x16: 16, f32x8, f32, f32x16_sort_tests);
+impl_sort_lanes!(i64x8: 8, i64x4, i64, i64x8_sort_tests);
+impl_sort_lanes!(u64x8: 8, u64x4, u64, u64x8_sort_tests);
+impl_sort_lanes!(f64x8: 8, f64x4, f64, f64x8_sort_tests);
+impl_sort_lanes!(i128x4: 4, i128x2, i128, i128x4_sort_tests);
+impl_sort_lanes!(u128x4: 4, u128x2, u128, u128x4_sort_tests);
+
+impl_int_scan!(i8x64: 64, i8, i8x64_scan_tests);
+impl_int_scan!(u8x64: 64, u8, u8x64_scan_tests);
+impl_int_scan!(i16x32: 32, i16, i16x32_scan_tests);
+impl_int_scan!(u16x32: 32, u16, u16x32_scan_tests);
+impl_int_scan!(i32x16: 16, i32, i32x16_scan_tests);
+impl_int_scan!(u32x16: 16, u32, u32x16_scan_tests);
+impl_float_scan!(f32x16: 16, f32, f32x16_scan_tests);
+impl_int_scan!(i64x8: 8, i64, i64x8_scan_tests);
+impl_int_scan!(u64x8: 8, u64, u64x8_scan_tests);
+impl_float_scan!(f64x8: 8, f64, f64x8_scan_tests);
+impl_int_scan!(i128x4: 4, i128, i128x4_scan_tests);
+impl_int_scan!(u128x4: 4, u128, u128x4_scan_tests);
+
+impl_slide!(i8x64: 64, i8, i8x64_slide_tests);
+impl_slide!(u8x64: 64, u8, u8x64_slide_tests);
+impl_slide!(i16x32: 32, i16, i16x32_slide_tests);
+impl_slide!(u16x32: 32, u16, u16x32_slide_tests);
+impl_slide!(i32x16: 16, i32, i32x16_slide_tests);
+impl_slide!(u32x16: 16, u32, u32x16_slide_tests);
+impl_slide!(f32x16: 16, f32, f32x16_slide_tests);
+impl_slide!(i64x8: 8, i64, i64x8_slide_tests);
+impl_slide!(u64x8: 8, u64, u64x8_slide_tests);
+impl_slide!(f64x8: 8, f64, f64x8_slide_tests);
+impl_slide!(i128x4: 4, i128, i128x4_slide_tests);
+impl_slide!(u128x4: 4, u128, u128x4_slide_tests);
+impl_slide!(b8x64: 64, bool, b8x64_slide_tests);
+
+impl_signed_int_math!(i8x64: i8, u8x64, i8x64_int_math_tests);
+impl_unsigned_int_math!(u8x64: u8, u8x64_int_math_tests);
+impl_signed_int_math!(i16x32: i16, u16x32, i16x32_int_math_tests);
+impl_unsigned_int_math!(u16x32: u16, u16x32_int_math_tests);
+impl_signed_int_math!(i32x16: i32, u32x16, i32x16_int_math_tests);
+impl_unsigned_int_math!(u32x16: u32, u32x16_int_math_tests);
+impl_signed_int_math!(i64x8: i64, u64x8, i64x8_int_math_tests);
+impl_unsigned_int_math!(u64x8: u64, u64x8_int_math_tests);
+impl_signed_int_math!(i128x4: i128, u128x4, i128x4_int_math_tests);
+impl_unsigned_int_math!(u128x4: u128, u128x4_int_math_tests);
+
+impl_endian!(i8x64: 1, i8, u8x64, 64, i8x64_endian_tests);
+impl_endian!(u8x64: 1, u8, u8x64, 64, u8x64_endian_tests);
+impl_endian!(i16x32: 2, i16, u8x64, 64, i16x32_endian_tests);
+impl_endian!(u16x32: 2, u16, u8x64, 64, u16x32_endian_tests);
+impl_endian!(i32x16: 4, i32, u8x64, 64, i32x16_endian_tests);
+impl_endian!(u32x16: 4, u32, u8x64, 64, u32x16_endian_tests);
+impl_endian!(i64x8: 8, i64, u8x64, 64, i64x8_endian_tests);
+impl_endian!(u64x8: 8, u64, u8x64, 64, u64x8_endian_tests);
+impl_endian!(i128x4: 16, i128, u8x64, 64, i128x4_endian_tests);
+impl_endian!(u128x4: 16, u128, u8x64, 64, u128x4_endian_tests);
+
+impl_complex!(f32x16: 16, f32, f32x16_complex_tests);
+impl_complex!(f64x8: 8, f64, f64x8_complex_tests);
+
 impl_from_bits!(
     i8x64: i8,
     i8x64_from_bits | u64x8,
@@ -139,7 +239,9 @@ impl_from_bits!(
     u16x32,
     i16x32,
     u8x64,
-    b8x64
+    b8x64,
+    i128x4,
+    u128x4
 );
 impl_from_bits!(
     u8x64: u8,
@@ -152,7 +254,9 @@ impl_from_bits!(
     u16x32,
     i16x32,
     i8x64,
-    b8x64
+    b8x64,
+    i128x4,
+    u128x4
 );
 impl_from_bits!(
     i16x32: i16,
@@ -165,7 +269,9 @@ impl_from_bits!(
     u16x32,
     i8x64,
     u8x64,
-    b8x64
+    b8x64,
+    i128x4,
+    u128x4
 );
 impl_from_bits!(
     u16x32: u16,
@@ -178,7 +284,9 @@ impl_from_bits!(
     i16x32,
     i8x64,
     u8x64,
-    b8x64
+    b8x64,
+    i128x4,
+    u128x4
 );
 impl_from_bits!(
     i32x16: i32,
@@ -191,7 +299,9 @@ impl_from_bits!(
     i16x32,
     i8x64,
     u8x64,
-    b8x64
+    b8x64,
+    i128x4,
+    u128x4
 );
 impl_from_bits!(
     u32x16: u32,
@@ -204,7 +314,9 @@ impl_from_bits!(
     i16x32,
     i8x64,
     u8x64,
-    b8x64
+    b8x64,
+    i128x4,
+    u128x4
 );
 impl_from_bits!(
     f32x16: f32,
@@ -217,7 +329,9 @@ impl_from_bits!(
     i16x32,
     i8x64,
     u8x64,
-    b8x64
+    b8x64,
+    i128x4,
+    u128x4
 );
 impl_from_bits!(
     i64x8: i64,
@@ -230,7 +344,9 @@ impl_from_bits!(
     i16x32,
     i8x64,
     u8x64,
-    b8x64
+    b8x64,
+    i128x4,
+    u128x4
 );
 impl_from_bits!(
     u64x8: u64,
@@ -243,7 +359,9 @@ impl_from_bits!(
     i16x32,
     i8x64,
     u8x64,
-    b8x64
+    b8x64,
+    i128x4,
+    u128x4
 );
 impl_from_bits!(
     f64x8: f64,
@@ -256,7 +374,39 @@ impl_from_bits!(
     i16x32,
     i8x64,
     u8x64,
-    b8x64
+    b8x64,
+    i128x4,
+    u128x4
+);
+impl_from_bits!(
+    i128x4: i128,
+    i128x4_from_bits | i8x64,
+    u64x8,
+    i64x8,
+    f64x8,
+    u32x16,
+    i32x16,
+    f32x16,
+    u16x32,
+    i16x32,
+    u8x64,
+    b8x64,
+    u128x4
+);
+impl_from_bits!(
+    u128x4: u128,
+    u128x4_from_bits | i8x64,
+    u64x8,
+    i64x8,
+    f64x8,
+    u32x16,
+    i32x16,
+    f32x16,
+    u16x32,
+    i16x32,
+    u8x64,
+    b8x64,
+    i128x4
 );
 
 impl_from!(
@@ -329,3 +479,32 @@ impl_from!(u16x32: u16, u16x32_from | i16x32, u8x32, i8x32);
 
 impl_from!(i8x64: i8, i8x64_from | u8x64);
 impl_from!(u8x64: u8, u8x64_from | i8x64);
+
+impl_from!(
+    i128x4: i128,
+    i128x4_from | i8x4,
+    u8x4,
+    u16x4,
+    i16x4,
+    f32x4,
+    u32x4,
+    i32x4,
+    f64x4,
+    i64x4,
+    u64x4,
+    u128x4
+);
+impl_from!(
+    u128x4: u128,
+    u128x4_from | i8x4,
+    u8x4,
+    u16x4,
+    i16x4,
+    f32x4,
+    u32x4,
+    i32x4,
+    f64x4,
+    i64x4,
+    u64x4,
+    i128x4
+);
@@ -2,7 +2,7 @@
 
 simd_api_imports!();
 
-use coresimd::simd::{b8x2, b8x4};
+use coresimd::simd::{b8x2, b8x4, f32x4, i16x2, i8x4, u16x2, u8x4};
 
 simd_i_ty! {
     i8x8: 8, i8, b8x8, i8x8_tests |
@@ -60,6 +60,67 @@ simd_f_ty! {
     /// A 64-bit vector with 2 `f32` lanes.
 }
 
+simd_f16_ty! {
+    f16x4: 4, f32x4, u16x4, f16x4_tests |
+    u16, u16, u16, u16 |
+    /// A 64-bit vector with 4 half-precision floating-point lanes.
+}
+
+impl_float_class!(f32x2: f32, i32x2, b8x2, f32x2_class_tests);
+impl_minmax!(f32x2, i32x2);
+
+impl_sum_widening!(i8x8: i8, i32, i8x8_sum_widening_tests);
+impl_sum_widening!(u8x8: u8, u32, u8x8_sum_widening_tests);
+impl_sum_widening!(i16x4: i16, i64, i16x4_sum_widening_tests);
+impl_sum_widening!(u16x4: u16, u64, u16x4_sum_widening_tests);
+impl_sum_widening!(i32x2: i32, i64, i32x2_sum_widening_tests);
+impl_sum_widening!(u32x2: u32, u64, u32x2_sum_widening_tests);
+impl_sum_pairs_widening!(i8x8: i8, i16x4, i8x8_sum_pairs_tests);
+impl_sum_pairs_widening!(u8x8: u8, u16x4, u8x8_sum_pairs_tests);
+impl_sum_pairs_widening!(i16x4: i16, i32x2, i16x4_sum_pairs_tests);
+impl_sum_pairs_widening!(u16x4: u16, u32x2, u16x4_sum_pairs_tests);
+
+impl_sort_lanes!(i8x8: 8, i8x4, i8, i8x8_sort_tests);
+impl_sort_lanes!(u8x8: 8, u8x4, u8, u8x8_sort_tests);
+impl_sort_lanes!(i16x4: 4, i16x2, i16, i16x4_sort_tests);
+impl_sort_lanes!(u16x4: 4, u16x2, u16, u16x4_sort_tests);
+impl_sort_lanes!(i32x2: 2, i32, i32x2_sort_tests);
+impl_sort_lanes!(u32x2: 2, u32, u32x2_sort_tests);
+impl_sort_lanes!(f32x2: 2, f32, f32x2_sort_tests);
+
+impl_int_scan!(i8x8: 8, i8, i8x8_scan_tests);
+impl_int_scan!(u8x8: 8, u8, u8x8_scan_tests);
+impl_int_scan!(i16x4: 4, i16, i16x4_scan_tests);
+impl_int_scan!(u16x4: 4, u16, u16x4_scan_tests);
+impl_int_scan!(i32x2: 2, i32, i32x2_scan_tests);
+impl_int_scan!(u32x2: 2, u32, u32x2_scan_tests);
+impl_float_scan!(f32x2: 2, f32, f32x2_scan_tests);
+
+impl_slide!(i8x8: 8, i8, i8x8_slide_tests);
+impl_slide!(u8x8: 8, u8, u8x8_slide_tests);
+impl_slide!(i16x4: 4, i16, i16x4_slide_tests);
+impl_slide!(u16x4: 4, u16, u16x4_slide_tests);
+impl_slide!(i32x2: 2, i32, i32x2_slide_tests);
+impl_slide!(u32x2: 2, u32, u32x2_slide_tests);
+impl_slide!(f32x2: 2, f32, f32x2_slide_tests);
+impl_slide!(b8x8: 8, bool, b8x8_slide_tests);
+
+impl_signed_int_math!(i8x8: i8, u8x8, i8x8_int_math_tests);
+impl_unsigned_int_math!(u8x8: u8, u8x8_int_math_tests);
+impl_signed_int_math!(i16x4: i16, u16x4, i16x4_int_math_tests);
+impl_unsigned_int_math!(u16x4: u16, u16x4_int_math_tests);
+impl_signed_int_math!(i32x2: i32, u32x2, i32x2_int_math_tests);
+impl_unsigned_int_math!(u32x2: u32, u32x2_int_math_tests);
+
+impl_endian!(i8x8: 1, i8, u8x8, 8, i8x8_endian_tests);
+impl_endian!(u8x8: 1, u8, u8x8, 8, u8x8_endian_tests);
+impl_endian!(i16x4: 2, i16, u8x8, 8, i16x4_endian_tests);
+impl_endian!(u16x4: 2, u16, u8x8, 8, u16x4_endian_tests);
+impl_endian!(i32x2: 4, i32, u8x8, 8, i32x2_endian_tests);
+impl_endian!(u32x2: 4, u32, u8x8, 8, u32x2_endian_tests);
+
+impl_complex!(f32x2: 2, f32, f32x2_complex_tests);
+
 impl_from_bits!(
     u32x2: u32,
     u32x2_from_bits | i32x2,
@@ -97,7 +158,8 @@ impl_from_bits!(
     i16x4,
     u8x8,
     i8x8,
-    b8x8
+    b8x8,
+    f16x4
 );
 impl_from_bits!(
     i16x4: i16,
@@ -106,7 +168,8 @@ impl_from_bits!(
     u16x4,
     u8x8,
     i8x8,
-    b8x8
+    b8x8,
+    f16x4
 );
 impl_from_bits!(
     u8x8: u8,
@@ -127,6 +190,8 @@ impl_from_bits!(
     b8x8
 );
 
+impl_from_bits_!(f16x4: u16x4, i16x4);
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use coresimd::x86::__m64;
 
@@ -144,6 +209,8 @@ impl_from_bits_!(i16x4: __m64);
 impl_from_bits_!(u8x8: __m64);
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 impl_from_bits_!(i8x8: __m64);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_from_bits_!(f16x4: __m64);
 
 impl_from!(
     f32x2: f32,
@@ -155,7 +222,9 @@ impl_from!(
     u16x2,
     i16x2,
     u8x2,
-    i8x2
+    i8x2,
+    i128x2,
+    u128x2
 );
 
 impl_from!(
@@ -168,7 +237,9 @@ impl_from!(
     u16x2,
     i16x2,
     u8x2,
-    i8x2
+    i8x2,
+    i128x2,
+    u128x2
 );
 
 impl_from!(
@@ -181,7 +252,9 @@ impl_from!(
     u16x2,
     i16x2,
     u8x2,
-    i8x2
+    i8x2,
+    i128x2,
+    u128x2
 );
 
 impl_from!(
@@ -194,7 +267,9 @@ impl_from!(
     u32x4,
     i16x4,
     u8x4,
-    i8x4
+    i8x4,
+    i128x4,
+    u128x4
 );
 
 impl_from!(
@@ -207,7 +282,9 @@ impl_from!(
     u32x4,
     u16x4,
     u8x4,
-    i8x4
+    i8x4,
+    i128x4,
+    u128x4
 );
 impl_from!(
     i8x8: i8,
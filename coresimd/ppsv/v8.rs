@@ -0,0 +1,12 @@
+//! 8-bit wide portable packed vector types.
+
+simd_api_imports!();
+
+simd_b_ty! {
+    b8x1: 1, i8, b8x1_tests |
+    i8 |
+    x0 |
+    /// An 8-bit vector with 1 `bool` lane.
+}
+
+impl_slide!(b8x1: 1, bool, b8x1_slide_tests);
@@ -0,0 +1,123 @@
+//! Portable packed vectors of pointers.
+//!
+//! Their width is that of the pointer-sized integer vectors of the target:
+//! `cptrx4<T>` is as wide as a `u64x4` on targets with 64-bit pointers, and
+//! as wide as a `u32x4` on targets with 32-bit pointers.
+//!
+//! There are no portable vectors with 16 64-bit lanes, so on targets with
+//! 64-bit pointers the lanes of `cptrx16<T>` and `mptrx16<T>` are stored in
+//! the internal 1024-bit vectors of the `wide` module. These implement only
+//! the minimal API, the comparisons and the arithmetic operators, and the
+//! offsets taken by `offset`/`add` are best constructed from arrays, e.g.,
+//! `v.wrapping_add([1; 16].into())`.
+
+use clone::Clone;
+use cmp::{Eq, PartialEq};
+use convert::From;
+use coresimd::simd::{b8x16, b8x2, b8x4, b8x8, FromBits};
+use default::Default;
+use fmt;
+use marker;
+use mem;
+use ptr;
+
+#[cfg(target_pointer_width = "32")]
+use coresimd::simd::{
+    i32x16 as isizex16, i32x2 as isizex2, i32x4 as isizex4, i32x8 as isizex8,
+    u32x16 as usizex16, u32x2 as usizex2, u32x4 as usizex4, u32x8 as usizex8,
+};
+#[cfg(target_pointer_width = "64")]
+use coresimd::simd::{
+    i64x2 as isizex2, i64x4 as isizex4, i64x8 as isizex8, u64x2 as usizex2,
+    u64x4 as usizex4, u64x8 as usizex8,
+};
+#[cfg(target_pointer_width = "64")]
+use self::wide::{isizex16, usizex16};
+
+/// Storage of the 16 pointer-sized lanes of `cptrx16`/`mptrx16` on targets
+/// with 64-bit pointers.
+#[cfg(target_pointer_width = "64")]
+mod wide {
+    use clone::Clone;
+    use cmp::PartialEq;
+    use convert::From;
+    use coresimd::simd::b8x16;
+    use coresimd::simd_llvm::*;
+    use mem;
+    use ops;
+
+    define_ty! {
+        usizex16,
+        u64, u64, u64, u64, u64, u64, u64, u64,
+        u64, u64, u64, u64, u64, u64, u64, u64 |
+        /// A 1024-bit vector with 16 `u64` lanes.
+    }
+    impl_minimal!(
+        usizex16, u64, 16,
+        x0, x1, x2, x3, x4, x5, x6, x7,
+        x8, x9, x10, x11, x12, x13, x14, x15
+    );
+    impl_array!(usizex16, u64, 16);
+    impl_cmp!(usizex16, b8x16);
+    impl_arithmetic_ops!(usizex16);
+    impl_partial_eq!(usizex16);
+
+    define_ty! {
+        isizex16,
+        i64, i64, i64, i64, i64, i64, i64, i64,
+        i64, i64, i64, i64, i64, i64, i64, i64 |
+        /// A 1024-bit vector with 16 `i64` lanes.
+    }
+    impl_minimal!(
+        isizex16, i64, 16,
+        x0, x1, x2, x3, x4, x5, x6, x7,
+        x8, x9, x10, x11, x12, x13, x14, x15
+    );
+    impl_array!(isizex16, i64, 16);
+    impl_cmp!(isizex16, b8x16);
+    impl_arithmetic_ops!(isizex16);
+    impl_partial_eq!(isizex16);
+
+    impl_from_bits_!(usizex16: isizex16);
+    impl_from_bits_!(isizex16: usizex16);
+}
+
+simd_cptr_ty! {
+    cptrx2: 2, usizex2, isizex2, b8x2, cptrx2_tests |
+    /// A vector with 2 `*const T` lanes.
+}
+
+simd_mptr_ty! {
+    mptrx2: 2, cptrx2, usizex2, isizex2, b8x2, mptrx2_tests |
+    /// A vector with 2 `*mut T` lanes.
+}
+
+simd_cptr_ty! {
+    cptrx4: 4, usizex4, isizex4, b8x4, cptrx4_tests |
+    /// A vector with 4 `*const T` lanes.
+}
+
+simd_mptr_ty! {
+    mptrx4: 4, cptrx4, usizex4, isizex4, b8x4, mptrx4_tests |
+    /// A vector with 4 `*mut T` lanes.
+}
+
+simd_cptr_ty! {
+    cptrx8: 8, usizex8, isizex8, b8x8, cptrx8_tests |
+    /// A vector with 8 `*const T` lanes.
+}
+
+simd_mptr_ty! {
+    mptrx8: 8, cptrx8, usizex8, isizex8, b8x8, mptrx8_tests |
+    /// A vector with 8 `*mut T` lanes.
+}
+
+simd_cptr_ty! {
+    cptrx16: 16, usizex16, isizex16, b8x16, cptrx16_tests |
+    /// A vector with 16 `*const T` lanes.
+}
+
+simd_mptr_ty! {
+    mptrx16: 16, cptrx16, usizex16, isizex16, b8x16, mptrx16_tests |
+    /// A vector with 16 `*mut T` lanes.
+}
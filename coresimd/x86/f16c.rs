@@ -0,0 +1,133 @@
+//! F16C: conversions between half-precision (16-bit) and single-precision
+//! (32-bit) floating-point numbers.
+//!
+//! The references are:
+//!
+//! - [Intel 64 and IA-32 Architectures Software Developer's Manual Volume 2:
+//! Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+use coresimd::simd::*;
+use coresimd::x86::*;
+use mem;
+
+/// Converts the 4 half-precision (16-bit) floating-point values in the low
+/// 64 bits of `a` into 4 single-precision (32-bit) floating-point values.
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr(vcvtph2ps))]
+pub unsafe fn _mm_cvtph_ps(a: __m128i) -> __m128 {
+    vcvtph2ps(a.as_i16x8())
+}
+
+/// Converts the 8 half-precision (16-bit) floating-point values in `a` into
+/// 8 single-precision (32-bit) floating-point values.
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr(vcvtph2ps))]
+pub unsafe fn _mm256_cvtph_ps(a: __m128i) -> __m256 {
+    vcvtph2ps256(a.as_i16x8())
+}
+
+/// Converts the 4 single-precision (32-bit) floating-point values in `a`
+/// into 4 half-precision (16-bit) floating-point values stored in the low 64
+/// bits of the result. The upper 64 bits of the result are zeroed.
+///
+/// Rounding is done according to the `rounding` parameter, which can be one
+/// of:
+///
+/// - `0x00`: Round to the nearest, ties to even.
+/// - `0x01`: Round down, toward negative infinity.
+/// - `0x02`: Round up, toward positive infinity.
+/// - `0x03`: Truncate the values.
+/// - `0x04`: Use the rounding mode of `MXCSR.RC`.
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr(vcvtps2ph, rounding = 0))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm_cvtps_ph(a: __m128, rounding: i32) -> __m128i {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vcvtps2ph(a, $imm3)
+        }
+    }
+    mem::transmute(constify_imm3!(rounding, call))
+}
+
+/// Converts the 8 single-precision (32-bit) floating-point values in `a`
+/// into 8 half-precision (16-bit) floating-point values.
+///
+/// Rounding is done according to the `rounding` parameter, see
+/// `_mm_cvtps_ph`.
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr(vcvtps2ph, rounding = 0))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm256_cvtps_ph(a: __m256, rounding: i32) -> __m128i {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vcvtps2ph256(a, $imm3)
+        }
+    }
+    mem::transmute(constify_imm3!(rounding, call))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.vcvtph2ps.128"]
+    fn vcvtph2ps(a: i16x8) -> __m128;
+    #[link_name = "llvm.x86.vcvtph2ps.256"]
+    fn vcvtph2ps256(a: i16x8) -> __m256;
+    #[link_name = "llvm.x86.vcvtps2ph.128"]
+    fn vcvtps2ph(a: __m128, rounding: i32) -> i16x8;
+    #[link_name = "llvm.x86.vcvtps2ph.256"]
+    fn vcvtps2ph256(a: __m256, rounding: i32) -> i16x8;
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use coresimd::x86::*;
+
+    #[simd_test = "f16c"]
+    unsafe fn test_mm_cvtph_ps() {
+        // 1.0, -2.0, 0.5, 65504.0 (largest finite half)
+        let a = _mm_setr_epi16(0x3c00, -0x4000, 0x3800, 0x7bff, 0, 0, 0, 0);
+        let r = _mm_cvtph_ps(a);
+        let e = _mm_setr_ps(1., -2., 0.5, 65504.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "f16c"]
+    unsafe fn test_mm256_cvtph_ps() {
+        let a = _mm_setr_epi16(
+            0x3c00, -0x4000, 0x3800, 0x7bff, 0, 0x4000, 0x4200, 0x4400,
+        );
+        let r = _mm256_cvtph_ps(a);
+        let e = _mm256_setr_ps(1., -2., 0.5, 65504., 0., 2., 3., 4.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "f16c"]
+    unsafe fn test_mm_cvtps_ph() {
+        let a = _mm_setr_ps(1., -2., 0.5, 65504.);
+        let r = _mm_cvtps_ph(a, 0);
+        let e = _mm_setr_epi16(0x3c00, -0x4000, 0x3800, 0x7bff, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "f16c"]
+    unsafe fn test_mm256_cvtps_ph() {
+        let a = _mm256_setr_ps(1., -2., 0.5, 65504., 0., 2., 3., 4.);
+        let r = _mm256_cvtps_ph(a, 0);
+        let e = _mm_setr_epi16(
+            0x3c00, -0x4000, 0x3800, 0x7bff, 0, 0x4000, 0x4200, 0x4400,
+        );
+        assert_eq_m128i(r, e);
+    }
+}
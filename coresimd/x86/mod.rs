@@ -459,12 +459,23 @@ impl m256iExt for __m256i {
     }
 }
 
-use coresimd::simd::{b8x16, b8x32, b8x8, f32x4, f32x8, f64x2, f64x4, i16x16,
-                     i16x4, i16x8, i32x2, i32x4, i32x8, i64x2, i64x4, i8x16,
-                     i8x32, i8x8, u16x16, u16x4, u16x8, u32x2, u32x4, u32x8,
-                     u64x2, u64x4, u8x16, u8x32, u8x8};
+use coresimd::simd::{b8x16, b8x32, b8x8, bf16x16, bf16x8, f16x16, f16x4,
+                     f16x8, f32x2, f32x4, f32x8, f64x2, f64x4, i128x1, i128x2,
+                     i16x16, i16x4, i16x8, i32x2, i32x4, i32x8, i64x2, i64x4,
+                     i8x16, i8x32, i8x8, u128x1, u128x2, u16x16, u16x4, u16x8,
+                     u32x2, u32x4, u32x8, u64x2, u64x4, u8x16, u8x32, u8x8};
 
-impl_from_bits_!(__m64: u32x2, i32x2, u16x4, i16x4, u8x8, i8x8, b8x8);
+impl_from_bits_!(
+    __m64: f32x2,
+    u32x2,
+    i32x2,
+    f16x4,
+    u16x4,
+    i16x4,
+    u8x8,
+    i8x8,
+    b8x8
+);
 impl_from_bits_!(
     __m128: u64x2,
     i64x2,
@@ -474,9 +485,13 @@ impl_from_bits_!(
     f32x4,
     u16x8,
     i16x8,
+    f16x8,
+    bf16x8,
     u8x16,
     i8x16,
-    b8x16
+    b8x16,
+    i128x1,
+    u128x1
 );
 impl_from_bits_!(
     __m128i: u64x2,
@@ -487,9 +502,13 @@ impl_from_bits_!(
     f32x4,
     u16x8,
     i16x8,
+    f16x8,
+    bf16x8,
     u8x16,
     i8x16,
-    b8x16
+    b8x16,
+    i128x1,
+    u128x1
 );
 impl_from_bits_!(
     __m128d: u64x2,
@@ -500,9 +519,13 @@ impl_from_bits_!(
     f32x4,
     u16x8,
     i16x8,
+    f16x8,
+    bf16x8,
     u8x16,
     i8x16,
-    b8x16
+    b8x16,
+    i128x1,
+    u128x1
 );
 impl_from_bits_!(
     __m256: u64x4,
@@ -513,9 +536,13 @@ impl_from_bits_!(
     f32x8,
     u16x16,
     i16x16,
+    f16x16,
+    bf16x16,
     u8x32,
     i8x32,
-    b8x32
+    b8x32,
+    i128x2,
+    u128x2
 );
 impl_from_bits_!(
     __m256i: u64x4,
@@ -526,9 +553,13 @@ impl_from_bits_!(
     f32x8,
     u16x16,
     i16x16,
+    f16x16,
+    bf16x16,
     u8x32,
     i8x32,
-    b8x32
+    b8x32,
+    i128x2,
+    u128x2
 );
 impl_from_bits_!(
     __m256d: u64x4,
@@ -539,9 +570,13 @@ impl_from_bits_!(
     f32x8,
     u16x16,
     i16x16,
+    f16x16,
+    bf16x16,
     u8x32,
     i8x32,
-    b8x32
+    b8x32,
+    i128x2,
+    u128x2
 );
 
 mod eflags;
@@ -579,6 +614,8 @@ mod avx;
 pub use self::avx::*;
 mod avx2;
 pub use self::avx2::*;
+mod f16c;
+pub use self::f16c::*;
 
 mod abm;
 pub use self::abm::*;
@@ -609,3 +646,60 @@ pub use self::aes::*;
 
 mod rdrand;
 pub use self::rdrand::*;
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+    use coresimd::x86::*;
+    use coresimd::simd::*;
+
+    #[simd_test = "sse2"]
+    unsafe fn portable_m128_interop() {
+        let a = f32x4::new(1., 2., 3., 4.);
+        let r: f32x4 = _mm_add_ps(a.into_bits(), a.into_bits()).into_bits();
+        assert_eq!(r, a + a);
+
+        let a = i64x2::new(-1, 2);
+        let r: i64x2 = _mm_add_epi64(a.into_bits(), a.into_bits()).into_bits();
+        assert_eq!(r, a + a);
+
+        let a = f64x2::new(1.5, -2.5);
+        let r: f64x2 = _mm_mul_pd(a.into_bits(), a.into_bits()).into_bits();
+        assert_eq!(r, a * a);
+
+        // The conversions preserve the bits:
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        assert_eq!(u8x16::from_bits(a), u8x16::from_bits(i32x4::from_bits(a)));
+        assert_eq_m128i(u32x4::from_bits(a).into_bits(), a);
+    }
+
+    #[simd_test = "avx"]
+    unsafe fn portable_m256_interop() {
+        let a = f64x4::new(1., 2., 3., 4.);
+        let r: f64x4 = _mm256_add_pd(a.into_bits(), a.into_bits()).into_bits();
+        assert_eq!(r, a + a);
+
+        let a = f32x8::new(1., 2., 3., 4., 5., 6., 7., 8.);
+        let m: __m256 = a.into_bits();
+        let r: u32x8 = _mm256_castps_si256(m).into_bits();
+        assert_eq!(r, u32x8::from_bits(a));
+    }
+
+    #[simd_test = "f16c"]
+    unsafe fn portable_f16_interop() {
+        let a = f32x4::new(1., -2., 0.5, 65504.);
+        let h: f16x8 = _mm_cvtps_ph(a.into_bits(), 0).into_bits();
+        let r: f32x4 = _mm_cvtph_ps(h.into_bits()).into_bits();
+        assert_eq!(r, a);
+    }
+
+    #[simd_test = "mmx"]
+    unsafe fn portable_m64_interop() {
+        let a = i16x4::new(1, -2, 3, i16::max_value());
+        let r: i16x4 = _mm_adds_pi16(a.into_bits(), a.into_bits()).into_bits();
+        assert_eq!(r, i16x4::new(2, -4, 6, i16::max_value()));
+        let f = f32x2::new(1., 2.);
+        let m: __m64 = f.into_bits();
+        assert_eq!(f32x2::from_bits(m), f);
+    }
+}
@@ -42,6 +42,12 @@ extern crate stdsimd;
 extern crate stdsimd_test;
 #[cfg(test)]
 extern crate test;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_test;
+#[cfg(feature = "quickcheck")]
+extern crate quickcheck;
 
 #[path = "../../../coresimd/mod.rs"]
 mod coresimd;
@@ -58,6 +64,10 @@ use _core::convert;
 #[allow(unused_imports)]
 use _core::default;
 #[allow(unused_imports)]
+use _core::f32;
+#[allow(unused_imports)]
+use _core::f64;
+#[allow(unused_imports)]
 use _core::fmt;
 #[allow(unused_imports)]
 use _core::hash;
@@ -33,6 +33,7 @@ fn x86_all() {
         is_x86_feature_detected!("avx512vpopcntdq")
     );
     println!("fma: {:?}", is_x86_feature_detected!("fma"));
+    println!("f16c: {:?}", is_x86_feature_detected!("f16c"));
     println!("abm: {:?}", is_x86_feature_detected!("abm"));
     println!("bmi: {:?}", is_x86_feature_detected!("bmi1"));
     println!("bmi2: {:?}", is_x86_feature_detected!("bmi2"));
@@ -17,56 +17,6 @@ const PI: f64 = std::f64::consts::PI;
 const SOLAR_MASS: f64 = 4.0 * PI * PI;
 const DAYS_PER_YEAR: f64 = 365.24;
 
-pub trait Frsqrt {
-    fn frsqrt(&self) -> Self;
-}
-
-impl Frsqrt for f64x2 {
-    fn frsqrt(&self) -> Self {
-        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
-                  target_feature = "sse"))]
-        {
-            #[cfg(target_arch = "x86")]
-            use stdsimd::arch::x86::*;
-            #[cfg(target_arch = "x86_64")]
-            use stdsimd::arch::x86_64::*;
-            let t: f32x2 = (*self).into();
-
-            let u: f64x4 = unsafe {
-                let res = _mm_rsqrt_ps(_mm_setr_ps(
-                    t.extract(0),
-                    t.extract(1),
-                    0.,
-                    0.,
-                ));
-                f32x4::from_bits(res).into()
-            };
-            Self::new(u.extract(0), u.extract(1))
-        }
-        #[cfg(all(any(target_arch = "arm", target_arch = "aarch64"),
-                  target_feature = "neon"))]
-        {
-            #[cfg(target_arch = "arm")]
-            use stdsimd::arch::arm::*;
-            #[cfg(target_arch = "aarch64")]
-            use stdsimd::arch::aarch64::*;
-
-            unsafe { vrsqrte_f32((*self).into()).into() }
-        }
-        #[cfg(not(any(all(any(target_arch = "x86",
-                              target_arch = "x86_64"),
-                          target_feature = "sse"),
-                      all(any(target_arch = "arm",
-                              target_arch = "aarch64"),
-                          target_feature = "neon"))))]
-        {
-            self.replace(0, 1. / self.extract(0).sqrt());
-            self.replace(1, 1. / self.extract(1).sqrt());
-            *self
-        }
-    }
-}
-
 struct Body {
     x: [f64; 3],
     _fill: f64,
@@ -124,12 +74,7 @@ fn advance(bodies: &mut [Body; N_BODIES], dt: f64) {
         }
 
         dsquared = dx[0] * dx[0] + dx[1] * dx[1] + dx[2] * dx[2];
-        distance = dsquared.frsqrt();
-        for _ in 0..2 {
-            distance = distance * f64x2::splat(1.5)
-                - ((f64x2::splat(0.5) * dsquared) * distance)
-                    * (distance * distance)
-        }
+        distance = dsquared.rsqrt_approx_refined(2);
         dmag = f64x2::splat(dt) / dsquared * distance;
         dmag.store_unaligned(&mut mag[i..]);
 
@@ -125,6 +125,10 @@ macro_rules! is_x86_feature_detected {
         cfg!(target_feature = "fma") || $crate::arch::detect::check_for(
             $crate::arch::detect::Feature::fma)
     };
+    ("f16c") => {
+        cfg!(target_feature = "f16c") || $crate::arch::detect::check_for(
+            $crate::arch::detect::Feature::f16c)
+    };
     ("bmi1") => {
         cfg!(target_feature = "bmi1") || $crate::arch::detect::check_for(
             $crate::arch::detect::Feature::bmi)
@@ -235,6 +239,8 @@ pub enum Feature {
     avx512_vpopcntdq,
     /// FMA (Fused Multiply Add)
     fma,
+    /// F16C (Conversions between half- and single-precision floats)
+    f16c,
     /// BMI1 (Bit Manipulation Instructions 1)
     bmi,
     /// BMI1 (Bit Manipulation Instructions 2)
@@ -430,6 +436,7 @@ pub fn detect_features() -> cache::Initializer {
                 // And AVX/AVX2:
                 enable(proc_info_ecx, 28, Feature::avx);
                 enable(extended_features_ebx, 5, Feature::avx2);
+                enable(proc_info_ecx, 29, Feature::f16c);
 
                 // For AVX-512 the OS also needs to support saving/restoring
                 // the extended state, only then we enable AVX-512 support:
@@ -504,6 +511,7 @@ mod tests {
             is_x86_feature_detected!("avx512vpopcntdq")
         );
         println!("fma: {:?}", is_x86_feature_detected!("fma"));
+        println!("f16c: {:?}", is_x86_feature_detected!("f16c"));
         println!("abm: {:?}", is_x86_feature_detected!("abm"));
         println!("bmi: {:?}", is_x86_feature_detected!("bmi1"));
         println!("bmi2: {:?}", is_x86_feature_detected!("bmi2"));
@@ -554,6 +562,7 @@ mod tests {
             information.avx512_vpopcntdq()
         );
         assert_eq!(is_x86_feature_detected!("fma"), information.fma());
+        assert_eq!(is_x86_feature_detected!("f16c"), information.f16c());
         assert_eq!(is_x86_feature_detected!("bmi1"), information.bmi1());
         assert_eq!(is_x86_feature_detected!("bmi2"), information.bmi2());
         assert_eq!(is_x86_feature_detected!("popcnt"), information.popcnt());